@@ -0,0 +1,430 @@
+//! Typed instruction builders, PDA derivation and account decoding for Rust
+//! bots and backends, so they never assemble discriminators or account
+//! lists by hand. Off-chain only: build with the `client` feature.
+//!
+//! Tables are keypair accounts rather than PDAs, and each holds its players'
+//! lamports itself, so a table's key doubles as its vault. Builders leave
+//! every optional account out but those asked for in `Extras`; anything
+//! more exotic can still be put together from `crate::accounts` and
+//! `crate::instruction`.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::sysvar;
+use anchor_lang::{system_program, InstructionData, ZeroCopy};
+
+use crate::engine::stats_shard_for;
+use crate::{accounts, instruction, name_hash, ActionTicket, Game, GameVariant, TableConfig, ID};
+
+/// The account holding a table's lamports: the table itself.
+pub fn vault(game: &Pubkey) -> Pubkey {
+    *game
+}
+
+/// `["table_config", game]`
+pub fn table_config(game: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"table_config", game.as_ref()], &ID).0
+}
+
+/// `["seat", game, player]`
+pub fn seat(game: &Pubkey, player: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"seat", game.as_ref(), player.as_ref()], &ID).0
+}
+
+/// `["profile", wallet]`
+pub fn profile(wallet: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"profile", wallet.as_ref()], &ID).0
+}
+
+/// `["name", name_hash(display_name)]`
+pub fn name_record(display_name: &str) -> Pubkey {
+    Pubkey::find_program_address(&[b"name", &name_hash(display_name)], &ID).0
+}
+
+/// `["spectator", game, wallet]`
+pub fn spectator(game: &Pubkey, wallet: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"spectator", game.as_ref(), wallet.as_ref()], &ID).0
+}
+
+/// `["price_gate", game]`
+pub fn price_gate(game: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"price_gate", game.as_ref()], &ID).0
+}
+
+/// `["dealer_bot", game, bot]`
+pub fn dealer_bot(game: &Pubkey, bot: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"dealer_bot", game.as_ref(), bot.as_ref()], &ID).0
+}
+
+/// `["global_config"]`
+pub fn global_config() -> Pubkey {
+    Pubkey::find_program_address(&[b"global_config"], &ID).0
+}
+
+/// `["payout_route", game, wallet]`
+pub fn payout_route(game: &Pubkey, wallet: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"payout_route", game.as_ref(), wallet.as_ref()], &ID).0
+}
+
+/// `["memo_config", game]`
+pub fn memo_config(game: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"memo_config", game.as_ref()], &ID).0
+}
+
+/// `["stats_shard", shard]` for the shard a table reports to.
+pub fn stats_shard(game: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"stats_shard", &[stats_shard_for(game)]], &ID).0
+}
+
+/// A ticket for acting on the table as it stands in `game`.
+pub fn ticket(game: &Game, client_nonce: u64) -> ActionTicket {
+    ActionTicket {
+        hand_number: game.hand_number,
+        street: if game.is_betting() { game.street() } else { 0 },
+        nonce: game.action_nonce,
+        client_nonce,
+    }
+}
+
+/// Optional accounts that tables with the matching setting turn on need.
+/// Each builder that takes one passes the parts its instruction declares.
+#[derive(Clone, Copy, Default)]
+pub struct Extras {
+    /// The instructions sysvar, for deposits at a table that takes only the
+    /// player's own funds.
+    pub own_funds_proof: bool,
+    /// The SOL/USD feed of a table with a USD buy-in range, passed with its
+    /// `PriceGate` on deposits.
+    pub usd_feed: Option<Pubkey>,
+    /// The signer's `DealerBot`, for cranks at a table that registers bots.
+    pub dealer_bot: bool,
+    /// The staking program and deposit account a winner's payout route
+    /// names, passed with the route when settling a hand they won.
+    pub payout_route: Option<PayoutTarget>,
+    /// The table's `MemoConfig` and the Memo program, at a table that tags
+    /// the instruction's fund movements with memos.
+    pub memo: bool,
+}
+
+/// A winner's payout route, as `set_payout_route` set it.
+#[derive(Clone, Copy)]
+pub struct PayoutTarget {
+    pub winner: Pubkey,
+    pub program: Pubkey,
+    pub deposit: Pubkey,
+}
+
+fn build(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+/// Opens a cash table on the fresh keypair `game`, which must also sign.
+pub fn initialize_game(game: &Pubkey, user: &Pubkey, small_blind: u64, big_blind: u64, variant: GameVariant) -> Instruction {
+    build(
+        accounts::InitializeGame {
+            game: *game,
+            config: table_config(game),
+            stats_shard: None,
+            user: *user,
+            system_program: system_program::ID,
+        },
+        instruction::InitializeGame { small_blind, big_blind, variant },
+    )
+}
+
+/// Takes a seat at a table, without an attestation, invitation or club.
+pub fn join_game(game: &Pubkey, player: &Pubkey, deposit: u64) -> Instruction {
+    build(join_accounts(game, player), instruction::JoinGame { deposit })
+}
+
+/// `join_game` with the accounts `extras` asks for, at tables that take
+/// only the player's own funds, have a USD buy-in range or tag deposits.
+pub fn join_game_v2(game: &Pubkey, player: &Pubkey, deposit: u64, extras: Extras) -> Instruction {
+    build(
+        accounts::JoinGameV2 {
+            join: join_accounts(game, player),
+            instructions_sysvar: extras.own_funds_proof.then_some(sysvar::instructions::ID),
+            price_gate: extras.usd_feed.map(|_| price_gate(game)),
+            price_feed: extras.usd_feed,
+            memo_config: extras.memo.then(|| memo_config(game)),
+            memo_program: extras.memo.then_some(crate::engine::MEMO_ID),
+        },
+        instruction::JoinGameV2 { deposit },
+    )
+}
+
+fn join_accounts(game: &Pubkey, player: &Pubkey) -> accounts::JoinGame {
+    accounts::JoinGame {
+        game: *game,
+        config: table_config(game),
+        player: *player,
+        seat: seat(game, player),
+        attestation: None,
+        invitation: None,
+        profile: profile(player),
+        club: None,
+        system_program: system_program::ID,
+    }
+}
+
+pub fn create_profile(wallet: &Pubkey, display_name: &str, avatar_uri: &str) -> Instruction {
+    build(
+        accounts::CreateProfile {
+            profile: profile(wallet),
+            name_record: name_record(display_name),
+            wallet: *wallet,
+            system_program: system_program::ID,
+        },
+        instruction::CreateProfile {
+            display_name: display_name.to_string(),
+            avatar_uri: avatar_uri.to_string(),
+        },
+    )
+}
+
+/// Deals the next hand at a cash table.
+pub fn start_round(game: &Pubkey, signer: &Pubkey) -> Instruction {
+    build(start_accounts(game, signer), instruction::StartRound {})
+}
+
+/// `start_round` for a signer that deals as a dealer bot.
+pub fn start_round_v2(game: &Pubkey, signer: &Pubkey) -> Instruction {
+    build(
+        accounts::StartGameV2 {
+            start: start_accounts(game, signer),
+            dealer_bot: Some(dealer_bot(game, signer)),
+        },
+        instruction::StartRoundV2 {},
+    )
+}
+
+fn start_accounts(game: &Pubkey, signer: &Pubkey) -> accounts::StartGame {
+    accounts::StartGame {
+        game: *game,
+        config: table_config(game),
+        tournament: None,
+        blind_structure: None,
+        signer: *signer,
+    }
+}
+
+fn player_action(game: &Pubkey, player: &Pubkey) -> accounts::PlayerAction {
+    accounts::PlayerAction {
+        game: *game,
+        config: table_config(game),
+        player: *player,
+        seat: seat(game, player),
+        history: None,
+        system_program: system_program::ID,
+    }
+}
+
+pub fn bet(game: &Pubkey, player: &Pubkey, amount: u64, ticket: ActionTicket) -> Instruction {
+    build(player_action(game, player), instruction::Bet { amount, ticket })
+}
+
+pub fn call(game: &Pubkey, player: &Pubkey, ticket: ActionTicket) -> Instruction {
+    build(player_action(game, player), instruction::Call { ticket })
+}
+
+pub fn fold(game: &Pubkey, player: &Pubkey, ticket: ActionTicket) -> Instruction {
+    build(player_action(game, player), instruction::Fold { ticket })
+}
+
+/// Lets `session_key` act for `player`'s seat for `minutes`.
+pub fn authorize_session(game: &Pubkey, player: &Pubkey, session_key: &Pubkey, minutes: u16) -> Instruction {
+    build(
+        accounts::AuthorizeSession {
+            game: *game,
+            player: *player,
+            seat: seat(game, player),
+        },
+        instruction::AuthorizeSession { session_key: *session_key, minutes },
+    )
+}
+
+/// Has a `bet`, `call` or `fold` built for a seat's owner signed by their
+/// session key instead.
+pub fn with_session_key(mut ix: Instruction, session_key: &Pubkey) -> Instruction {
+    // `player` follows `game` and `config` in `PlayerAction`
+    ix.accounts[2] = AccountMeta::new(*session_key, true);
+    ix
+}
+
+/// Cranks a cash table on. `winners` are the wallets the showdown pays,
+/// passed as writable remaining accounts.
+pub fn advance_game(game: &Pubkey, caller: &Pubkey, winners: &[Pubkey]) -> Instruction {
+    let mut ix = build(advance_accounts(game, caller), instruction::AdvanceGame {});
+    ix.accounts.extend(winners.iter().map(|winner| AccountMeta::new(*winner, false)));
+    ix
+}
+
+/// `advance_game` with the accounts `extras` asks for, at tables that
+/// register dealer bots or tag payouts, or to pay a winner through their
+/// payout route.
+pub fn advance_game_v2(game: &Pubkey, caller: &Pubkey, winners: &[Pubkey], extras: Extras) -> Instruction {
+    let mut ix = build(advance_accounts_v2(game, caller, extras), instruction::AdvanceGameV2 {});
+    ix.accounts.extend(winners.iter().map(|winner| AccountMeta::new(*winner, false)));
+    ix
+}
+
+/// The step an automation thread signed by `thread` runs on a cash table.
+pub fn run_automation(game: &Pubkey, thread: &Pubkey, extras: Extras) -> Instruction {
+    let extras = Extras { payout_route: None, ..extras };
+    build(advance_accounts_v2(game, thread, extras), instruction::RunAutomation {})
+}
+
+fn advance_accounts_v2(game: &Pubkey, caller: &Pubkey, extras: Extras) -> accounts::AdvanceGameV2 {
+    accounts::AdvanceGameV2 {
+        advance: advance_accounts(game, caller),
+        dealer_bot: extras.dealer_bot.then(|| dealer_bot(game, caller)),
+        payout_route: extras.payout_route.map(|route| payout_route(game, &route.winner)),
+        global_config: extras.payout_route.map(|_| global_config()),
+        staking_deposit: extras.payout_route.map(|route| route.deposit),
+        staking_program: extras.payout_route.map(|route| route.program),
+        memo_config: extras.memo.then(|| memo_config(game)),
+        memo_program: extras.memo.then_some(crate::engine::MEMO_ID),
+    }
+}
+
+fn advance_accounts(game: &Pubkey, caller: &Pubkey) -> accounts::AdvanceGame {
+    accounts::AdvanceGame {
+        game: *game,
+        config: table_config(game),
+        tournament: None,
+        blind_structure: None,
+        caller: *caller,
+        history: None,
+        stats_shard: None,
+        leaderboard: None,
+        table_leaderboard: None,
+        season: None,
+        club: None,
+    }
+}
+
+pub fn add_chips(game: &Pubkey, player: &Pubkey, amount: u64) -> Instruction {
+    build(chips_accounts(game, player), instruction::AddChips { amount })
+}
+
+/// `add_chips` with the accounts `extras` asks for, at tables that check
+/// or tag top-ups.
+pub fn add_chips_v2(game: &Pubkey, player: &Pubkey, amount: u64, extras: Extras) -> Instruction {
+    build(
+        accounts::AddChipsV2 {
+            chips: chips_accounts(game, player),
+            config: table_config(game),
+            instructions_sysvar: extras.own_funds_proof.then_some(sysvar::instructions::ID),
+            price_gate: extras.usd_feed.map(|_| price_gate(game)),
+            price_feed: extras.usd_feed,
+            memo_config: extras.memo.then(|| memo_config(game)),
+            memo_program: extras.memo.then_some(crate::engine::MEMO_ID),
+        },
+        instruction::AddChipsV2 { amount },
+    )
+}
+
+fn chips_accounts(game: &Pubkey, player: &Pubkey) -> accounts::AddChips {
+    accounts::AddChips {
+        game: *game,
+        player: *player,
+        seat: seat(game, player),
+        profile: profile(player),
+        system_program: system_program::ID,
+    }
+}
+
+/// Gives up `player`'s seat between hands, cashing out their stack.
+pub fn leave_table(game: &Pubkey, player: &Pubkey) -> Instruction {
+    build(leave_accounts(game, player), instruction::LeaveTable {})
+}
+
+/// `leave_table` with the memo accounts, at tables that tag withdrawals.
+pub fn leave_table_v2(game: &Pubkey, player: &Pubkey, extras: Extras) -> Instruction {
+    build(
+        accounts::LeaveTableV2 {
+            leave: leave_accounts(game, player),
+            memo_config: extras.memo.then(|| memo_config(game)),
+            memo_program: extras.memo.then_some(crate::engine::MEMO_ID),
+        },
+        instruction::LeaveTableV2 {},
+    )
+}
+
+fn leave_accounts(game: &Pubkey, player: &Pubkey) -> accounts::LeaveTable {
+    accounts::LeaveTable {
+        game: *game,
+        config: table_config(game),
+        player: *player,
+        seat: seat(game, player),
+    }
+}
+
+pub fn register_spectator(game: &Pubkey, wallet: &Pubkey) -> Instruction {
+    build(
+        accounts::RegisterSpectator {
+            game: *game,
+            spectator: spectator(game, wallet),
+            wallet: *wallet,
+            system_program: system_program::ID,
+        },
+        instruction::RegisterSpectator {},
+    )
+}
+
+pub fn tip_player(game: &Pubkey, wallet: &Pubkey, seat: u8, amount: u64) -> Instruction {
+    build(tip_accounts(game, wallet), instruction::TipPlayer { seat, amount })
+}
+
+/// `tip_player`, applying the tipper's limits and passing the memo
+/// accounts `extras` asks for.
+pub fn tip_player_v2(game: &Pubkey, wallet: &Pubkey, seat: u8, amount: u64, extras: Extras) -> Instruction {
+    build(
+        accounts::TipPlayerV2 {
+            tip: tip_accounts(game, wallet),
+            config: table_config(game),
+            profile: profile(wallet),
+            memo_config: extras.memo.then(|| memo_config(game)),
+            memo_program: extras.memo.then_some(crate::engine::MEMO_ID),
+        },
+        instruction::TipPlayerV2 { seat, amount },
+    )
+}
+
+fn tip_accounts(game: &Pubkey, wallet: &Pubkey) -> accounts::TipPlayer {
+    accounts::TipPlayer {
+        game: *game,
+        spectator: spectator(game, wallet),
+        wallet: *wallet,
+        system_program: system_program::ID,
+    }
+}
+
+/// Decodes a Borsh account such as `PlayerProfile` or `Seat` from its raw
+/// data, checking the discriminator.
+pub fn decode<T: AccountDeserialize>(mut data: &[u8]) -> Result<T> {
+    T::try_deserialize(&mut data)
+}
+
+/// Decodes a zero-copy account from its raw data, checking the
+/// discriminator. Bytes past the struct, such as a table's extensions, are
+/// ignored.
+pub fn decode_zero_copy<T: ZeroCopy + Discriminator>(data: &[u8]) -> Result<T> {
+    let disc = T::DISCRIMINATOR;
+    require!(data.starts_with(disc), ErrorCode::AccountDiscriminatorMismatch);
+    data.get(disc.len()..disc.len() + std::mem::size_of::<T>())
+        .and_then(|body| bytemuck::try_pod_read_unaligned(body).ok())
+        .ok_or_else(|| ErrorCode::AccountDidNotDeserialize.into())
+}
+
+pub fn decode_game(data: &[u8]) -> Result<Game> {
+    decode_zero_copy(data)
+}
+
+pub fn decode_table_config(data: &[u8]) -> Result<TableConfig> {
+    decode_zero_copy(data)
+}
@@ -0,0 +1,51 @@
+pub const MAX_PLAYERS: usize = 6;
+pub const MAX_HOLE_CARDS: usize = 7;
+pub const DECK_SIZE: usize = 52;
+pub const MAX_TABLES: usize = 8;
+pub const MAX_ENTRANTS: usize = MAX_PLAYERS * MAX_TABLES;
+pub const MAX_BLIND_LEVELS: usize = 16;
+pub const LAST_STUD_STREET: u8 = 4; // seventh street
+pub const MAX_ROTATION: usize = 8;
+pub const DEFAULT_TURN_TIMEOUT: i64 = 60; // seconds a player has to act
+pub const MAX_CONSECUTIVE_TIMEOUTS: u8 = 2; // forced folds in a row before a seat sits out
+pub const DEFAULT_HAND_DELAY: i64 = 5; // seconds between auto-dealt hands
+pub const STALE_TABLE_EPOCHS: u64 = 10; // idle epochs before a table can be closed
+pub const ABANDONED_HAND_TIMEOUT: i64 = 24 * 60 * 60; // seconds past a turn deadline before a hand can be voided
+pub const ICM_SCALE: u128 = 1_000_000_000_000; // fixed-point scale for ICM probabilities
+pub const MAX_PAID_PLACES: usize = 10;
+pub const NO_ELIMINATOR: u8 = u8::MAX; // `eliminated_by` entry when nobody won the busting pot
+pub const MAX_HAND_ACTIONS: usize = 64;
+pub const STATS_SHARDS: u8 = 8; // protocol stats counters settlements are spread over
+pub const HAND_ARCHIVE_DEPTH: usize = 20; // levels in a table's hand archive tree, room for about a million hands
+pub const HAND_HISTORY_RETENTION: i64 = 30 * 24 * 60 * 60; // seconds before a hand history can be closed
+// Betting instructions have taken a client nonce in their ticket since
+// version 2. Clients built before then still send the original `bet`, `call`
+// and `fold` discriminators, which now reach the `_v1` entrypoints; the
+// current ones use what Anchor would give `bet_v2` and so on.
+pub const BET_V1: [u8; 8] = [94, 203, 166, 126, 20, 243, 169, 82];
+pub const CALL_V1: [u8; 8] = [181, 94, 56, 161, 194, 221, 200, 3];
+pub const FOLD_V1: [u8; 8] = [63, 91, 162, 171, 160, 70, 220, 136];
+pub const BET_V2: [u8; 8] = [5, 72, 133, 11, 203, 203, 149, 106];
+pub const CALL_V2: [u8; 8] = [168, 106, 34, 103, 229, 149, 92, 192];
+pub const FOLD_V2: [u8; 8] = [128, 90, 51, 48, 175, 178, 6, 241];
+pub const LEADERBOARD_SIZE: usize = 10; // players ranked on each leaderboard
+pub const LIMIT_DAY: i64 = 86_400; // window a daily buy-in limit covers
+pub const LIMIT_COOLING_OFF: i64 = 86_400; // before a looser self-imposed limit applies
+pub const MAX_CLUB_NAME_LEN: usize = 32; // bytes of UTF-8
+pub const MAX_CLUB_MEMBERS: usize = 64; // wallets on one club's member list
+pub const SEASON_PAID_PLACES: usize = 5; // final standings paid from a season's prize pool
+pub const SEASON_SCORED_FINISHES: usize = 5; // tournament places that earn season points
+pub const MAX_SEASON_VENUES: usize = 16; // fits SeasonEntry::tournaments_scored
+pub const MAX_SESSION_MINUTES: u16 = 720; // longest a session key can act for a seat
+pub const MAX_TIP: u64 = 100_000_000; // lamports in one spectator tip
+pub const MAX_DEALER_BOTS: u8 = 8; // registered crank keys per table
+pub const MAX_STAKING_PROGRAMS: usize = 8; // on the winnings escrow allowlist
+pub const MAX_MEMO_TAG_LEN: usize = 64; // bytes of UTF-8
+pub const MAX_EMOTES_PER_STREET: u8 = 2; // per seat
+pub const MAX_FRIENDS: usize = 32; // wallets on one profile's friends list
+pub const MAX_NOTE_LEN: usize = 1024; // ciphertext bytes in one player note
+pub const MAX_DISPLAY_NAME_LEN: usize = 32; // bytes of UTF-8
+pub const MAX_AVATAR_URI_LEN: usize = 200;
+pub const ATTESTATION_LEN: usize = 8 + 32 + 8; // discriminator + subject + expires_at
+#[cfg(feature = "compact-events")]
+pub const EVENT_SCHEMA_VERSION: u8 = 1; // bumped when a `GameEventBody` variant changes shape
@@ -0,0 +1,289 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+use crate::constants::{MAX_HOLE_CARDS, MAX_PLAYERS};
+use crate::engine::tournament_blind_level;
+use crate::errors::PokerError;
+use crate::events::{CardsDealt, HandStarted, RandomnessSource, StreetDealt};
+use crate::rules::{evaluate, fresh_deck, pseudo_shuffle};
+use crate::state::{BlindStructure, Game, GamePhase, GameVariant, TableConfig, TableExtension, Tournament};
+
+// Shuffles, deals and opens the betting for a new hand
+pub(crate) fn begin_hand(
+    game: &mut Game,
+    extensions: &mut [u8],
+    game_key: Pubkey,
+    config: &TableConfig,
+    tournament: Option<&mut Account<Tournament>>,
+    blind_structure: Option<&Account<BlindStructure>>,
+    now: i64,
+) -> Result<()> {
+    require!(game.halted == 0, PokerError::TableHalted);
+
+    // Never deal to fewer than two players who are seated, sitting in and
+    // (at tournament tables) still have chips
+    require!(
+        game.ready_count() >= (config.min_players as usize).max(2),
+        PokerError::NotEnoughPlayers
+    );
+    game.set_phase(GamePhase::Dealing)?;
+
+    // Tournament tables pick up the blind level for the current time
+    if game.is_tournament_table() {
+        let tournament = tournament.ok_or(PokerError::TableMismatch)?;
+        require_keys_eq!(tournament.key(), game.tournament, PokerError::TableMismatch);
+
+        // Hand-for-hand: each table deals once, then waits for the others to finish
+        if tournament.hand_for_hand {
+            let index = tournament
+                .table_index(&game_key)
+                .ok_or(PokerError::TableMismatch)?;
+            require!(!tournament.hfh_dealt[index], PokerError::WaitingForTables);
+            tournament.hfh_dealt[index] = true;
+        }
+
+        if let Some((_, level)) = tournament_blind_level(tournament, blind_structure, now)? {
+            game.small_blind = level.small_blind;
+            game.big_blind = level.big_blind;
+            game.ante = level.ante;
+        }
+    }
+
+    // Cash tables on a blind schedule double the stakes every interval
+    if !game.is_tournament_table() && config.rules.blind_double_interval > 0 {
+        let doublings = (now - config.escalation_started_at) / config.rules.blind_double_interval;
+        let factor = 1u64.checked_shl(doublings.clamp(0, 63) as u32).unwrap_or(u64::MAX);
+        game.small_blind = config.base_small_blind.saturating_mul(factor);
+        game.big_blind = config.base_big_blind.saturating_mul(factor);
+    }
+
+    // Mixed games move to the next variant once the current one has run its course
+    if config.rotation_len > 0 {
+        let per_variant = match config.hands_per_variant {
+            0 => game.seated_count() as u8, // one orbit
+            n => n,
+        };
+        if game.hands_in_variant >= per_variant {
+            game.rotation_index = (game.rotation_index + 1) % config.rotation_len;
+            game.hands_in_variant = 0;
+        }
+        game.variant = config.rotation[game.rotation_index as usize];
+        game.hands_in_variant += 1;
+    }
+
+    // Top time banks back up every `time_bank_refill_hands` hands
+    if config.time_bank_refill_hands > 0 {
+        game.hands_since_refill += 1;
+        if game.hands_since_refill >= config.time_bank_refill_hands {
+            game.hands_since_refill = 0;
+            for seat in 0..MAX_PLAYERS {
+                if game.players[seat] != Pubkey::default() {
+                    game.time_banks[seat] = config.time_bank;
+                }
+            }
+        }
+    }
+
+    // Shuffle and deal cards
+    let seed = now as u64 + game_key.to_bytes()[0] as u64;
+
+    let mut deck = fresh_deck();
+    pseudo_shuffle(&mut deck, seed);
+    game.deck_seed = seed;
+    if let Some(stored) = TableExtension::StoredDeck.region(game, extensions) {
+        stored.copy_from_slice(&deck);
+    }
+
+    // Reset folded and bets; seats that can't be dealt in sit the hand out folded
+    game.dealt_in = 0;
+    game.vpip = 0;
+    game.pfr = 0;
+    for i in 0..MAX_PLAYERS {
+        game.folded[i] = u8::from(!game.is_ready(i));
+        game.dealt_in |= u8::from(game.folded[i] == 0) << i;
+    }
+    game.player_bets = [0; MAX_PLAYERS];
+    game.contributions = [0; MAX_PLAYERS];
+    game.all_in_protected = [0; MAX_PLAYERS];
+    game.crank_rewards_paid = 0;
+    game.hand_number += 1;
+    game.hand_started_at = now;
+    game.disputed = 0;
+    game.last_active_epoch = Clock::get()?.epoch;
+    game.pot = 0;
+    game.players_in_round = game.ready_count() as u8;
+
+    // Collect antes from every stack dealt in
+    if game.ante > 0 {
+        for i in 0..MAX_PLAYERS {
+            if game.players[i] != Pubkey::default() && game.folded[i] == 0 {
+                let ante = game.ante.min(game.stacks[i]);
+                game.stacks[i] -= ante;
+                game.contributions[i] += ante;
+                game.pot += ante;
+            }
+        }
+    }
+
+    // Deal hole cards (third street in stud: two down, one up)
+    let starting_cards = game.variant().starting_cards();
+    let mut deck_index = 0;
+    for i in 0..MAX_PLAYERS {
+        game.player_hands[i] = [0u8; MAX_HOLE_CARDS];
+        if game.players[i] != Pubkey::default() && game.folded[i] == 0 {
+            game.player_hands[i][..starting_cards]
+                .copy_from_slice(&deck[deck_index..deck_index + starting_cards]);
+            deck_index += starting_cards;
+        }
+    }
+
+    // Deal community cards
+    game.community_cards = [0u8; 5];
+    if game.variant().has_board() {
+        for i in 0..5 {
+            game.community_cards[i] = deck[deck_index];
+            deck_index += 1;
+        }
+    }
+    game.deck_index = deck_index as u8;
+
+    game.set_phase(GamePhase::Betting { street: 0 })?;
+    game.acted = [0; MAX_PLAYERS];
+
+    if game.variant() == GameVariant::Stud {
+        // Lowest door card posts the bring-in and action moves to their left
+        let bring_in_seat = (0..MAX_PLAYERS)
+            .filter(|&i| game.players[i] != Pubkey::default() && game.folded[i] == 0)
+            .min_by_key(|&i| {
+                let door = game.player_hands[i][2];
+                (door % 13, door / 13)
+            })
+            .ok_or(PokerError::NoActivePlayers)?;
+
+        let bring_in = game.small_blind.min(game.stacks[bring_in_seat]);
+        game.stacks[bring_in_seat] -= bring_in;
+        game.player_bets[bring_in_seat] = bring_in;
+        game.contributions[bring_in_seat] += bring_in;
+        game.pot += bring_in;
+        game.current_bet = bring_in;
+        let first = game.next_to_act(bring_in_seat as u8)?;
+        game.start_turn(first, now, config);
+    } else {
+        // Action starts with the lowest seat dealt in
+        let first = game.next_to_act((MAX_PLAYERS - 1) as u8)?;
+        game.start_turn(first, now, config);
+        game.current_bet = game.big_blind; // Start betting at big blind
+    }
+
+    emit_event!(HandStarted {
+        game: game_key,
+        seq: game.state_seq,
+        hand_id: hand_id(&game_key, game.hand_number),
+        hand_number: game.hand_number,
+        variant: game.variant(),
+        players: game.players_in_round,
+    });
+    emit_event!(CardsDealt {
+        game: game_key,
+        seq: game.state_seq,
+        hand_id: hand_id(&game_key, game.hand_number),
+        hand_number: game.hand_number,
+        street: 0,
+        source: RandomnessSource::ClockSeed,
+        request_slot: Clock::get()?.slot,
+        deck_commitment: deck_commitment(seed, &deck),
+    });
+
+    Ok(())
+}
+
+// Deals the next stud street and opens its betting
+pub(crate) fn deal_next_street(
+    game: &mut Game,
+    extensions: &mut [u8],
+    game_key: Pubkey,
+    config: &TableConfig,
+    now: i64,
+) -> Result<()> {
+    let street = game.street();
+    game.set_phase(GamePhase::Dealing)?;
+
+    let mut deck = fresh_deck();
+    match TableExtension::StoredDeck.region(game, extensions) {
+        Some(stored) => deck.copy_from_slice(stored),
+        None => pseudo_shuffle(&mut deck, game.deck_seed),
+    }
+
+    // Fourth through sixth street are dealt face up, seventh face down
+    let slot = street as usize + 3;
+    let mut deck_index = game.deck_index as usize;
+    for i in 0..MAX_PLAYERS {
+        if game.players[i] != Pubkey::default() && game.folded[i] == 0 {
+            game.player_hands[i][slot] = deck[deck_index];
+            deck_index += 1;
+        }
+    }
+    game.deck_index = deck_index as u8;
+    game.set_phase(GamePhase::Betting { street: street + 1 })?;
+
+    game.player_bets = [0; MAX_PLAYERS];
+    game.current_bet = 0;
+    game.acted = [0; MAX_PLAYERS];
+
+    // Up cards sit in slots 2..6
+    let up_cards = (street as usize + 2).min(4);
+    let first = (0..MAX_PLAYERS)
+        .filter(|&i| game.players[i] != Pubkey::default() && game.folded[i] == 0 && game.can_act(i))
+        .max_by_key(|&i| (evaluate(&game.player_hands[i][2..2 + up_cards]), MAX_PLAYERS - i));
+    let first = match first {
+        Some(seat) => seat as u8,
+        // Everyone left is all-in; the crank runs the hand out
+        None => next_active_player(&game.players, &game.folded, (MAX_PLAYERS - 1) as u8)?,
+    };
+    game.start_turn(first, now, config);
+
+    emit_event!(StreetDealt {
+        game: game_key,
+        seq: game.state_seq,
+        hand_id: hand_id(&game_key, game.hand_number),
+        hand_number: game.hand_number,
+        street: street + 1,
+    });
+    emit_event!(CardsDealt {
+        game: game_key,
+        seq: game.state_seq,
+        hand_id: hand_id(&game_key, game.hand_number),
+        hand_number: game.hand_number,
+        street: street + 1,
+        source: RandomnessSource::ClockSeed,
+        request_slot: Clock::get()?.slot,
+        deck_commitment: deck_commitment(game.deck_seed, &deck),
+    });
+
+    Ok(())
+}
+
+// Utility function to get next active player's turn. Seats left out of the
+// hand are marked folded, so the one-byte check goes before the key compare.
+pub(crate) fn next_active_player(players: &[Pubkey; MAX_PLAYERS], folded: &[u8; MAX_PLAYERS], current_turn: u8) -> Result<u8> {
+    let mut next = current_turn;
+    for _ in 0..MAX_PLAYERS {
+        next = (next + 1) % (MAX_PLAYERS as u8);
+        if folded[next as usize] == 0 && players[next as usize] != Pubkey::default() {
+            return Ok(next);
+        }
+    }
+    Err(PokerError::NoActivePlayers.into())
+}
+
+// `sha256(game || hand_number)`: names a hand uniquely across every table,
+// for indexers joining events and histories
+pub(crate) fn hand_id(game: &Pubkey, hand_number: u64) -> [u8; 32] {
+    hashv(&[game.as_ref(), &hand_number.to_le_bytes()]).to_bytes()
+}
+
+// Hash of the shuffle seed and the order it produced, so a revealed seed can
+// be checked against what was dealt
+fn deck_commitment(seed: u64, deck: &[u8]) -> [u8; 32] {
+    hashv(&[&seed.to_le_bytes(), deck]).to_bytes()
+}
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program::invoke_unchecked;
+
+use crate::errors::PokerError;
+use crate::state::{GlobalConfig, PayoutRoute};
+
+// Where a cash pot goes: the winner's wallet, or the deposit their payout
+// route names when the settlement passes one
+pub(crate) struct Payee<'a, 'info> {
+    pub wallet: &'a AccountInfo<'info>,
+    pub escrow: Option<Escrow<'a, 'info>>,
+}
+
+// A winner's payout route and the accounts paying through it takes
+pub(crate) struct Escrow<'a, 'info> {
+    pub route: &'a PayoutRoute,
+    pub global_config: &'a GlobalConfig,
+    pub deposit: &'a AccountInfo<'info>,
+    pub program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> Escrow<'a, 'info> {
+    // Gathers the settling instruction's optional route accounts. Without a
+    // route the winner is paid directly; with one, the rest must come too.
+    pub(crate) fn from_accounts(
+        route: Option<&'a PayoutRoute>,
+        global_config: Option<&'a GlobalConfig>,
+        deposit: Option<&'a AccountInfo<'info>>,
+        program: Option<&'a AccountInfo<'info>>,
+    ) -> Result<Option<Self>> {
+        let Some(route) = route else {
+            return Ok(None);
+        };
+        match (global_config, deposit, program) {
+            (Some(global_config), Some(deposit), Some(program)) => Ok(Some(Self { route, global_config, deposit, program })),
+            _ => err!(PokerError::InvalidPayoutRoute),
+        }
+    }
+}
+
+// Pays cash winnings into the winner's deposit at their staking program
+// instead of their wallet, then calls the program's deposit hook to book
+// them. Returns false, so the wallet is paid instead, when the settlement
+// passes no route or the route's program has been taken off the allowlist
+// since it was set; neither may hold up the table.
+pub(crate) fn pay_into_escrow<'info>(
+    game_info: &AccountInfo<'info>,
+    wallet_info: &AccountInfo<'info>,
+    amount: u64,
+    escrow: Option<&Escrow<'_, 'info>>,
+) -> Result<bool> {
+    let Some(Escrow { route, global_config, deposit, program }) = escrow else {
+        return Ok(false);
+    };
+    require_keys_eq!(route.wallet, wallet_info.key(), PokerError::InvalidPayoutRoute);
+    let Some(staking) = global_config.staking_program(&route.program).copied() else {
+        return Ok(false);
+    };
+    require!(
+        *deposit.key == route.deposit && deposit.owner == &route.program && deposit.is_writable,
+        PokerError::InvalidPayoutRoute
+    );
+    require!(*program.key == route.program && program.executable, PokerError::InvalidPayoutRoute);
+
+    **game_info.try_borrow_mut_lamports()? -= amount;
+    **deposit.try_borrow_mut_lamports()? += amount;
+
+    // The table goes along because the runtime checks that the lamports
+    // balance across the accounts the two programs share. Its data is still
+    // borrowed by the settlement, hence the unchecked call; the hook cannot
+    // write to an account it does not own.
+    let mut data = staking.deposit_ix.to_vec();
+    data.extend_from_slice(&amount.to_le_bytes());
+    let hook = Instruction {
+        program_id: route.program,
+        accounts: vec![
+            AccountMeta::new(game_info.key(), false),
+            AccountMeta::new(route.deposit, false),
+            AccountMeta::new_readonly(route.wallet, false),
+        ],
+        data,
+    };
+    invoke_unchecked(&hook, &[game_info.clone(), (*deposit).clone(), wallet_info.clone(), (*program).clone()])?;
+    Ok(true)
+}
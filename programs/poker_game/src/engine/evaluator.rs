@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_PLAYERS;
+use crate::rules::best_hand;
+use crate::state::Game;
+
+// Hand strength of every non-folded seat, None for empty or folded seats
+pub(crate) fn showdown_scores(game: &Game) -> [Option<u32>; MAX_PLAYERS] {
+    let hole_cards = game.variant().hole_cards();
+    let mut scores = [None; MAX_PLAYERS];
+    for (i, score) in scores.iter_mut().enumerate() {
+        if game.players[i] != Pubkey::default() && game.folded[i] == 0 {
+            *score = Some(best_hand(
+                game.variant(),
+                &game.player_hands[i][..hole_cards],
+                &game.community_cards,
+            ));
+        }
+    }
+    scores
+}
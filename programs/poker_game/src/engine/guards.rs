@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::instruction::{get_stack_height, TRANSACTION_LEVEL_STACK_HEIGHT};
+use anchor_lang::solana_program::sysvar::instructions;
+use anchor_lang::system_program;
+
+use crate::constants::ATTESTATION_LEN;
+use crate::errors::PokerError;
+use crate::state::{CrankStep, DealerBot, Game, PlayerProfile, TableConfig};
+
+// Rejects a second action within `window` slots of the last one, then
+// records this one. Failed transactions leave no trace on chain, so this
+// throttles successful calls; a zero window disables it.
+pub(crate) fn rate_limit(last_slot: &mut u64, window: u64, slot: u64) -> Result<()> {
+    if window > 0 && *last_slot > 0 {
+        require!(slot >= *last_slot + window, PokerError::RateLimited);
+    }
+    *last_slot = slot;
+    Ok(())
+}
+
+// Checks a compliance pass: an account owned by the gatekeeper program
+// laid out as the Anchor discriminator of an `Attestation` account, the
+// subject wallet and an i64 expiry timestamp. The gatekeeper decides who
+// gets one (allow/deny lists, geo-fencing); the table only checks that it
+// is valid right now.
+pub(crate) fn check_attestation(attestation: &AccountInfo, gatekeeper: &Pubkey, player: &Pubkey, now: i64) -> Result<()> {
+    require_keys_eq!(*attestation.owner, *gatekeeper, PokerError::InvalidAttestation);
+
+    // Anything else the gatekeeper owns could carry a wallet at the same offset
+    let data = attestation.try_borrow_data()?;
+    require!(data.len() >= ATTESTATION_LEN, PokerError::InvalidAttestation);
+    require!(data[..8] == hash(b"account:Attestation").to_bytes()[..8], PokerError::InvalidAttestation);
+    require!(data[8..40] == player.to_bytes(), PokerError::InvalidAttestation);
+
+    let expires_at = i64::from_le_bytes(data[40..48].try_into().unwrap());
+    require!(now < expires_at, PokerError::AttestationExpired);
+
+    Ok(())
+}
+
+// Reads a wallet's profile from its PDA, which may not have been created
+pub(crate) fn load_profile(info: &AccountInfo) -> Result<Option<PlayerProfile>> {
+    if info.owner != &crate::ID || info.data_is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(PlayerProfile::try_deserialize(&mut &info.try_borrow_data()?[..])?))
+}
+
+// Checks a buy-in against the player's self-imposed limits, if they have a
+// profile, and records it there
+pub(crate) fn charge_buy_in(info: &AccountInfo, profile: Option<&mut PlayerProfile>, amount: u64, now: i64) -> Result<()> {
+    if let Some(profile) = profile {
+        profile.limits.charge(amount, now)?;
+        profile.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+    }
+    Ok(())
+}
+
+const COMPUTE_BUDGET_ID: Pubkey = pubkey!("ComputeBudget111111111111111111111111111111");
+
+// Flash-loan guard for tables that demand own funds. A deposit there must be
+// a top-level instruction in a transaction that calls nothing but this
+// program, the System Program and the Compute Budget program, so no lending
+// protocol can lend the lamports in ahead of it and take them back after.
+pub(crate) fn require_own_funds(config: &TableConfig, sysvar: Option<&AccountInfo>) -> Result<()> {
+    if config.own_funds_only == 0 {
+        return Ok(());
+    }
+    require!(get_stack_height() == TRANSACTION_LEVEL_STACK_HEIGHT, PokerError::OwnFundsRequired);
+
+    let sysvar = sysvar.ok_or(PokerError::OwnFundsRequired)?;
+    let mut index = 0;
+    while let Ok(ix) = instructions::load_instruction_at_checked(index, sysvar) {
+        require!(
+            ix.program_id == crate::ID || ix.program_id == system_program::ID || ix.program_id == COMPUTE_BUDGET_ID,
+            PokerError::OwnFundsRequired
+        );
+        index += 1;
+    }
+    Ok(())
+}
+
+// Whether the caller's `DealerBot`, if it has one for the table, allows
+// `step`. The account's seeds already tie it to the table and the caller.
+pub(crate) fn bot_allows(bot: Option<&DealerBot>, step: CrankStep) -> bool {
+    bot.is_some_and(|bot| bot.scopes.allows(step))
+}
+
+// Once a table registers dealer bots, only its host, its seated players
+// and bots scoped for `step` may crank it. Tables without bots stay open
+// to anyone.
+pub(crate) fn require_crank_scope(config: &TableConfig, game: &Game, caller: &Pubkey, bot: Option<&DealerBot>, step: CrankStep) -> Result<()> {
+    if config.dealer_bots == 0 || config.is_host(caller) || game.players.contains(caller) {
+        return Ok(());
+    }
+    require!(bot_allows(bot, step), PokerError::CrankNotAuthorized);
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program::invoke;
+
+use crate::errors::PokerError;
+use crate::state::{FundMovement, MemoConfig, TableConfig};
+
+pub(crate) const MEMO_ID: Pubkey = pubkey!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+
+/// The SPL Memo program, for `Program` accounts.
+#[derive(Clone)]
+pub struct Memo;
+
+impl anchor_lang::Id for Memo {
+    fn id() -> Pubkey {
+        MEMO_ID
+    }
+}
+
+// A table's memo settings and the accounts writing a memo takes, as
+// declared by the instructions that move its funds
+#[derive(Clone, Copy)]
+pub(crate) struct Memos<'a, 'info> {
+    movements: u8,
+    config: Option<&'a MemoConfig>,
+    program: Option<&'a AccountInfo<'info>>,
+}
+
+impl<'a, 'info> Memos<'a, 'info> {
+    pub(crate) fn new(table: &TableConfig, config: Option<&'a MemoConfig>, program: Option<&'a AccountInfo<'info>>) -> Self {
+        Self { movements: table.memo_movements, config, program }
+    }
+
+    /// For the entrypoints whose account lists predate memos: tags nothing.
+    pub(crate) fn none() -> Self {
+        Self { movements: 0, config: None, program: None }
+    }
+}
+
+// Logs `<tag>:<movement>:<wallet>:<lamports>` through the SPL Memo program
+// when the table's operator tags this kind of movement, which needs the
+// table's `MemoConfig` and the Memo program
+pub(crate) fn write_memo(memos: Memos, movement: FundMovement, wallet: &Pubkey, amount: u64) -> Result<()> {
+    if memos.movements & movement.bit() == 0 || amount == 0 {
+        return Ok(());
+    }
+    let (Some(config), Some(program)) = (memos.config, memos.program) else {
+        return err!(PokerError::MemoRequired);
+    };
+
+    let memo = format!("{}:{}:{}:{}", config.tag, movement.label(), wallet, amount);
+    invoke(
+        &Instruction { program_id: MEMO_ID, accounts: vec![], data: memo.into_bytes() },
+        std::slice::from_ref(program),
+    )?;
+    Ok(())
+}
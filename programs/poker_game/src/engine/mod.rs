@@ -0,0 +1,17 @@
+mod deal;
+mod escrow;
+mod evaluator;
+mod guards;
+mod memo;
+mod oracle;
+mod settle;
+mod tournament;
+
+pub(crate) use deal::*;
+pub(crate) use escrow::*;
+pub(crate) use evaluator::*;
+pub(crate) use guards::*;
+pub(crate) use memo::*;
+pub(crate) use oracle::*;
+pub(crate) use settle::*;
+pub(crate) use tournament::*;
@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+
+use crate::errors::PokerError;
+use crate::state::{PriceGate, TableConfig};
+
+// Pyth's receiver program, which owns every `PriceUpdateV2` account
+const PYTH_RECEIVER_ID: Pubkey = pubkey!("rec5EKMGg6MxZYaMdyBfgwp4d5rB9T1VQH5pJv5LtFJ");
+// Pyth's price feed id for SOL/USD. The receiver owns updates for every
+// feed, so the id is what tells a SOL price from any other.
+const SOL_USD_FEED_ID: [u8; 32] = [
+    0xef, 0x0d, 0x8b, 0x6f, 0xda, 0x2c, 0xeb, 0xa4, 0x1d, 0xa1, 0x5d, 0x40, 0x95, 0xd1, 0xda, 0x39,
+    0x2a, 0x0d, 0x2f, 0x8e, 0xd0, 0xc6, 0xc7, 0xbc, 0x0f, 0x4c, 0xfa, 0xc8, 0xc2, 0x80, 0xb5, 0x6d,
+];
+const LAMPORTS_PER_SOL: u128 = 1_000_000_000;
+// Widest confidence interval accepted, in basis points of the price; a
+// wider one means publishers disagree too much to price a buy-in
+const MAX_CONFIDENCE_BPS: u128 = 200;
+
+/// SOL/USD as Pyth publishes it: `price * 10^exponent` dollars.
+pub(crate) struct SolPrice {
+    pub price: u64,
+    pub exponent: i32,
+    pub publish_time: i64,
+}
+
+impl SolPrice {
+    // Cents as lamports, rounding down and saturating
+    pub fn lamports(&self, cents: u64) -> u64 {
+        let (num, den) = self.scale();
+        mul_div(cents as u128 * LAMPORTS_PER_SOL, num, den.saturating_mul(100))
+    }
+
+    // Lamports as cents, rounding down and saturating
+    pub fn cents(&self, lamports: u64) -> u64 {
+        let (num, den) = self.scale();
+        mul_div(lamports as u128 * 100, den, LAMPORTS_PER_SOL * num)
+    }
+
+    // The price of a SOL as the fraction `den / num` of a dollar
+    fn scale(&self) -> (u128, u128) {
+        let shift = 10u128.pow(self.exponent.unsigned_abs().min(18));
+        if self.exponent < 0 {
+            (shift, self.price as u128)
+        } else {
+            (1, (self.price as u128).saturating_mul(shift))
+        }
+    }
+}
+
+fn mul_div(value: u128, num: u128, den: u128) -> u64 {
+    value
+        .checked_mul(num)
+        .and_then(|product| u64::try_from(product / den).ok())
+        .unwrap_or(u64::MAX)
+}
+
+// Checks that `feed` is a fully verified Pyth `PriceUpdateV2` for SOL/USD.
+// The layout is the discriminator, write authority and verification level,
+// then the price message's feed id, price, confidence, exponent and publish
+// time. Only a fully verified update (level 1) is laid out at these offsets;
+// a partial one carries an extra byte.
+pub(crate) fn check_sol_feed(feed: &AccountInfo) -> Result<()> {
+    require_keys_eq!(*feed.owner, PYTH_RECEIVER_ID, PokerError::InvalidPriceFeed);
+
+    let data = feed.try_borrow_data()?;
+    require!(data.len() >= 101, PokerError::InvalidPriceFeed);
+    require!(data[..8] == hash(b"account:PriceUpdateV2").to_bytes()[..8], PokerError::InvalidPriceFeed);
+    require!(data[40] == 1, PokerError::InvalidPriceFeed);
+    require!(data[41..73] == SOL_USD_FEED_ID, PokerError::InvalidPriceFeed);
+    Ok(())
+}
+
+// Reads the price from a fully verified SOL/USD update, refusing one that
+// is stale or whose confidence interval is too wide
+pub(crate) fn read_sol_price(feed: &AccountInfo, max_age: i64, now: i64) -> Result<SolPrice> {
+    check_sol_feed(feed)?;
+
+    let data = feed.try_borrow_data()?;
+    let price = i64::from_le_bytes(data[73..81].try_into().unwrap());
+    let confidence = u64::from_le_bytes(data[81..89].try_into().unwrap());
+    let exponent = i32::from_le_bytes(data[89..93].try_into().unwrap());
+    let publish_time = i64::from_le_bytes(data[93..101].try_into().unwrap());
+    require!(price > 0, PokerError::InvalidPriceFeed);
+    require!(now - publish_time <= max_age, PokerError::StalePrice);
+    require!(
+        confidence as u128 * 10_000 <= price as u128 * MAX_CONFIDENCE_BPS,
+        PokerError::PriceUncertain
+    );
+
+    Ok(SolPrice { price: price as u64, exponent, publish_time })
+}
+
+// At tables with a USD buy-in range, checks the stack a deposit leaves
+// against the range converted at the current price. Top-ups are only held
+// to the maximum.
+pub(crate) fn check_usd_buy_in(
+    config: &TableConfig,
+    gate: Option<&PriceGate>,
+    feed: Option<&AccountInfo>,
+    stack: u64,
+    top_up: bool,
+    now: i64,
+) -> Result<()> {
+    if config.usd_buy_in == 0 {
+        return Ok(());
+    }
+    let (Some(gate), Some(feed)) = (gate, feed) else {
+        return err!(PokerError::InvalidPriceFeed);
+    };
+    require_keys_eq!(*feed.key, gate.feed, PokerError::InvalidPriceFeed);
+    let price = read_sol_price(feed, gate.max_age, now)?;
+
+    if !top_up && gate.min_buy_in_usd > 0 {
+        require!(stack >= price.lamports(gate.min_buy_in_usd), PokerError::BuyInOutOfRange);
+    }
+    if gate.max_buy_in_usd > 0 {
+        require!(stack <= price.lamports(gate.max_buy_in_usd), PokerError::BuyInOutOfRange);
+    }
+    Ok(())
+}
@@ -0,0 +1,311 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{MAX_PLAYERS, STATS_SHARDS};
+use crate::engine::{hand_id, pay_into_escrow, showdown_scores, write_memo, Memos, Payee};
+use crate::errors::PokerError;
+use crate::events::{CrankReason, CrankRun, PlayerLeft, PotAwarded, ShowdownResult};
+use crate::rules::{rating_change, stake_k, ROYAL_FLUSH};
+use crate::state::{Achievement, Club, FundMovement, Game, GamePhase, Leaderboard, PlayerProfile, Season, SeasonEntry, Seat, StatsShard, TableConfig};
+
+// Pays the pot to `winner_index` and ends the hand, returning what was paid
+pub(crate) fn award_pot<'info>(
+    game: &mut Game,
+    game_info: &AccountInfo<'info>,
+    winner_index: usize,
+    payee: Option<Payee<'_, 'info>>,
+    now: i64,
+    stats_shard: Option<&mut Account<'info, StatsShard>>,
+    memos: Memos,
+) -> Result<u64> {
+    if let Some(payee) = &payee {
+        require_keys_eq!(payee.wallet.key(), game.players[winner_index], PokerError::PlayerNotInGame);
+    }
+    game.mark_settled()?;
+    game.set_phase(GamePhase::Settling)?;
+
+    game.last_winner = game.players[winner_index];
+    game.hand_ended_at = now;
+
+    emit_event!(ShowdownResult {
+        game: game_info.key(),
+        seq: game.state_seq,
+        hand_id: hand_id(&game_info.key(), game.hand_number),
+        hand_number: game.hand_number,
+        winner: game.players[winner_index],
+        seat: winner_index as u8,
+        live_players: game.live_count() as u8,
+    });
+
+    // A winner who is all-in, or kept in under all-in protection, can only
+    // win what they matched; the rest goes back to whoever put it in
+    if game.all_in_protected[winner_index] != 0 || game.stacks[winner_index] == 0 {
+        let matched = game.contributions[winner_index];
+        for seat in 0..MAX_PLAYERS {
+            let over = game.contributions[seat].saturating_sub(matched);
+            if over > 0 {
+                game.stacks[seat] += over;
+                game.contributions[seat] -= over;
+                game.pot -= over;
+            }
+        }
+    }
+
+    // Tournament pots are paid in chips, with the lamports left in the prize
+    // pool; a cash pot goes to the winner's wallet or payout route, or stays
+    // on their stack when settled without the wallet
+    match payee {
+        Some(Payee { wallet: winner_info, escrow }) if !game.is_tournament_table() => {
+            let escrowed = game.escrow_seats & (1 << winner_index) != 0
+                && pay_into_escrow(game_info, winner_info, game.pot, escrow.as_ref())?;
+            if !escrowed {
+                **game_info.try_borrow_mut_lamports()? -= game.pot;
+                **winner_info.try_borrow_mut_lamports()? += game.pot;
+            }
+            write_memo(memos, FundMovement::Payout, &winner_info.key(), game.pot)?;
+        }
+        _ => game.stacks[winner_index] += game.pot,
+    }
+
+    let rake = game.crank_rewards_paid;
+    let duration = now - game.hand_started_at;
+    let pot = game.pot;
+    game.stats.record(pot + rake, rake, duration);
+    if let Some(shard) = stats_shard {
+        shard.pending.record_pot(pot + rake, rake);
+    }
+
+    emit_event!(PotAwarded {
+        game: game_info.key(),
+        seq: game.state_seq,
+        hand_id: hand_id(&game_info.key(), game.hand_number),
+        hand_number: game.hand_number,
+        winner: game.players[winner_index],
+        amount: game.pot,
+    });
+
+    let paid = game.pot;
+    game.pot = 0;
+    game.set_phase(GamePhase::WaitingForPlayers)?;
+
+    Ok(paid)
+}
+
+// Adds a settled hand to the lifetime stats of the profiles of players
+// dealt into it. Once any profile is passed, the profile address of every
+// player dealt in must be too, created or not, so whoever settles can't
+// pick whose rating and stats move. When the winner has a profile, it
+// takes rating points from each of the others and is marked for any
+// achievement the hand earned.
+pub(crate) fn record_profiles<'info>(
+    game: &Game,
+    winner_index: usize,
+    won: u64,
+    profile_infos: &'info [AccountInfo<'info>],
+) -> Result<()> {
+    if !profile_infos.iter().any(holds::<PlayerProfile>) {
+        return Ok(());
+    }
+
+    let showdown = game.live_count() > 1;
+    let mut profiles = Vec::with_capacity(MAX_PLAYERS);
+    for seat in (0..MAX_PLAYERS).filter(|&i| game.dealt_in & (1 << i) != 0) {
+        let address = Pubkey::find_program_address(&[b"profile", game.players[seat].as_ref()], &crate::ID).0;
+        let info = profile_infos
+            .iter()
+            .find(|info| info.key == &address)
+            .ok_or(PokerError::ProfileMismatch)?;
+        if holds::<PlayerProfile>(info) {
+            profiles.push((seat, Account::<PlayerProfile>::try_from(info)?));
+        }
+    }
+
+    if let Some(winner) = profiles.iter().position(|(seat, _)| *seat == winner_index) {
+        let k = stake_k(won, game.big_blind);
+        let winner_rating = profiles[winner].1.rating;
+        let mut gained = 0u16;
+        for (seat, profile) in profiles.iter_mut() {
+            if *seat != winner_index {
+                let change = rating_change(winner_rating, profile.rating, k);
+                profile.rating = profile.rating.saturating_sub(change);
+                gained = gained.saturating_add(change);
+            }
+        }
+        let winner = &mut profiles[winner].1;
+        winner.rating = winner.rating.saturating_add(gained);
+        if showdown && showdown_scores(game)[winner_index] == Some(ROYAL_FLUSH) {
+            winner.achievements |= Achievement::RoyalFlush.bit();
+        }
+    }
+
+    for (seat, profile) in profiles.iter_mut() {
+        let seat_bit = 1 << *seat;
+        profile.stats.record(
+            (*seat == winner_index).then_some(won),
+            showdown,
+            game.vpip & seat_bit != 0,
+            game.pfr & seat_bit != 0,
+        );
+        profile.exit(&crate::ID)?;
+    }
+    Ok(())
+}
+
+// Adds season points for the players dealt in, when the season is passed,
+// running and lists this table. Every dealt-in player's entry address must
+// be among the remaining accounts, registered or not, so whoever settles
+// can't withhold a rival's points.
+pub(crate) fn record_season<'info>(
+    season: Option<&mut Box<Account<'info, Season>>>,
+    game_key: Pubkey,
+    game: &Game,
+    winner_index: usize,
+    entry_infos: &'info [AccountInfo<'info>],
+    now: i64,
+) -> Result<()> {
+    let Some(season) = season else {
+        return Ok(());
+    };
+    if !season.is_active(now) || season.venue_index(&game_key).is_none() {
+        return Ok(());
+    }
+
+    let season_key = season.key();
+    for seat in (0..MAX_PLAYERS).filter(|&i| game.dealt_in & (1 << i) != 0) {
+        let address = Pubkey::find_program_address(
+            &[b"season_entry", season_key.as_ref(), game.players[seat].as_ref()],
+            &crate::ID,
+        )
+        .0;
+        let info = entry_infos
+            .iter()
+            .find(|info| info.key == &address)
+            .ok_or(PokerError::SeasonEntryMismatch)?;
+        if !holds::<SeasonEntry>(info) {
+            continue;
+        }
+
+        let mut entry = Account::<SeasonEntry>::try_from(info)?;
+        let mut points = season.weights.hand_played as u64;
+        if seat == winner_index {
+            points += season.weights.hand_won as u64;
+        }
+        season.award(&mut entry, points);
+        entry.exit(&crate::ID)?;
+    }
+    Ok(())
+}
+
+// Remaining accounts at settlement mix profiles and season entries; each
+// recorder picks out its own by owner and discriminator
+fn holds<T: Discriminator>(info: &AccountInfo) -> bool {
+    info.owner == &crate::ID && info.try_borrow_data().is_ok_and(|data| data.starts_with(T::DISCRIMINATOR))
+}
+
+// Pays the table's crank reward out of a cash pot, up to the per-hand cap.
+// Tournament pots are chips, so their cranks go unpaid.
+pub(crate) fn pay_crank_reward<'info>(
+    game: &mut Game,
+    game_info: &AccountInfo<'info>,
+    config: &TableConfig,
+    caller: &AccountInfo<'info>,
+    memos: Memos,
+) -> Result<()> {
+    if game.is_tournament_table() {
+        return Ok(());
+    }
+
+    let cap_left = config.rules.crank_reward_cap.saturating_sub(game.crank_rewards_paid);
+    let reward = config.rules.crank_reward.min(cap_left).min(game.pot);
+    if reward == 0 {
+        return Ok(());
+    }
+
+    **game_info.try_borrow_mut_lamports()? -= reward;
+    **caller.try_borrow_mut_lamports()? += reward;
+    game.pot -= reward;
+    game.crank_rewards_paid += reward;
+
+    write_memo(memos, FundMovement::Rake, &caller.key(), reward)
+}
+
+// Gives up `seat` between hands, paying its stack from the table to the
+// owner's wallet
+pub(crate) fn cash_out<'info>(
+    game: &mut Game,
+    game_info: &AccountInfo<'info>,
+    seat: usize,
+    wallet: &AccountInfo<'info>,
+    memos: Memos,
+) -> Result<u64> {
+    require_keys_eq!(wallet.key(), game.players[seat], PokerError::PlayerNotInGame);
+    let stack = game.vacate_seat(seat);
+    **game_info.try_borrow_mut_lamports()? -= stack;
+    **wallet.try_borrow_mut_lamports()? += stack;
+
+    emit_event!(PlayerLeft {
+        game: game_info.key(),
+        seq: game.state_seq,
+        hand_id: hand_id(&game_info.key(), game.hand_number),
+        player: wallet.key(),
+        seat: seat as u8,
+        cashed_out: stack,
+    });
+    write_memo(memos, FundMovement::Withdrawal, &wallet.key(), stack)?;
+    Ok(stack)
+}
+
+// Cashes out every seated player, for instructions that clear the table.
+// `seats` holds each seated player's wallet followed by their `Seat`, in
+// seat order; the seats are closed to the wallets.
+pub(crate) fn cash_out_all<'info>(
+    game: &mut Game,
+    game_info: &AccountInfo<'info>,
+    seats: &'info [AccountInfo<'info>],
+    memos: Memos,
+) -> Result<()> {
+    let mut pairs = seats.chunks_exact(2);
+    for index in 0..MAX_PLAYERS {
+        if game.players[index] == Pubkey::default() {
+            continue;
+        }
+        let pair = pairs.next().ok_or(PokerError::MissingSeatAccounts)?;
+        let (wallet, seat) = (&pair[0], Account::<Seat>::try_from(&pair[1])?);
+        require!(
+            seat.game == game_info.key() && seat.owner == wallet.key() && seat.index as usize == index,
+            PokerError::SeatMismatch
+        );
+        cash_out(game, game_info, index, wallet, memos)?;
+        seat.close(wallet.clone())?;
+    }
+    Ok(())
+}
+
+// Adds the pot just awarded to the table's club stats when the club was passed
+pub(crate) fn record_club(club: Option<&mut Box<Account<Club>>>, game: &Game, won: u64) {
+    if let Some(club) = club {
+        club.stats.record_pot(won + game.crank_rewards_paid, game.crank_rewards_paid);
+    }
+}
+
+// Credits the pot's winner on whichever leaderboards were passed
+pub(crate) fn record_leaderboards(winner: Pubkey, won: u64, boards: [Option<&mut Box<Account<Leaderboard>>>; 2]) {
+    for board in boards.into_iter().flatten() {
+        board.record_win(winner, won);
+    }
+}
+
+// Reports an automated step so operators can watch their cranks
+pub(crate) fn emit_crank(game: &Game, game_key: Pubkey, crank: Pubkey, reason: CrankReason) {
+    emit_event!(CrankRun {
+        game: game_key,
+        seq: game.state_seq,
+        hand_id: hand_id(&game_key, game.hand_number),
+        crank,
+        reason,
+    });
+}
+
+// Stats shard a table writes to, spreading write locks across shards
+pub(crate) fn stats_shard_for(game: &Pubkey) -> u8 {
+    game.to_bytes()[0] % STATS_SHARDS
+}
@@ -0,0 +1,331 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::constants::{ICM_SCALE, MAX_ENTRANTS, MAX_HOLE_CARDS, MAX_PLAYERS};
+use crate::errors::PokerError;
+use crate::rules::pseudo_shuffle;
+use crate::state::{BlindLevel, BlindStructure, Game, GameVariant, TableConfig, TableStats, Ticket, Tournament, TournamentStatus};
+
+// Seat draw that starts a tournament: shuffles entrants with the seed built
+// up during registration, deals them round-robin across the tables passed
+// in and opens play. Registration order says nothing about where anyone sits.
+pub(crate) fn draw_seats<'info>(
+    tournament: &mut Account<'info, Tournament>,
+    remaining_accounts: &'info [AccountInfo<'info>],
+    now: i64,
+) -> Result<()> {
+    require!(
+        tournament.status == TournamentStatus::Registering,
+        PokerError::RegistrationClosed
+    );
+    require!(now >= tournament.start_time, PokerError::TournamentNotStarted);
+    require!(
+        tournament.entrant_count >= tournament.min_entrants.max(2),
+        PokerError::NotEnoughEntrants
+    );
+
+    let tables = load_tournament_tables(tournament, remaining_accounts)?;
+    let table_count = tables.len();
+    require!(
+        tournament.entrant_count as usize <= table_count * MAX_PLAYERS,
+        PokerError::TooFewTables
+    );
+
+    let mut order: [u8; MAX_ENTRANTS] = std::array::from_fn(|i| i as u8);
+    let order = &mut order[..tournament.entrant_count as usize];
+    pseudo_shuffle(order, tournament.seat_seed);
+
+    // Deal entrants round-robin so table sizes never differ by more than one
+    for (i, &entrant) in order.iter().enumerate() {
+        let mut table = tables[i % table_count].load_mut()?;
+        let seat = i / table_count;
+        table.players[seat] = tournament.entrants[entrant as usize];
+        table.stacks[seat] = tournament.starting_stack;
+        table.players_in_round += 1;
+    }
+
+    for table in &tables {
+        table.load_mut()?.next_seq();
+    }
+
+    tournament.players_remaining = tournament.entrant_count;
+    tournament.status = TournamentStatus::Running;
+    tournament.round_tables = tournament.table_count;
+    tournament.started_at = now;
+    tournament.refresh_hand_for_hand();
+
+    Ok(())
+}
+
+// Blind level currently in effect for a tournament, or None when it plays flat blinds
+pub(crate) fn tournament_blind_level(
+    tournament: &Account<Tournament>,
+    structure: Option<&Account<BlindStructure>>,
+    now: i64,
+) -> Result<Option<(usize, BlindLevel)>> {
+    if tournament.blind_structure == Pubkey::default() {
+        return Ok(None);
+    }
+
+    let structure = structure.ok_or(PokerError::BlindStructureMismatch)?;
+    require_keys_eq!(
+        structure.key(),
+        tournament.blind_structure,
+        PokerError::BlindStructureMismatch
+    );
+
+    Ok(Some(structure.level_at(now - tournament.started_at)))
+}
+
+// Pays a finisher's prize after checking the wallet finished in `place`
+pub(crate) fn pay_finisher<'info>(
+    tournament: &Tournament,
+    tournament_account_info: &AccountInfo<'info>,
+    wallet: &AccountInfo<'info>,
+    place: usize,
+    amount: u64,
+) -> Result<()> {
+    let entrant = tournament
+        .entrant_index(&wallet.key())
+        .ok_or(PokerError::PayoutAccountsMismatch)?;
+    require!(
+        tournament.finish_positions[entrant] as usize == place,
+        PokerError::PayoutAccountsMismatch
+    );
+
+    **tournament_account_info.try_borrow_mut_lamports()? -= amount;
+    **wallet.try_borrow_mut_lamports()? += amount;
+    Ok(())
+}
+
+// Chip chop: everyone locks up the lowest remaining prize and the rest is
+// split in proportion to chips. Rounding dust goes to the chip leader.
+pub(crate) fn chip_chop(stacks: &[u64], prizes: &[u64]) -> [u64; MAX_PLAYERS] {
+    let n = stacks.len();
+    let floor = prizes[n - 1];
+    let surplus = (prizes.iter().sum::<u64>() - floor * n as u64) as u128;
+    let total_chips: u128 = stacks.iter().map(|&s| s as u128).sum();
+
+    let mut amounts = [0u64; MAX_PLAYERS];
+    for i in 0..n {
+        amounts[i] = floor + (surplus * stacks[i] as u128 / total_chips) as u64;
+    }
+    distribute_dust(&mut amounts[..n], stacks, prizes.iter().sum());
+    amounts
+}
+
+// Malmuth-Harville ICM: each player's expected prize given the chance of
+// finishing in each place is proportional to their share of the chips left.
+pub(crate) fn icm_equities(stacks: &[u64], prizes: &[u64]) -> [u64; MAX_PLAYERS] {
+    let n = stacks.len();
+    let total_chips: u128 = stacks.iter().map(|&s| s as u128).sum();
+    let mut equities = [0u128; MAX_PLAYERS];
+    icm_place(stacks, prizes, 0, total_chips, ICM_SCALE, &mut [false; MAX_PLAYERS], &mut equities);
+
+    let mut amounts = [0u64; MAX_PLAYERS];
+    for i in 0..n {
+        amounts[i] = (equities[i] / ICM_SCALE) as u64;
+    }
+    distribute_dust(&mut amounts[..n], stacks, prizes.iter().sum());
+    amounts
+}
+
+fn icm_place(
+    stacks: &[u64],
+    prizes: &[u64],
+    place: usize,
+    chips_left: u128,
+    probability: u128,
+    placed: &mut [bool; MAX_PLAYERS],
+    equities: &mut [u128; MAX_PLAYERS],
+) {
+    if place == prizes.len() || chips_left == 0 {
+        return;
+    }
+    for i in 0..stacks.len() {
+        if placed[i] {
+            continue;
+        }
+        let p = probability * stacks[i] as u128 / chips_left;
+        equities[i] += p * prizes[place] as u128;
+        placed[i] = true;
+        icm_place(stacks, prizes, place + 1, chips_left - stacks[i] as u128, p, placed, equities);
+        placed[i] = false;
+    }
+}
+
+// Gives whatever integer division left over to the biggest stack
+fn distribute_dust(amounts: &mut [u64], stacks: &[u64], total: u64) {
+    let leader = (0..stacks.len()).max_by_key(|&i| stacks[i]).unwrap_or_default();
+    amounts[leader] += total - amounts.iter().sum::<u64>();
+}
+
+// Moves a satellite ticket's escrowed buy-in into the prize pool. The ticket
+// account itself is closed by its `close` constraint.
+pub(crate) fn redeem_ticket(tournament: &mut Account<Tournament>, ticket: &Account<Ticket>) -> Result<()> {
+    require_keys_eq!(ticket.tournament, tournament.key(), PokerError::InvalidTicket);
+    require!(ticket.value == tournament.buy_in, PokerError::InvalidTicket);
+
+    **ticket.to_account_info().try_borrow_mut_lamports()? -= ticket.value;
+    **tournament.to_account_info().try_borrow_mut_lamports()? += ticket.value;
+    tournament.add_entry_fee(ticket.value);
+    Ok(())
+}
+
+// Moves the tournament buy-in from the player into the prize pool
+pub(crate) fn collect_buy_in<'info>(
+    tournament: &mut Account<'info, Tournament>,
+    player: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    if tournament.buy_in > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: player.to_account_info(),
+                    to: tournament.to_account_info(),
+                },
+            ),
+            tournament.buy_in,
+        )?;
+        let buy_in = tournament.buy_in;
+        tournament.add_entry_fee(buy_in);
+    }
+    Ok(())
+}
+
+// Seats a player at the first open seat of a tournament table
+pub(crate) fn seat_tournament_player(
+    table: &mut Game,
+    config: &TableConfig,
+    player: Pubkey,
+    stack: u64,
+) -> Result<usize> {
+    let seat = table
+        .players
+        .iter()
+        .position(|&p| p == Pubkey::default())
+        .ok_or(PokerError::GameFull)?;
+
+    table.players[seat] = player;
+    table.stacks[seat] = stack;
+    table.time_banks[seat] = config.time_bank;
+    table.last_client_nonce[seat] = 0;
+    table.consecutive_timeouts[seat] = 0;
+    table.sitting_out[seat] = 0;
+    table.folded[seat] = 0;
+    table.player_bets[seat] = 0;
+    table.players_in_round = table.seated_count() as u8;
+
+    Ok(seat)
+}
+
+// Fresh state for a table owned by a tournament
+pub(crate) fn init_tournament_table(game: &mut Game, tournament: &Account<Tournament>) {
+    game.players = [Pubkey::default(); MAX_PLAYERS];
+    game.player_hands = [[0u8; MAX_HOLE_CARDS]; MAX_PLAYERS];
+    game.community_cards = [0u8; 5];
+    game.pot = 0;
+    game.small_blind = tournament.small_blind;
+    game.big_blind = tournament.big_blind;
+    game.current_bet = 0;
+    game.current_turn = 0;
+    game.phase = 0;
+    game.betting_street = 0;
+    game.folded = [0; MAX_PLAYERS];
+    game.player_bets = [0; MAX_PLAYERS];
+    game.players_in_round = 0;
+    game.tournament = tournament.key();
+    game.stacks = [0; MAX_PLAYERS];
+    game.ante = 0;
+    game.variant = GameVariant::Holdem as u8;
+    game.deck_seed = 0;
+    game.deck_index = 0;
+    game.acted = [0; MAX_PLAYERS];
+    game.rotation_index = 0;
+    game.hands_in_variant = 0;
+    game.last_winner = Pubkey::default();
+    game.turn_deadline = 0;
+    game.time_banks = [0; MAX_PLAYERS];
+    game.hands_since_refill = 0;
+    game.hand_ended_at = 0;
+    game.contributions = [0; MAX_PLAYERS];
+    game.last_action_slot = [0; MAX_PLAYERS];
+    game.last_client_nonce = [0; MAX_PLAYERS];
+    game.last_crank_slot = 0;
+    game.hand_number = 0;
+    game.all_in_protected = [0; MAX_PLAYERS];
+    game.last_protected_hand = [0; MAX_PLAYERS];
+    game.last_active_epoch = 0;
+    game.crank_rewards_paid = 0;
+    game.consecutive_timeouts = [0; MAX_PLAYERS];
+    game.sitting_out = [0; MAX_PLAYERS];
+    game.settled_hand = 0;
+    game.action_nonce = 0;
+    game.halted = 0;
+    game.state_seq = 0;
+    game.hand_started_at = 0;
+    game.stats = TableStats::default();
+    game.showdown_at = 0;
+    game.disputed = 0;
+    game.extensions = 0;
+    game.bump = 0;
+    game.dealt_in = 0;
+    game.vpip = 0;
+    game.pfr = 0;
+    game.escrow_seats = 0;
+    game.gated_top_ups = 0;
+    game.reserved = [0; 57];
+}
+
+// Deserializes the tournament's tables from remaining accounts, checking they
+// match the tournament's table list exactly and in order.
+fn load_tournament_tables<'info>(
+    tournament: &Tournament,
+    accounts: &'info [AccountInfo<'info>],
+) -> Result<Vec<AccountLoader<'info, Game>>> {
+    let count = tournament.table_count as usize;
+    require!(count > 0, PokerError::TooFewTables);
+    require!(accounts.len() == count, PokerError::TableMismatch);
+
+    let mut tables = Vec::with_capacity(count);
+    for (info, expected) in accounts.iter().zip(tournament.tables.iter()) {
+        require_keys_eq!(info.key(), *expected, PokerError::TableMismatch);
+        require!(info.is_writable, PokerError::TableMismatch);
+        tables.push(AccountLoader::<Game>::try_from(info)?);
+    }
+    Ok(tables)
+}
+
+// Moves the player (and their chips) in `seat` of one table to the first open seat of another
+pub(crate) fn move_seat(from: &mut Game, to: &mut Game, seat: usize) -> Result<()> {
+    let open = to
+        .players
+        .iter()
+        .position(|&p| p == Pubkey::default())
+        .ok_or(PokerError::GameFull)?;
+
+    to.players[open] = from.players[seat];
+    to.stacks[open] = from.stacks[seat];
+    to.time_banks[open] = from.time_banks[seat];
+    to.last_client_nonce[open] = 0;
+    to.consecutive_timeouts[open] = from.consecutive_timeouts[seat];
+    to.sitting_out[open] = from.sitting_out[seat];
+    to.folded[open] = 0;
+    to.player_bets[open] = 0;
+
+    from.players[seat] = Pubkey::default();
+    from.stacks[seat] = 0;
+    from.time_banks[seat] = 0;
+    from.last_client_nonce[seat] = 0;
+    from.consecutive_timeouts[seat] = 0;
+    from.sitting_out[seat] = 0;
+    from.folded[seat] = 0;
+    from.player_bets[seat] = 0;
+
+    from.players_in_round = from.seated_count() as u8;
+    to.players_in_round = to.seated_count() as u8;
+
+    Ok(())
+}
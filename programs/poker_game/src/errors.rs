@@ -0,0 +1,277 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum PokerError {
+    #[msg("Game is full.")]
+    GameFull,
+    #[msg("Game already started.")]
+    GameAlreadyStarted,
+    #[msg("Game is not active.")]
+    GameNotActive,
+    #[msg("Player not in game.")]
+    PlayerNotInGame,
+    #[msg("Player has already folded.")]
+    PlayerAlreadyFolded,
+    #[msg("Player has folded.")]
+    PlayerFolded,
+    #[msg("Not player's turn.")]
+    NotPlayersTurn,
+    #[msg("Bet amount is too low.")]
+    BetTooLow,
+    #[msg("No active players remaining.")]
+    NoActivePlayers,
+    #[msg("Not authorized to perform this action.")]
+    NotAuthorized,
+    #[msg("Seats at tournament tables are managed by the tournament.")]
+    TournamentTable,
+    #[msg("Not enough chips in stack.")]
+    InsufficientStack,
+    #[msg("Tournament is full.")]
+    TournamentFull,
+    #[msg("Player is already registered.")]
+    AlreadyRegistered,
+    #[msg("Tournament registration is closed.")]
+    RegistrationClosed,
+    #[msg("Tournament is not running.")]
+    TournamentNotRunning,
+    #[msg("Tournament already has the maximum number of tables.")]
+    TooManyTables,
+    #[msg("Not enough tables to seat every entrant.")]
+    TooFewTables,
+    #[msg("Not enough entrants to start the tournament.")]
+    NotEnoughEntrants,
+    #[msg("Table does not belong to this tournament.")]
+    TableMismatch,
+    #[msg("Tables are already balanced.")]
+    TablesBalanced,
+    #[msg("Table is still needed to seat the remaining players.")]
+    TableStillNeeded,
+    #[msg("A hand is in progress at this table.")]
+    TableInHand,
+    #[msg("Seat is empty.")]
+    SeatEmpty,
+    #[msg("Player still has chips.")]
+    PlayerHasChips,
+    #[msg("Tournament has not been decided yet.")]
+    TournamentNotDecided,
+    #[msg("Blind structure is invalid.")]
+    InvalidBlindStructure,
+    #[msg("Blind structure does not match the tournament.")]
+    BlindStructureMismatch,
+    #[msg("Re-entry period has closed.")]
+    ReentryClosed,
+    #[msg("Player has not been eliminated.")]
+    PlayerNotEliminated,
+    #[msg("Player has used all re-entries.")]
+    ReentryLimitReached,
+    #[msg("Payout structure is invalid.")]
+    InvalidPayoutStructure,
+    #[msg("Payout accounts do not match the finishing order.")]
+    PayoutAccountsMismatch,
+    #[msg("Satellites award tickets instead of cash.")]
+    SatelliteTournament,
+    #[msg("Ticket is not valid for this tournament.")]
+    InvalidTicket,
+    #[msg("Ticket has already been claimed.")]
+    TicketAlreadyClaimed,
+    #[msg("Prize pool does not cover the satellite seats.")]
+    SatelliteUnderfunded,
+    #[msg("Bet exceeds the pot limit.")]
+    BetExceedsPotLimit,
+    #[msg("Player does not hold the best hand.")]
+    NotBestHand,
+    #[msg("Not available for this game variant.")]
+    WrongVariant,
+    #[msg("All streets have been dealt.")]
+    HandComplete,
+    #[msg("Hand has streets left to play.")]
+    HandNotComplete,
+    #[msg("Betting on this street is not complete.")]
+    BettingNotComplete,
+    #[msg("Fixed-limit bets must raise by exactly one bet.")]
+    InvalidFixedLimitBet,
+    #[msg("Variant rotation is invalid.")]
+    InvalidRotation,
+    #[msg("Waiting for the other tables to finish their hand.")]
+    WaitingForTables,
+    #[msg("Tournament is not playing hand-for-hand.")]
+    NotHandForHand,
+    #[msg("Bounty cannot exceed the buy-in.")]
+    InvalidBounty,
+    #[msg("Nothing to claim.")]
+    NothingToClaim,
+    #[msg("Shootout tables are never balanced or broken.")]
+    ShootoutTournament,
+    #[msg("Tournament is not a shootout.")]
+    NotShootout,
+    #[msg("Table has more than one player left.")]
+    TableNotDecided,
+    #[msg("Freerolls use freeroll registration.")]
+    FreerollTournament,
+    #[msg("Tournament is not a freeroll.")]
+    NotFreeroll,
+    #[msg("Player is not allowed to enter this tournament.")]
+    EntryNotAllowed,
+    #[msg("Deals can only be made at the final table.")]
+    DealNotFinalTable,
+    #[msg("No deal has been proposed.")]
+    NoDealProposed,
+    #[msg("Deal amounts do not add up to the remaining prizes.")]
+    InvalidDeal,
+    #[msg("Player has already accepted the deal.")]
+    DealAlreadyAccepted,
+    #[msg("The player to act still has time.")]
+    TurnNotExpired,
+    #[msg("Turn timeout must be positive.")]
+    InvalidTurnTimer,
+    #[msg("The winner's wallet must be passed as a remaining account.")]
+    MissingWinnerAccount,
+    #[msg("Auto-deal needs 2 to 6 players and a non-negative delay.")]
+    InvalidAutoDeal,
+    #[msg("The hand has not been abandoned long enough to void.")]
+    HandNotAbandoned,
+    #[msg("Too many actions in a short time; try again in a later slot.")]
+    RateLimited,
+    #[msg("Schedule needs a start time and at least two entrants.")]
+    InvalidSchedule,
+    #[msg("Tournament is not scheduled.")]
+    NotScheduled,
+    #[msg("Tournament start time has not been reached.")]
+    TournamentNotStarted,
+    #[msg("Tournament has enough entrants to start.")]
+    EnoughEntrants,
+    #[msg("Tournament has not been cancelled.")]
+    TournamentNotCancelled,
+    #[msg("Table is still in use.")]
+    TableNotStale,
+    #[msg("Invalid table rules.")]
+    InvalidTableRules,
+    #[msg("Player is not sitting out.")]
+    NotSittingOut,
+    #[msg("The table cannot move to that phase from where it is.")]
+    InvalidPhaseTransition,
+    #[msg("Not enough players are ready to deal a hand.")]
+    NotEnoughPlayers,
+    #[msg("Player already has a seat at this table.")]
+    AlreadySeated,
+    #[msg("This hand has already been settled.")]
+    AlreadySettled,
+    #[msg("A valid compliance attestation is required to join this table.")]
+    InvalidAttestation,
+    #[msg("The compliance attestation has expired.")]
+    AttestationExpired,
+    #[msg("This action was signed for a different hand, street or turn.")]
+    StaleAction,
+    #[msg("Invalid table owner.")]
+    InvalidOwner,
+    #[msg("Blinds must be greater than zero.")]
+    ZeroBlind,
+    #[msg("Big blind must be at least the small blind.")]
+    BigBlindTooSmall,
+    #[msg("Betting is closed for this hand.")]
+    BettingClosed,
+    #[msg("The table is halted pending an invariant check.")]
+    TableHalted,
+    #[msg("Seat account does not match this table.")]
+    SeatMismatch,
+    #[msg("Hand history does not belong to this hand.")]
+    HandHistoryMismatch,
+    #[msg("Hand history is still within its retention period.")]
+    HandHistoryRetained,
+    #[msg("Hand can still be disputed.")]
+    DisputeWindowOpen,
+    #[msg("Dispute window has closed.")]
+    DisputeWindowClosed,
+    #[msg("Hand is disputed and awaits the arbiter.")]
+    HandDisputed,
+    #[msg("Hand is not disputed.")]
+    HandNotDisputed,
+    #[msg("Stats shard index is out of range.")]
+    InvalidStatsShard,
+    #[msg("Extension is already enabled.")]
+    ExtensionEnabled,
+    #[msg("Hand has not been settled yet.")]
+    HandNotSettled,
+    #[msg("The table's hand archive is full.")]
+    ArchiveFull,
+    #[msg("This instruction version is deprecated at this table.")]
+    InstructionDeprecated,
+    #[msg("Display name must be 1 to 32 bytes and the avatar URI at most 200.")]
+    InvalidProfile,
+    #[msg("Settling with profiles takes the profile address of every player dealt into the hand.")]
+    ProfileMismatch,
+    #[msg("This table only seats profiles within its rating range.")]
+    RatingOutOfRange,
+    #[msg("Minimum rating is above the maximum.")]
+    InvalidRatingRange,
+    #[msg("The player has not earned this achievement.")]
+    AchievementNotEarned,
+    #[msg("Notes hold at most 1024 bytes.")]
+    NoteTooLong,
+    #[msg("That wallet cannot be added to or removed from this friends list.")]
+    InvalidFriend,
+    #[msg("Friends list is full.")]
+    FriendListFull,
+    #[msg("This seat has sent its emotes for the street.")]
+    EmoteLimit,
+    #[msg("Season must end after it starts and its prize shares may not exceed 10000 bps.")]
+    InvalidSeason,
+    #[msg("The season is not running.")]
+    SeasonNotActive,
+    #[msg("The season has not ended yet.")]
+    SeasonNotOver,
+    #[msg("The season lists the most venues it can.")]
+    SeasonVenuesFull,
+    #[msg("This venue does not earn points in the season.")]
+    NotSeasonVenue,
+    #[msg("Settling with a season takes the entry address of every player dealt into the hand.")]
+    SeasonEntryMismatch,
+    #[msg("Club names must be 1 to 32 bytes, and members join once.")]
+    InvalidClub,
+    #[msg("The club's member list is full.")]
+    ClubFull,
+    #[msg("This table only seats members of its club.")]
+    NotClubMember,
+    #[msg("The player has excluded themselves from play.")]
+    SelfExcluded,
+    #[msg("Buy-in would exceed the player's daily limit.")]
+    BuyInLimitReached,
+    #[msg("Tips must be between 1 and MAX_TIP lamports.")]
+    InvalidTip,
+    #[msg("This table takes deposits only from top-level transactions that borrow nothing, with the instructions sysvar passed.")]
+    OwnFundsRequired,
+    #[msg("Price feed is missing, not the table's, not a verified Pyth SOL/USD update, or not positive.")]
+    InvalidPriceFeed,
+    #[msg("Price feed has not been updated recently enough.")]
+    StalePrice,
+    #[msg("Buy-in is outside the table's USD range at the current price.")]
+    BuyInOutOfRange,
+    #[msg("USD buy-in range must have min at most max, and a positive price age.")]
+    InvalidPriceGate,
+    #[msg("Session keys must differ from the wallet and last 1 to MAX_SESSION_MINUTES minutes.")]
+    InvalidSession,
+    #[msg("Crank key is not the host, a seated player, or a dealer bot scoped for this step.")]
+    CrankNotAuthorized,
+    #[msg("Table already has MAX_DEALER_BOTS dealer bots.")]
+    TooManyDealerBots,
+    #[msg("Staking program is not on the allowlist in the global config.")]
+    StakingProgramNotAllowed,
+    #[msg("Payout route, its deposit account or the staking program is missing or does not match.")]
+    InvalidPayoutRoute,
+    #[msg("Table tags this movement with a memo: pass its MemoConfig and the Memo program.")]
+    MemoRequired,
+    #[msg("Memo tag is too long.")]
+    MemoTagTooLong,
+    #[msg("Starting stack must cover at least the big blind.")]
+    StartingStackTooSmall,
+    #[msg("Bounty cannot change once players have registered.")]
+    BountyLocked,
+    #[msg("Each seated player's wallet and Seat must be passed as remaining accounts, in seat order.")]
+    MissingSeatAccounts,
+    #[msg("A daily limit is set or a change to it is pending; lift it and wait out the cooling-off period first.")]
+    LimitsInForce,
+    #[msg("This table checks top-ups with accounts only add_chips_v2 takes.")]
+    AddChipsV2Required,
+    #[msg("Price feed's confidence interval is too wide to price a buy-in.")]
+    PriceUncertain,
+}
@@ -0,0 +1,355 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_PLAYERS;
+use crate::state::{ArchivedHand, GameVariant};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum TimeoutAction {
+    /// The player's time bank kicked in.
+    TimeBank,
+    Check,
+    /// Kept in the hand as all-in under disconnect protection.
+    AllInProtection,
+    Fold,
+}
+
+#[event]
+pub struct TurnTimedOut {
+    pub game: Pubkey,
+    pub seq: u64,
+    pub hand_id: [u8; 32],
+    pub player: Pubkey,
+    pub seat: u8,
+    pub action: TimeoutAction,
+    /// Time bank balance spent by this timeout; the seat has none left after it.
+    pub time_bank_used: i64,
+    /// Whoever called `force_timeout`.
+    pub crank: Pubkey,
+}
+
+#[event]
+pub struct PlayerJoined {
+    pub game: Pubkey,
+    pub seq: u64,
+    pub hand_id: [u8; 32],
+    pub player: Pubkey,
+    pub seat: u8,
+    pub deposit: u64,
+    /// From the player's profile; empty when none was passed.
+    pub display_name: String,
+}
+
+#[event]
+pub struct HandStarted {
+    pub game: Pubkey,
+    pub seq: u64,
+    pub hand_id: [u8; 32],
+    pub hand_number: u64,
+    pub variant: GameVariant,
+    /// Seats dealt in.
+    pub players: u8,
+}
+
+/// Emitted for bets, raises and calls.
+#[event]
+pub struct BetPlaced {
+    pub game: Pubkey,
+    pub seq: u64,
+    pub hand_id: [u8; 32],
+    pub player: Pubkey,
+    pub seat: u8,
+    /// The player's total for the betting round.
+    pub amount: u64,
+    /// What this action added to the pot.
+    pub added: u64,
+}
+
+/// Emitted for folds, including forced folds on timeout.
+#[event]
+pub struct PlayerFolded {
+    pub game: Pubkey,
+    pub seq: u64,
+    pub hand_id: [u8; 32],
+    pub player: Pubkey,
+    pub seat: u8,
+}
+
+#[event]
+pub struct StreetDealt {
+    pub game: Pubkey,
+    pub seq: u64,
+    pub hand_id: [u8; 32],
+    pub hand_number: u64,
+    pub street: u8,
+}
+
+#[event]
+pub struct ShowdownResult {
+    pub game: Pubkey,
+    pub seq: u64,
+    pub hand_id: [u8; 32],
+    pub hand_number: u64,
+    pub winner: Pubkey,
+    pub seat: u8,
+    /// Players still in at showdown; 1 when everyone else folded.
+    pub live_players: u8,
+}
+
+#[event]
+pub struct PotAwarded {
+    pub game: Pubkey,
+    pub seq: u64,
+    pub hand_id: [u8; 32],
+    pub hand_number: u64,
+    pub winner: Pubkey,
+    pub amount: u64,
+}
+
+/// Emitted when `end_game` refunds an abandoned hand.
+#[event]
+pub struct GameEnded {
+    pub game: Pubkey,
+    pub seq: u64,
+    pub hand_id: [u8; 32],
+    pub hand_number: u64,
+}
+
+#[event]
+pub struct TableHalted {
+    pub game: Pubkey,
+    pub seq: u64,
+    pub hand_id: [u8; 32],
+}
+
+#[event]
+pub struct DealProposed {
+    pub tournament: Pubkey,
+    pub proposer: Pubkey,
+    /// Deal players and their amounts; the first `count` entries are used.
+    pub players: [Pubkey; MAX_PLAYERS],
+    pub amounts: [u64; MAX_PLAYERS],
+    pub count: u8,
+}
+
+#[event]
+pub struct DealAccepted {
+    pub tournament: Pubkey,
+    pub player: Pubkey,
+    /// Set on the last acceptance, which pays the deal out.
+    pub settled: bool,
+}
+
+/// Emitted when a pending deal is withdrawn.
+#[event]
+pub struct DealDeclined {
+    pub tournament: Pubkey,
+    /// Who declined, or the default key when an elimination voided the deal.
+    pub player: Pubkey,
+}
+
+/// Emitted for every step a crank or timeout caller performs.
+#[event]
+pub struct CrankRun {
+    pub game: Pubkey,
+    pub seq: u64,
+    pub hand_id: [u8; 32],
+    pub crank: Pubkey,
+    pub reason: CrankReason,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum CrankReason {
+    /// A time bank was spent or the player checked on timeout.
+    TurnTimeout,
+    /// The player on the clock was folded.
+    ForcedFold,
+    HandDealt,
+    StreetDealt,
+    /// Everyone left was all in, so the board was run out to showdown.
+    AllInRunout,
+    PotAwarded,
+    /// Nobody was left to win; contributions went back on the stacks.
+    HandRefunded,
+    /// An abandoned hand was voided and refunded.
+    HandVoided,
+}
+
+#[event]
+pub struct HandDisputed {
+    pub game: Pubkey,
+    pub seq: u64,
+    pub hand_id: [u8; 32],
+    pub hand_number: u64,
+    pub player: Pubkey,
+    pub seat: u8,
+}
+
+#[event]
+pub struct DisputeResolved {
+    pub game: Pubkey,
+    pub seq: u64,
+    pub hand_id: [u8; 32],
+    pub hand_number: u64,
+    /// False when the hand was voided and refunded.
+    pub upheld: bool,
+}
+
+/// Emitted whenever cards come off the deck: the initial deal is street 0.
+#[event]
+pub struct CardsDealt {
+    pub game: Pubkey,
+    pub seq: u64,
+    pub hand_id: [u8; 32],
+    pub hand_number: u64,
+    pub street: u8,
+    pub source: RandomnessSource,
+    /// Slot the entropy was taken in.
+    pub request_slot: u64,
+    /// `sha256(seed || deck order)` for the hand's shuffle.
+    pub deck_commitment: [u8; 32],
+}
+
+/// Where a hand's shuffle seed came from.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum RandomnessSource {
+    /// Derived from the clock and the table key at deal time.
+    ClockSeed,
+}
+
+/// Emitted when a tournament player busts; `game` is their table.
+#[event]
+pub struct PlayerEliminated {
+    pub game: Pubkey,
+    pub seq: u64,
+    pub hand_id: [u8; 32],
+    pub tournament: Pubkey,
+    pub player: Pubkey,
+    /// Winner of the busting pot, or the default key.
+    pub eliminator: Pubkey,
+    pub level: u8,
+    pub finish_position: u8,
+    /// Bounty cash the eliminator collected.
+    pub bounty_won: u64,
+}
+
+/// Emitted when a settled hand history is folded into the table's archive
+/// and closed. `hand` is the leaf's preimage.
+#[event]
+pub struct HandArchived {
+    pub game: Pubkey,
+    pub seq: u64,
+    pub hand_id: [u8; 32],
+    pub leaf_index: u64,
+    /// Archive root once this leaf was added.
+    pub root: [u8; 32],
+    pub hand: ArchivedHand,
+}
+
+/// Emitted when a player adds someone to their friends list, so the friend
+/// can be told.
+#[event]
+pub struct FriendAdded {
+    pub wallet: Pubkey,
+    pub friend: Pubkey,
+}
+
+#[event]
+pub struct PlayerInvited {
+    pub game: Pubkey,
+    pub inviter: Pubkey,
+    pub invitee: Pubkey,
+}
+
+#[event]
+pub struct InvitationAccepted {
+    pub game: Pubkey,
+    pub inviter: Pubkey,
+    pub invitee: Pubkey,
+}
+
+#[event]
+pub struct EmoteSent {
+    pub game: Pubkey,
+    pub seq: u64,
+    pub hand_id: [u8; 32],
+    pub seat: u8,
+    pub emote: Emote,
+}
+
+#[event]
+pub struct PlayerTipped {
+    pub game: Pubkey,
+    pub seq: u64,
+    pub spectator: Pubkey,
+    pub player: Pubkey,
+    pub seat: u8,
+    pub amount: u64,
+}
+
+#[event]
+pub struct PlayerLeft {
+    pub game: Pubkey,
+    pub seq: u64,
+    pub hand_id: [u8; 32],
+    pub player: Pubkey,
+    pub seat: u8,
+    /// The stack paid back to the wallet.
+    pub cashed_out: u64,
+}
+
+/// The reactions a seat can send. Only the tag goes on chain; clients draw
+/// the picture.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum Emote {
+    ThumbsUp,
+    NiceHand,
+    GoodGame,
+    Laugh,
+    Wow,
+    Thinking,
+    Sad,
+    Angry,
+}
+
+/// Every event in one envelope, emitted instead of the individual events
+/// when built with `compact-events`, so indexers need a single decoder.
+/// Variants are only ever appended: the body is the last thing in the log,
+/// so a decoder that meets an unknown tag can skip the rest of it.
+#[cfg(feature = "compact-events")]
+#[event]
+pub struct GameEvent {
+    pub version: u8,
+    pub body: GameEventBody,
+}
+
+#[cfg(feature = "compact-events")]
+#[derive(AnchorSerialize, AnchorDeserialize)]
+// Built only to be serialized straight into the log, so size is not a concern
+#[allow(clippy::large_enum_variant)]
+pub enum GameEventBody {
+    TurnTimedOut(TurnTimedOut),
+    PlayerJoined(PlayerJoined),
+    HandStarted(HandStarted),
+    BetPlaced(BetPlaced),
+    PlayerFolded(PlayerFolded),
+    StreetDealt(StreetDealt),
+    ShowdownResult(ShowdownResult),
+    PotAwarded(PotAwarded),
+    GameEnded(GameEnded),
+    TableHalted(TableHalted),
+    PlayerEliminated(PlayerEliminated),
+    CardsDealt(CardsDealt),
+    HandDisputed(HandDisputed),
+    DisputeResolved(DisputeResolved),
+    CrankRun(CrankRun),
+    DealProposed(DealProposed),
+    DealAccepted(DealAccepted),
+    DealDeclined(DealDeclined),
+    HandArchived(HandArchived),
+    FriendAdded(FriendAdded),
+    PlayerInvited(PlayerInvited),
+    InvitationAccepted(InvitationAccepted),
+    EmoteSent(EmoteSent),
+    PlayerTipped(PlayerTipped),
+    PlayerLeft(PlayerLeft),
+}
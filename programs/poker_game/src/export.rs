@@ -0,0 +1,141 @@
+//! PokerStars-style hand history text built from `HandHistory` accounts, for
+//! trackers and study tools. Off-chain only: build with the `export` feature.
+//!
+//! Cards use the program's encoding, rank `card % 13` (deuce to ace) and
+//! suit `card / 13` (clubs, diamonds, hearts, spades). Amounts are lamports,
+//! or chips at tournament tables.
+
+use std::fmt::Write;
+
+use anchor_lang::prelude::Pubkey;
+
+use crate::{GameVariant, HandActionKind, HandHistory};
+
+const RANKS: &[u8; 13] = b"23456789TJQKA";
+const SUITS: &[u8; 4] = b"cdhs";
+const STUD_STREETS: [&str; 5] = ["3rd STREET", "4th STREET", "5th STREET", "6th STREET", "RIVER"];
+
+/// Two-character card text, e.g. `Ah`.
+pub fn card_text(card: u8) -> String {
+    let rank = RANKS[(card % 13) as usize] as char;
+    let suit = SUITS[(card / 13) as usize % 4] as char;
+    format!("{rank}{suit}")
+}
+
+fn cards_text(cards: &[u8]) -> String {
+    cards.iter().map(|&c| card_text(c)).collect::<Vec<_>>().join(" ")
+}
+
+fn game_name(variant: GameVariant) -> &'static str {
+    match variant {
+        GameVariant::Holdem => "Hold'em No Limit",
+        GameVariant::Omaha => "Omaha Pot Limit",
+        GameVariant::Stud => "7 Card Stud Limit",
+    }
+}
+
+// Unix seconds as `YYYY/MM/DD HH:MM:SS`, using the days-to-civil algorithm
+// so the crate doesn't need a date library
+fn timestamp_text(unix: i64) -> String {
+    let days = unix.div_euclid(86_400);
+    let secs = unix.rem_euclid(86_400);
+
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{year:04}/{month:02}/{day:02} {:02}:{:02}:{:02}",
+        secs / 3_600,
+        secs % 3_600 / 60,
+        secs % 60
+    )
+}
+
+/// Renders one hand. `players` is the table's seating during the hand and
+/// names each seat by wallet.
+pub fn to_pokerstars(history: &HandHistory, players: &[Pubkey]) -> String {
+    let name = |seat: u8| {
+        players
+            .get(seat as usize)
+            .map(|p| p.to_string())
+            .unwrap_or_else(|| format!("Seat {}", seat + 1))
+    };
+    let mut out = String::new();
+
+    let _ = writeln!(
+        out,
+        "PokerStars Hand #{}: {} - {} UTC",
+        history.hand_number,
+        game_name(history.variant),
+        timestamp_text(history.created_at)
+    );
+    let _ = writeln!(out, "Table '{}' {}-max", history.game, players.len());
+    for (seat, player) in players.iter().enumerate() {
+        if *player != Pubkey::default() {
+            let _ = writeln!(out, "Seat {}: {}", seat + 1, player);
+        }
+    }
+
+    // Bets are stored as the player's total for the street, so anything
+    // over an earlier bet on the same street is a raise
+    let mut street = None;
+    let mut to_match = 0;
+    for action in &history.actions[..history.action_count as usize] {
+        if street != Some(action.street) {
+            street = Some(action.street);
+            to_match = 0;
+            let header = match history.variant {
+                GameVariant::Stud => STUD_STREETS[(action.street as usize).min(4)],
+                _ => "HOLE CARDS",
+            };
+            let _ = writeln!(out, "*** {header} ***");
+        }
+
+        let who = name(action.seat);
+        let _ = match action.kind {
+            HandActionKind::Bet if to_match == 0 => writeln!(out, "{who}: bets {}", action.amount),
+            HandActionKind::Bet => writeln!(
+                out,
+                "{who}: raises {} to {}",
+                action.amount - to_match,
+                action.amount
+            ),
+            HandActionKind::Call => writeln!(out, "{who}: calls {}", action.amount),
+            HandActionKind::Fold => writeln!(out, "{who}: folds"),
+        };
+        if action.kind == HandActionKind::Bet {
+            to_match = action.amount;
+        }
+    }
+    if history.truncated {
+        let _ = writeln!(out, "(further actions were not recorded)");
+    }
+
+    // The board is only copied in once the hand is settled
+    let board = history.variant != GameVariant::Stud && history.settled;
+    if board {
+        let b = &history.board;
+        let _ = writeln!(out, "*** FLOP *** [{}]", cards_text(&b[..3]));
+        let _ = writeln!(out, "*** TURN *** [{}] [{}]", cards_text(&b[..3]), card_text(b[3]));
+        let _ = writeln!(out, "*** RIVER *** [{}] [{}]", cards_text(&b[..4]), card_text(b[4]));
+    }
+
+    if history.settled {
+        let _ = writeln!(out, "{} collected {} from pot", history.winner, history.pot);
+    }
+
+    let _ = writeln!(out, "*** SUMMARY ***");
+    let _ = writeln!(out, "Total pot {} | Rake 0", history.pot);
+    if board {
+        let _ = writeln!(out, "Board [{}]", cards_text(&history.board));
+    }
+
+    out
+}
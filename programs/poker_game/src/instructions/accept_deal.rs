@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+
+use crate::engine::pay_finisher;
+use crate::errors::PokerError;
+use crate::events::DealAccepted;
+use crate::state::{Tournament, TournamentStatus};
+
+#[derive(Accounts)]
+pub struct AcceptDeal<'info> {
+    #[account(mut)]
+    pub tournament: Account<'info, Tournament>,
+    pub player: Signer<'info>,
+}
+
+pub(crate) fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, AcceptDeal<'info>>) -> Result<()> {
+    let tournament_account_info = ctx.accounts.tournament.to_account_info();
+    let tournament = &mut ctx.accounts.tournament;
+    let player = ctx.accounts.player.key();
+
+    require!(
+        tournament.status == TournamentStatus::Running,
+        PokerError::TournamentNotRunning
+    );
+    require!(tournament.deal_count > 0, PokerError::NoDealProposed);
+
+    let n = tournament.deal_count as usize;
+    let index = tournament.deal_players[..n]
+        .iter()
+        .position(|&p| p == player)
+        .ok_or(PokerError::PlayerNotInGame)?;
+    require!(!tournament.deal_accepted[index], PokerError::DealAlreadyAccepted);
+    tournament.deal_accepted[index] = true;
+
+    let unanimous = tournament.deal_accepted[..n].iter().all(|&accepted| accepted);
+    emit_event!(DealAccepted {
+        tournament: tournament.key(),
+        player,
+        settled: unanimous,
+    });
+    if !unanimous {
+        return Ok(());
+    }
+
+    // Unanimous: settle straight away
+    let (prizes, paid) = tournament.place_prizes();
+    let accounts = ctx.remaining_accounts;
+    require!(
+        accounts.len() == n + paid.saturating_sub(n),
+        PokerError::PayoutAccountsMismatch
+    );
+
+    for (i, info) in accounts[..n].iter().enumerate() {
+        require_keys_eq!(
+            info.key(),
+            tournament.deal_players[i],
+            PokerError::PayoutAccountsMismatch
+        );
+        let entrant = tournament
+            .entrant_index(&info.key())
+            .ok_or(PokerError::PayoutAccountsMismatch)?;
+
+        // Deal players keep the bounty on their own head
+        tournament.bounty_winnings[entrant] += tournament.bounties[entrant];
+        tournament.bounties[entrant] = 0;
+
+        let amount = tournament.deal_amounts[i];
+        **tournament_account_info.try_borrow_mut_lamports()? -= amount;
+        **info.try_borrow_mut_lamports()? += amount;
+    }
+
+    for (k, info) in accounts[n..].iter().enumerate() {
+        let place = n + k + 1;
+        pay_finisher(tournament, &tournament_account_info, info, place, prizes[place - 1])?;
+    }
+
+    tournament.prize_pool = 0;
+    tournament.status = TournamentStatus::Finished;
+
+    Ok(())
+}
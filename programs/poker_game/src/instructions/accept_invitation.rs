@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PokerError;
+use crate::events::InvitationAccepted;
+use crate::state::Invitation;
+
+#[derive(Accounts)]
+pub struct AcceptInvitation<'info> {
+    #[account(
+        mut,
+        has_one = invitee @ PokerError::NotAuthorized,
+        seeds = [b"invitation", invitation.game.as_ref(), invitee.key().as_ref()],
+        bump = invitation.bump,
+    )]
+    pub invitation: Account<'info, Invitation>,
+    pub invitee: Signer<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<AcceptInvitation>) -> Result<()> {
+    let invitation = &mut ctx.accounts.invitation;
+    invitation.accepted = true;
+
+    emit_event!(InvitationAccepted {
+        game: invitation.game,
+        inviter: invitation.inviter,
+        invitee: invitation.invitee,
+    });
+
+    Ok(())
+}
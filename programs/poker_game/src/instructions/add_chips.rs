@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar;
+use anchor_lang::system_program;
+
+use crate::engine::{charge_buy_in, check_usd_buy_in, load_profile, require_own_funds, write_memo, Memo, Memos};
+use crate::errors::PokerError;
+use crate::state::{FundMovement, Game, MemoConfig, PriceGate, Seat, TableConfig};
+
+#[derive(Accounts)]
+pub struct AddChips<'info> {
+    /// Stacks only change between hands, and tournament chips never.
+    #[account(
+        mut,
+        constraint = !game.load()?.in_hand() @ PokerError::TableInHand,
+        constraint = !game.load()?.is_tournament_table() @ PokerError::TournamentTable,
+    )]
+    pub game: AccountLoader<'info, Game>,
+    #[account(mut)]
+    pub player: Signer<'info>,
+    #[account(
+        seeds = [b"seat", game.key().as_ref(), player.key().as_ref()],
+        bump = seat.bump,
+        has_one = game @ PokerError::SeatMismatch,
+        constraint = seat.owner == player.key() @ PokerError::SeatMismatch,
+        constraint = game.load()?.players.get(seat.index as usize) == Some(&player.key()) @ PokerError::SeatMismatch,
+    )]
+    pub seat: Account<'info, Seat>,
+    /// CHECK: the player's profile PDA, read only if it has been created,
+    /// whose limits apply to the top-up.
+    #[account(mut, seeds = [b"profile", player.key().as_ref()], bump)]
+    pub profile: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// `add_chips`'s accounts, then the ones it gained after its account list
+/// was frozen for CPI.
+#[derive(Accounts)]
+pub struct AddChipsV2<'info> {
+    pub chips: AddChips<'info>,
+    #[account(seeds = [b"table_config", chips.game.key().as_ref()], bump = config.load()?.bump)]
+    pub config: AccountLoader<'info, TableConfig>,
+    /// CHECK: the instructions sysvar; required at tables that take only
+    /// the player's own funds.
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+    /// The table's USD buy-in range and its SOL/USD feed; required while the
+    /// range is on.
+    #[account(seeds = [b"price_gate", chips.game.key().as_ref()], bump = price_gate.bump)]
+    pub price_gate: Option<Account<'info, PriceGate>>,
+    /// CHECK: must be the gate's feed; checked by `check_usd_buy_in`.
+    pub price_feed: Option<UncheckedAccount<'info>>,
+    /// The table's memo tag and the Memo program; required at tables that
+    /// tag deposits with memos.
+    #[account(seeds = [b"memo_config", chips.game.key().as_ref()], bump = memo_config.bump)]
+    pub memo_config: Option<Account<'info, MemoConfig>>,
+    pub memo_program: Option<Program<'info, Memo>>,
+}
+
+pub(crate) fn handler(ctx: Context<AddChips>, amount: u64) -> Result<()> {
+    // Own-funds and USD-priced tables check top-ups against accounts only
+    // `add_chips_v2` takes
+    require!(ctx.accounts.game.load()?.gated_top_ups == 0, PokerError::AddChipsV2Required);
+    top_up(ctx.accounts, amount, Memos::none())
+}
+
+pub(crate) fn handler_v2(ctx: Context<AddChipsV2>, amount: u64) -> Result<()> {
+    let accounts = ctx.accounts;
+    let config = accounts.config.load()?;
+    require_own_funds(&config, accounts.instructions_sysvar.as_deref())?;
+    let stack = accounts.chips.game.load()?.stacks[accounts.chips.seat.index as usize] + amount;
+    check_usd_buy_in(
+        &config,
+        accounts.price_gate.as_deref(),
+        accounts.price_feed.as_deref(),
+        stack,
+        true,
+        Clock::get()?.unix_timestamp,
+    )?;
+
+    let memos = Memos::new(&config, accounts.memo_config.as_deref(), accounts.memo_program.as_deref());
+    top_up(&mut accounts.chips, amount, memos)
+}
+
+fn top_up(accounts: &mut AddChips, amount: u64, memos: Memos) -> Result<()> {
+    let mut profile = load_profile(&accounts.profile)?;
+    charge_buy_in(&accounts.profile, profile.as_mut(), amount, Clock::get()?.unix_timestamp)?;
+
+    let mut game = accounts.game.load_mut()?;
+    game.next_seq();
+    game.stacks[accounts.seat.index as usize] += amount;
+
+    system_program::transfer(
+        CpiContext::new(
+            accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: accounts.player.to_account_info(),
+                to: accounts.game.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    write_memo(memos, FundMovement::Deposit, &accounts.player.key(), amount)
+}
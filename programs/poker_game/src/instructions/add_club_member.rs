@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_CLUB_MEMBERS;
+use crate::errors::PokerError;
+use crate::state::Club;
+
+#[derive(Accounts)]
+pub struct AddClubMember<'info> {
+    #[account(mut, has_one = owner @ PokerError::NotAuthorized)]
+    pub club: Account<'info, Club>,
+    pub owner: Signer<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<AddClubMember>, member: Pubkey) -> Result<()> {
+    let club = &mut ctx.accounts.club;
+    require!(!club.admits(&member), PokerError::InvalidClub);
+    require!(club.members.len() < MAX_CLUB_MEMBERS, PokerError::ClubFull);
+    club.members.push(member);
+
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_FRIENDS;
+use crate::errors::PokerError;
+use crate::events::FriendAdded;
+use crate::state::PlayerProfile;
+
+#[derive(Accounts)]
+pub struct EditFriends<'info> {
+    #[account(mut, has_one = wallet @ PokerError::NotAuthorized, seeds = [b"profile", wallet.key().as_ref()], bump = profile.bump)]
+    pub profile: Account<'info, PlayerProfile>,
+    pub wallet: Signer<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<EditFriends>, friend: Pubkey) -> Result<()> {
+    let profile = &mut ctx.accounts.profile;
+    require!(friend != profile.wallet && !profile.friends.contains(&friend), PokerError::InvalidFriend);
+    require!(profile.friends.len() < MAX_FRIENDS, PokerError::FriendListFull);
+    profile.friends.push(friend);
+
+    emit_event!(FriendAdded {
+        wallet: profile.wallet,
+        friend,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_SEASON_VENUES;
+use crate::errors::PokerError;
+use crate::state::Season;
+
+#[derive(Accounts)]
+pub struct AddSeasonVenue<'info> {
+    #[account(mut, has_one = authority @ PokerError::NotAuthorized)]
+    pub season: Account<'info, Season>,
+    pub authority: Signer<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<AddSeasonVenue>, venue: Pubkey) -> Result<()> {
+    let season = &mut ctx.accounts.season;
+    if season.venue_index(&venue).is_some() {
+        return Ok(());
+    }
+
+    // A slot freed by a closed table comes first; only cash tables are
+    // closed, so no entry has a tournament scored against it
+    if let Some(index) = season.venue_index(&Pubkey::default()) {
+        season.venues[index] = venue;
+        return Ok(());
+    }
+    require!((season.venue_count as usize) < MAX_SEASON_VENUES, PokerError::SeasonVenuesFull);
+
+    let index = season.venue_count as usize;
+    season.venues[index] = venue;
+    season.venue_count += 1;
+
+    Ok(())
+}
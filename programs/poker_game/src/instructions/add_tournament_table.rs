@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_TABLES;
+use crate::engine::init_tournament_table;
+use crate::errors::PokerError;
+use crate::state::{Game, TableConfig, Tournament, TournamentStatus};
+
+#[derive(Accounts)]
+pub struct AddTournamentTable<'info> {
+    #[account(mut, has_one = authority @ PokerError::NotAuthorized)]
+    pub tournament: Account<'info, Tournament>,
+    #[account(init, payer = authority, space = 8 + Game::LEN)]
+    pub game: AccountLoader<'info, Game>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + TableConfig::LEN,
+        seeds = [b"table_config", game.key().as_ref()],
+        bump,
+    )]
+    pub config: AccountLoader<'info, TableConfig>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<AddTournamentTable>) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+    let game_key = ctx.accounts.game.key();
+    let mut game = ctx.accounts.game.load_init()?;
+
+    require!(
+        tournament.status == TournamentStatus::Registering,
+        PokerError::RegistrationClosed
+    );
+    require!(
+        (tournament.table_count as usize) < MAX_TABLES,
+        PokerError::TooManyTables
+    );
+
+    init_tournament_table(&mut game, tournament);
+    ctx.accounts.config.load_init()?.init(
+        game_key,
+        tournament.authority,
+        tournament.small_blind,
+        tournament.big_blind,
+        ctx.bumps.config,
+    );
+
+    let index = tournament.table_count as usize;
+    tournament.tables[index] = game_key;
+    tournament.table_count += 1;
+
+    Ok(())
+}
@@ -0,0 +1,234 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::LAST_STUD_STREET;
+use crate::engine::{
+    award_pot, begin_hand, deal_next_street, emit_crank, pay_crank_reward, rate_limit, record_club, record_leaderboards, record_profiles, record_season, require_crank_scope,
+    stats_shard_for, Escrow, Memo, Memos, Payee,
+};
+use crate::errors::PokerError;
+use crate::events::CrankReason;
+use crate::state::{AdvanceStatus, BlindStructure, Club, CrankStep, DealerBot, Game, GamePhase, GameVariant, GlobalConfig, HandHistory, Leaderboard, MemoConfig, PayoutRoute, Season, StatsShard, TableConfig, Tournament, load_extended};
+
+#[derive(Accounts)]
+pub struct AdvanceGame<'info> {
+    #[account(mut)]
+    pub game: AccountLoader<'info, Game>,
+    #[account(seeds = [b"table_config", game.key().as_ref()], bump = config.load()?.bump)]
+    pub config: AccountLoader<'info, TableConfig>,
+
+    /// Required for tournament tables.
+    #[account(mut)]
+    pub tournament: Option<Account<'info, Tournament>>,
+    /// Required when the tournament uses a blind structure.
+    pub blind_structure: Option<Account<'info, BlindStructure>>,
+
+    /// Receives the table's crank reward.
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    /// Records the result when passed.
+    #[account(
+        mut,
+        seeds = [b"hand_history", game.key().as_ref(), &game.load()?.hand_number.to_le_bytes()],
+        bump = history.bump,
+    )]
+    pub history: Option<Account<'info, HandHistory>>,
+
+    /// Counts the hand toward protocol stats when passed.
+    #[account(mut, seeds = [b"stats_shard".as_ref(), &[stats_shard_for(&game.key())]], bump = stats_shard.bump)]
+    pub stats_shard: Option<Account<'info, StatsShard>>,
+    /// Ranks the winner on the protocol-wide leaderboard when passed. Every
+    /// table settling with it shares its write lock.
+    #[account(mut, seeds = [b"leaderboard"], bump = leaderboard.bump)]
+    pub leaderboard: Option<Box<Account<'info, Leaderboard>>>,
+    /// Ranks the winner on the table's leaderboard when passed.
+    #[account(mut, seeds = [b"leaderboard", game.key().as_ref()], bump = table_leaderboard.bump)]
+    pub table_leaderboard: Option<Box<Account<'info, Leaderboard>>>,
+    /// Scores the hand for the season's entries among the remaining
+    /// accounts when passed.
+    #[account(mut)]
+    pub season: Option<Box<Account<'info, Season>>>,
+    /// Adds the pot to the table's club stats when passed.
+    #[account(mut, constraint = club.key() == config.load()?.club @ PokerError::NotClubMember)]
+    pub club: Option<Box<Account<'info, Club>>>,
+
+}
+
+/// `advance_game`'s accounts, then the ones it gained after its account
+/// list was frozen for CPI. `try_advance` and `run_automation` take these.
+#[derive(Accounts)]
+pub struct AdvanceGameV2<'info> {
+    pub advance: AdvanceGame<'info>,
+    /// The caller's registration when it cranks as a dealer bot.
+    #[account(
+        seeds = [b"dealer_bot", advance.game.key().as_ref(), advance.caller.key().as_ref()],
+        bump = dealer_bot.bump,
+    )]
+    pub dealer_bot: Option<Account<'info, DealerBot>>,
+    /// The winner's payout route, when their seat routes winnings into a
+    /// staking program. Left out, the pot goes to the winner's wallet.
+    #[account(
+        seeds = [b"payout_route", advance.game.key().as_ref(), payout_route.wallet.as_ref()],
+        bump = payout_route.bump,
+    )]
+    pub payout_route: Option<Box<Account<'info, PayoutRoute>>>,
+    /// Required with `payout_route`.
+    #[account(seeds = [b"global_config"], bump = global_config.bump)]
+    pub global_config: Option<Box<Account<'info, GlobalConfig>>>,
+    /// CHECK: the route's deposit account; checked by `pay_into_escrow`.
+    #[account(mut)]
+    pub staking_deposit: Option<UncheckedAccount<'info>>,
+    /// CHECK: the route's staking program; checked by `pay_into_escrow`.
+    pub staking_program: Option<UncheckedAccount<'info>>,
+    /// The table's memo tag and the Memo program; required at tables that
+    /// tag payouts or crank rewards with memos.
+    #[account(seeds = [b"memo_config", advance.game.key().as_ref()], bump = memo_config.bump)]
+    pub memo_config: Option<Account<'info, MemoConfig>>,
+    pub memo_program: Option<Program<'info, Memo>>,
+}
+
+// Accounts only the v2 account list has. `advance_game` runs without them,
+// so steps that need them are refused there.
+struct AdvanceExtras<'a, 'info> {
+    dealer_bot: Option<&'a DealerBot>,
+    escrow: Option<Escrow<'a, 'info>>,
+    memos: Memos<'a, 'info>,
+}
+
+pub(crate) fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, AdvanceGame<'info>>) -> Result<()> {
+    let extras = AdvanceExtras { dealer_bot: None, escrow: None, memos: Memos::none() };
+    advance_table(ctx.accounts, ctx.remaining_accounts, extras, false).map(|_| ())
+}
+
+pub(crate) fn handler_v2<'info>(ctx: Context<'_, '_, 'info, 'info, AdvanceGameV2<'info>>) -> Result<()> {
+    advance_v2(ctx, false).map(|_| ())
+}
+
+// `advance_table` with the v2 accounts, for `advance_game_v2`, `try_advance`
+// and `run_automation`
+pub(crate) fn advance_v2<'info>(ctx: Context<'_, '_, 'info, 'info, AdvanceGameV2<'info>>, to_stack: bool) -> Result<AdvanceStatus> {
+    let accounts = ctx.accounts;
+    let memos = Memos::new(&*accounts.advance.config.load()?, accounts.memo_config.as_deref(), accounts.memo_program.as_deref());
+    let extras = AdvanceExtras {
+        dealer_bot: accounts.dealer_bot.as_deref(),
+        escrow: Escrow::from_accounts(
+            accounts.payout_route.as_deref().map(|route| &**route),
+            accounts.global_config.as_deref().map(|config| &**config),
+            accounts.staking_deposit.as_deref(),
+            accounts.staking_program.as_deref(),
+        )?,
+        memos,
+    };
+    advance_table(&mut accounts.advance, ctx.remaining_accounts, extras, to_stack)
+}
+
+// Body of the advancing instructions: runs the step the table is waiting on
+// and reports which one it was. With `to_stack` a cash pot goes onto the
+// winner's stack, and every remaining account is a profile or season entry.
+fn advance_table<'info>(
+    accounts: &mut AdvanceGame<'info>,
+    remaining_accounts: &'info [AccountInfo<'info>],
+    extras: AdvanceExtras<'_, 'info>,
+    to_stack: bool,
+) -> Result<AdvanceStatus> {
+    let game_info = accounts.game.to_account_info();
+    let game_key = game_info.key();
+    let caller_info = accounts.caller.to_account_info();
+    let caller = caller_info.key();
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+    let config = accounts.config.load()?;
+    let (mut game, mut extensions) = load_extended(&accounts.game)?;
+    game.next_seq();
+    let dealer_bot = extras.dealer_bot;
+    let memos = extras.memos;
+
+    let window = config.rules.crank_slot_window;
+    rate_limit(&mut game.last_crank_slot, window, clock.slot)?;
+
+    if !game.in_hand() {
+        if !game.auto_deal_ready(&config, now) {
+            return Ok(AdvanceStatus::NotReadyToDeal);
+        }
+        require_crank_scope(&config, &game, &caller, dealer_bot, CrankStep::Deal)?;
+        begin_hand(
+            &mut game,
+            &mut extensions,
+            game_key,
+            &config,
+            accounts.tournament.as_mut(),
+            accounts.blind_structure.as_ref(),
+            now,
+        )?;
+        emit_crank(&game, game_key, caller, CrankReason::HandDealt);
+        return Ok(AdvanceStatus::HandDealt);
+    }
+
+    // Betting only pauses between stud streets; everything else moves
+    // to showdown by itself
+    if game.is_betting() {
+        if game.all_in_runout() {
+            require_crank_scope(&config, &game, &caller, dealer_bot, CrankStep::Deal)?;
+            while game.variant() == GameVariant::Stud && game.street() < LAST_STUD_STREET {
+                deal_next_street(&mut game, &mut extensions, game_key, &config, now)?;
+            }
+            game.set_phase(GamePhase::Showdown)?;
+            emit_crank(&game, game_key, caller, CrankReason::AllInRunout);
+        } else if game.betting_complete() {
+            require_crank_scope(&config, &game, &caller, dealer_bot, CrankStep::Deal)?;
+            deal_next_street(&mut game, &mut extensions, game_key, &config, now)?;
+            pay_crank_reward(&mut game, &game_info, &config, &caller_info, memos)?;
+            emit_crank(&game, game_key, caller, CrankReason::StreetDealt);
+            return Ok(AdvanceStatus::StreetDealt);
+        } else {
+            return Ok(AdvanceStatus::AwaitingAction);
+        }
+    }
+
+    require_crank_scope(&config, &game, &caller, dealer_bot, CrankStep::Settle)?;
+
+    // Nobody live to pay: hand the pot back instead of leaving it stuck
+    let Some(winner_index) = game.showdown_winner() else {
+        game.refund_hand()?;
+        emit_crank(&game, game_key, caller, CrankReason::HandRefunded);
+        return Ok(AdvanceStatus::HandRefunded);
+    };
+    if game.check_settlement(&config, now).is_err() {
+        return Ok(AdvanceStatus::SettlementHeld);
+    }
+    // The winner's wallet, then any players' profiles and season entries
+    let (winner_info, profiles) = if to_stack {
+        (None, remaining_accounts)
+    } else {
+        let (winner_info, profiles) = remaining_accounts
+            .split_first()
+            .ok_or(PokerError::MissingWinnerAccount)?;
+        (Some(winner_info), profiles)
+    };
+
+    pay_crank_reward(&mut game, &game_info, &config, &caller_info, memos)?;
+    let pot = game.pot;
+    let won = award_pot(
+        &mut game,
+        &game_info,
+        winner_index,
+        winner_info.map(|wallet| Payee { wallet, escrow: extras.escrow }),
+        now,
+        accounts.stats_shard.as_mut(),
+        memos,
+    )?;
+    record_profiles(&game, winner_index, won, profiles)?;
+    record_season(accounts.season.as_mut(), game_key, &game, winner_index, profiles, now)?;
+    record_club(accounts.club.as_mut(), &game, won);
+    record_leaderboards(
+        game.players[winner_index],
+        won,
+        [accounts.leaderboard.as_mut(), accounts.table_leaderboard.as_mut()],
+    );
+    if let Some(history) = accounts.history.as_mut() {
+        history.record_result(&game, winner_index, pot);
+    }
+    emit_crank(&game, game_key, caller, CrankReason::PotAwarded);
+
+    Ok(AdvanceStatus::PotAwarded)
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PokerError;
+use crate::state::{Game, GamePhase, Tournament, TournamentFormat, TournamentStatus};
+
+#[derive(Accounts)]
+pub struct AdvanceShootoutWinner<'info> {
+    #[account(mut)]
+    pub tournament: Account<'info, Tournament>,
+    #[account(mut, constraint = table.load()?.tournament == tournament.key() @ PokerError::TableMismatch)]
+    pub table: AccountLoader<'info, Game>,
+}
+
+pub(crate) fn handler(ctx: Context<AdvanceShootoutWinner>) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+    let table_key = ctx.accounts.table.key();
+    let mut table = ctx.accounts.table.load_mut()?;
+    table.next_seq();
+
+    require!(
+        tournament.status == TournamentStatus::Running,
+        PokerError::TournamentNotRunning
+    );
+    require!(
+        tournament.format == TournamentFormat::Shootout,
+        PokerError::NotShootout
+    );
+    require!(tournament.players_remaining > 1, PokerError::TournamentNotDecided);
+    require!(!table.in_hand(), PokerError::TableInHand);
+    require!(table.seated_count() == 1, PokerError::TableNotDecided);
+
+    let seat = table
+        .players
+        .iter()
+        .position(|&p| p != Pubkey::default())
+        .ok_or(PokerError::SeatEmpty)?;
+    let count = tournament.advancer_count as usize;
+    tournament.advancers[count] = table.players[seat];
+    tournament.advancer_count += 1;
+
+    table.players[seat] = Pubkey::default();
+    table.stacks[seat] = 0;
+    table.players_in_round = 0;
+    table.set_phase(GamePhase::Finished)?;
+
+    let index = tournament
+        .table_index(&table_key)
+        .ok_or(PokerError::TableMismatch)?;
+    tournament.remove_table(index);
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PokerError;
+use crate::events::HandArchived;
+use crate::state::{ArchivedHand, Game, HandArchive, HandHistory};
+
+#[derive(Accounts)]
+pub struct ArchiveHandHistory<'info> {
+    pub game: AccountLoader<'info, Game>,
+    #[account(mut, seeds = [b"hand_archive", game.key().as_ref()], bump = archive.bump)]
+    pub archive: Account<'info, HandArchive>,
+    #[account(
+        mut,
+        close = payer,
+        has_one = game @ PokerError::HandHistoryMismatch,
+        has_one = payer @ PokerError::NotAuthorized,
+        constraint = history.settled @ PokerError::HandNotSettled,
+    )]
+    pub history: Account<'info, HandHistory>,
+    /// Whoever opened the history gets its rent back.
+    #[account(mut)]
+    pub payer: SystemAccount<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<ArchiveHandHistory>) -> Result<()> {
+    let hand = ArchivedHand::from_history(&ctx.accounts.history);
+    let archive = &mut ctx.accounts.archive;
+    let leaf_index = archive.append(hand.leaf()?)?;
+
+    emit_event!(HandArchived {
+        game: ctx.accounts.game.key(),
+        seq: ctx.accounts.game.load()?.state_seq,
+        hand_id: hand.hand_id,
+        leaf_index,
+        root: archive.root,
+        hand,
+    });
+
+    Ok(())
+}
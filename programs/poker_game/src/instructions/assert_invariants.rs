@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::engine::hand_id;
+use crate::events::TableHalted;
+use crate::state::Game;
+
+#[derive(Accounts)]
+pub struct AssertInvariants<'info> {
+    #[account(mut)]
+    pub game: AccountLoader<'info, Game>,
+}
+
+pub(crate) fn handler(ctx: Context<AssertInvariants>) -> Result<()> {
+    let game_info = ctx.accounts.game.to_account_info();
+    let game_key = game_info.key();
+    let mut game = ctx.accounts.game.load_mut()?;
+    game.next_seq();
+
+    let rent = Rent::get()?.minimum_balance(game_info.data_len());
+    let owed = game.pot + game.stacks.iter().sum::<u64>();
+    let solvent = game.is_tournament_table() || game_info.lamports() >= rent + owed;
+
+    let healthy = solvent && game.ledger_balanced() && game.seats_consistent();
+    if !healthy && game.halted == 0 {
+        game.halted = 1;
+        emit_event!(TableHalted {
+            game: game_key,
+            seq: game.state_seq,
+            hand_id: hand_id(&game_key, game.hand_number),
+        });
+    }
+
+    Ok(())
+}
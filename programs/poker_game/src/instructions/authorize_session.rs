@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_SESSION_MINUTES;
+use crate::errors::PokerError;
+use crate::state::{Game, Seat};
+
+#[derive(Accounts)]
+pub struct AuthorizeSession<'info> {
+    pub game: AccountLoader<'info, Game>,
+    pub player: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"seat", game.key().as_ref(), player.key().as_ref()],
+        bump = seat.bump,
+        has_one = game @ PokerError::SeatMismatch,
+        constraint = seat.owner == player.key() @ PokerError::SeatMismatch,
+    )]
+    pub seat: Account<'info, Seat>,
+}
+
+pub(crate) fn handler(ctx: Context<AuthorizeSession>, session_key: Pubkey, minutes: u16) -> Result<()> {
+    let seat = &mut ctx.accounts.seat;
+
+    // The default key ends the session early
+    if session_key == Pubkey::default() {
+        seat.session_key = Pubkey::default();
+        seat.session_expires_at = 0;
+        return Ok(());
+    }
+
+    require!(
+        session_key != seat.owner && (1..=MAX_SESSION_MINUTES).contains(&minutes),
+        PokerError::InvalidSession
+    );
+    seat.session_key = session_key;
+    seat.session_expires_at = Clock::get()?.unix_timestamp + i64::from(minutes) * 60;
+
+    Ok(())
+}
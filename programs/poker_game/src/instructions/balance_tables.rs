@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_PLAYERS;
+use crate::engine::move_seat;
+use crate::errors::PokerError;
+use crate::state::{Game, Tournament, TournamentFormat, TournamentStatus};
+
+#[derive(Accounts)]
+pub struct MoveTournamentPlayers<'info> {
+    pub tournament: Account<'info, Tournament>,
+    #[account(mut, constraint = from_table.load()?.tournament == tournament.key() @ PokerError::TableMismatch)]
+    pub from_table: AccountLoader<'info, Game>,
+    #[account(
+        mut,
+        constraint = to_table.load()?.tournament == tournament.key() @ PokerError::TableMismatch,
+        constraint = to_table.key() != from_table.key() @ PokerError::TableMismatch,
+    )]
+    pub to_table: AccountLoader<'info, Game>,
+}
+
+pub(crate) fn handler(ctx: Context<MoveTournamentPlayers>) -> Result<()> {
+    let mut from = ctx.accounts.from_table.load_mut()?;
+    from.next_seq();
+    let mut to = ctx.accounts.to_table.load_mut()?;
+    to.next_seq();
+
+    require!(
+        ctx.accounts.tournament.status == TournamentStatus::Running,
+        PokerError::TournamentNotRunning
+    );
+    require!(
+        ctx.accounts.tournament.format == TournamentFormat::Standard,
+        PokerError::ShootoutTournament
+    );
+    require!(!from.in_hand() && !to.in_hand(), PokerError::TableInHand);
+    require!(
+        from.seated_count() >= to.seated_count() + 2,
+        PokerError::TablesBalanced
+    );
+
+    // Move the highest occupied seat so the draw stays deterministic
+    let seat = (0..MAX_PLAYERS)
+        .rev()
+        .find(|&i| from.players[i] != Pubkey::default())
+        .ok_or(PokerError::SeatEmpty)?;
+    move_seat(&mut from, &mut to, seat)?;
+
+    Ok(())
+}
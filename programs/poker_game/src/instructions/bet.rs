@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+
+use crate::engine::{hand_id, rate_limit};
+use crate::errors::PokerError;
+use crate::events::BetPlaced;
+use crate::state::{ActionTicket, Game, HandActionKind, HandHistory, Seat, TableConfig};
+
+#[derive(Accounts)]
+pub struct PlayerAction<'info> {
+    /// Once action closes, nothing can touch the pot until it is settled.
+    #[account(
+        mut,
+        constraint = game.load()?.in_hand() @ PokerError::GameNotActive,
+        constraint = game.load()?.is_betting() @ PokerError::BettingClosed,
+        constraint = game.load()?.halted == 0 @ PokerError::TableHalted
+    )]
+    pub game: AccountLoader<'info, Game>,
+    #[account(seeds = [b"table_config", game.key().as_ref()], bump = config.load()?.bump)]
+    pub config: AccountLoader<'info, TableConfig>,
+    /// The seat's owner, or the session key they authorized.
+    #[account(mut)]
+    pub player: Signer<'info>,
+    /// The player's seat at this table; it must still match the game.
+    #[account(
+        seeds = [b"seat", game.key().as_ref(), seat.owner.as_ref()],
+        bump = seat.bump,
+        has_one = game @ PokerError::SeatMismatch,
+        constraint = seat.can_act(&player.key(), Clock::get()?.unix_timestamp) @ PokerError::SeatMismatch,
+        constraint = game.load()?.players.get(seat.index as usize) == Some(&seat.owner) @ PokerError::SeatMismatch,
+    )]
+    pub seat: Account<'info, Seat>,
+    /// Records the action when passed.
+    #[account(
+        mut,
+        seeds = [b"hand_history", game.key().as_ref(), &game.load()?.hand_number.to_le_bytes()],
+        bump = history.bump,
+    )]
+    pub history: Option<Account<'info, HandHistory>>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<PlayerAction>, amount: u64, ticket: ActionTicket) -> Result<()> {
+    let game_key = ctx.accounts.game.key();
+    let clock = Clock::get()?;
+    let config = ctx.accounts.config.load()?;
+    let mut game = ctx.accounts.game.load_mut()?;
+    let player_index = ctx.accounts.seat.index as usize;
+    // A retry of an action that already landed changes nothing
+    if game.is_retry(player_index, &ticket) {
+        return Ok(());
+    }
+    game.next_seq();
+
+    require!(game.folded[player_index] == 0, PokerError::PlayerFolded);
+    require!(player_index as u8 == game.current_turn, PokerError::NotPlayersTurn);
+    let window = config.rules.action_slot_window;
+    rate_limit(&mut game.last_action_slot[player_index], window, clock.slot)?;
+    game.consecutive_timeouts[player_index] = 0;
+    game.consume_action(player_index, &ticket)?;
+
+    let added = game.apply_bet(player_index, amount)?;
+
+    emit_event!(BetPlaced {
+        game: game_key,
+        seq: game.state_seq,
+        hand_id: hand_id(&game_key, game.hand_number),
+        player: game.players[player_index],
+        seat: player_index as u8,
+        amount,
+        added,
+    });
+    if let Some(history) = ctx.accounts.history.as_mut() {
+        history.record(player_index, HandActionKind::Bet, amount, game.street());
+    }
+
+    game.pass_action(clock.unix_timestamp, &config)
+}
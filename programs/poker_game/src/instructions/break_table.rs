@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_PLAYERS;
+use crate::engine::move_seat;
+use crate::errors::PokerError;
+use crate::state::{Game, GamePhase, Tournament, TournamentFormat, TournamentStatus};
+
+#[derive(Accounts)]
+pub struct BreakTable<'info> {
+    #[account(mut)]
+    pub tournament: Account<'info, Tournament>,
+    #[account(mut, constraint = from_table.load()?.tournament == tournament.key() @ PokerError::TableMismatch)]
+    pub from_table: AccountLoader<'info, Game>,
+    #[account(
+        mut,
+        constraint = to_table.load()?.tournament == tournament.key() @ PokerError::TableMismatch,
+        constraint = to_table.key() != from_table.key() @ PokerError::TableMismatch,
+    )]
+    pub to_table: AccountLoader<'info, Game>,
+}
+
+pub(crate) fn handler(ctx: Context<BreakTable>) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+    let from_key = ctx.accounts.from_table.key();
+    let mut from = ctx.accounts.from_table.load_mut()?;
+    from.next_seq();
+    let mut to = ctx.accounts.to_table.load_mut()?;
+    to.next_seq();
+
+    require!(
+        tournament.status == TournamentStatus::Running,
+        PokerError::TournamentNotRunning
+    );
+    require!(
+        tournament.format == TournamentFormat::Standard,
+        PokerError::ShootoutTournament
+    );
+    require!(!from.in_hand() && !to.in_hand(), PokerError::TableInHand);
+    require!(
+        (tournament.players_remaining as usize)
+            <= (tournament.table_count as usize - 1) * MAX_PLAYERS,
+        PokerError::TableStillNeeded
+    );
+    require!(
+        from.seated_count() <= MAX_PLAYERS - to.seated_count(),
+        PokerError::GameFull
+    );
+
+    for seat in 0..MAX_PLAYERS {
+        if from.players[seat] != Pubkey::default() {
+            move_seat(&mut from, &mut to, seat)?;
+        }
+    }
+
+    // Drop the broken table from the tournament's table list
+    from.set_phase(GamePhase::Finished)?;
+    let index = tournament
+        .table_index(&from_key)
+        .ok_or(PokerError::TableMismatch)?;
+    tournament.remove_table(index);
+    tournament.refresh_hand_for_hand();
+
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::engine::{hand_id, rate_limit};
+use crate::errors::PokerError;
+use crate::events::BetPlaced;
+use crate::instructions::PlayerAction;
+use crate::state::{ActionTicket, HandActionKind};
+
+pub(crate) fn handler(ctx: Context<PlayerAction>, ticket: ActionTicket) -> Result<()> {
+    let game_key = ctx.accounts.game.key();
+    let clock = Clock::get()?;
+    let config = ctx.accounts.config.load()?;
+    let mut game = ctx.accounts.game.load_mut()?;
+    let player_index = ctx.accounts.seat.index as usize;
+    // A retry of an action that already landed changes nothing
+    if game.is_retry(player_index, &ticket) {
+        return Ok(());
+    }
+    game.next_seq();
+
+    require!(game.folded[player_index] == 0, PokerError::PlayerFolded);
+    require!(player_index as u8 == game.current_turn, PokerError::NotPlayersTurn);
+    let window = config.rules.action_slot_window;
+    rate_limit(&mut game.last_action_slot[player_index], window, clock.slot)?;
+    game.consecutive_timeouts[player_index] = 0;
+    game.consume_action(player_index, &ticket)?;
+
+    let to_call = game.apply_call(player_index)?;
+
+    emit_event!(BetPlaced {
+        game: game_key,
+        seq: game.state_seq,
+        hand_id: hand_id(&game_key, game.hand_number),
+        player: game.players[player_index],
+        seat: player_index as u8,
+        amount: game.player_bets[player_index],
+        added: to_call,
+    });
+    if let Some(history) = ctx.accounts.history.as_mut() {
+        history.record(player_index, HandActionKind::Call, to_call, game.street());
+    }
+
+    game.pass_action(clock.unix_timestamp, &config)
+}
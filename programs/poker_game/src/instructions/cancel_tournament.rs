@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PokerError;
+use crate::instructions::StartTournament;
+use crate::state::TournamentStatus;
+
+pub(crate) fn handler(ctx: Context<StartTournament>) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+
+    require!(
+        tournament.status == TournamentStatus::Registering,
+        PokerError::RegistrationClosed
+    );
+    require!(tournament.start_time > 0, PokerError::NotScheduled);
+    require!(
+        Clock::get()?.unix_timestamp >= tournament.start_time,
+        PokerError::TournamentNotStarted
+    );
+    require!(
+        tournament.entrant_count < tournament.min_entrants,
+        PokerError::EnoughEntrants
+    );
+
+    tournament.status = TournamentStatus::Cancelled;
+
+    Ok(())
+}
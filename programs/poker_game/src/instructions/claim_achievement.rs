@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PokerError;
+use crate::state::{Achievement, AchievementBadge, PlayerProfile, Tournament, TournamentStatus};
+
+#[derive(Accounts)]
+#[instruction(kind: Achievement)]
+pub struct ClaimAchievement<'info> {
+    #[account(seeds = [b"profile", player.key().as_ref()], bump = profile.bump)]
+    pub profile: Account<'info, PlayerProfile>,
+    /// Creating it fails when the badge was already awarded.
+    #[account(
+        init,
+        payer = player,
+        space = 8 + AchievementBadge::LEN,
+        seeds = [b"achievement", player.key().as_ref(), &[kind as u8]],
+        bump,
+    )]
+    pub badge: Account<'info, AchievementBadge>,
+    /// The tournament won, for `TournamentWin`.
+    pub tournament: Option<Account<'info, Tournament>>,
+    #[account(mut)]
+    pub player: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<ClaimAchievement>, kind: Achievement) -> Result<()> {
+    let player = ctx.accounts.player.key();
+
+    let earned = match kind {
+        // Spotted at settlement, where the cards are still on the table
+        Achievement::RoyalFlush => ctx.accounts.profile.achievements & kind.bit() != 0,
+        Achievement::TournamentWin => {
+            let tournament = ctx
+                .accounts
+                .tournament
+                .as_ref()
+                .ok_or(PokerError::AchievementNotEarned)?;
+            tournament.status == TournamentStatus::Finished
+                && tournament
+                    .entrant_index(&player)
+                    .is_some_and(|entrant| tournament.finish_positions[entrant] == 1)
+        }
+    };
+    require!(earned, PokerError::AchievementNotEarned);
+
+    let badge = &mut ctx.accounts.badge;
+    badge.owner = player;
+    badge.kind = kind;
+    badge.awarded_at = Clock::get()?.unix_timestamp;
+    badge.bump = ctx.bumps.badge;
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PokerError;
+use crate::state::{Tournament, TournamentStatus};
+
+#[derive(Accounts)]
+pub struct ClaimBounty<'info> {
+    #[account(mut)]
+    pub tournament: Account<'info, Tournament>,
+    #[account(mut)]
+    pub player: Signer<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<ClaimBounty>) -> Result<()> {
+    let tournament_account_info = ctx.accounts.tournament.to_account_info();
+    let player_account_info = ctx.accounts.player.to_account_info();
+
+    let tournament = &mut ctx.accounts.tournament;
+
+    require!(
+        tournament.status == TournamentStatus::Finished,
+        PokerError::TournamentNotDecided
+    );
+
+    let entrant = tournament
+        .entrant_index(&player_account_info.key())
+        .ok_or(PokerError::PlayerNotInGame)?;
+    let amount = tournament.bounty_winnings[entrant];
+    require!(amount > 0, PokerError::NothingToClaim);
+
+    **tournament_account_info.try_borrow_mut_lamports()? -= amount;
+    **player_account_info.try_borrow_mut_lamports()? += amount;
+    tournament.bounty_winnings[entrant] = 0;
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PokerError;
+use crate::state::{Tournament, TournamentStatus};
+
+#[derive(Accounts)]
+pub struct ClaimRefund<'info> {
+    #[account(mut)]
+    pub tournament: Account<'info, Tournament>,
+    #[account(mut)]
+    pub player: Signer<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<ClaimRefund>) -> Result<()> {
+    let tournament_account_info = ctx.accounts.tournament.to_account_info();
+    let player_account_info = ctx.accounts.player.to_account_info();
+
+    let tournament = &mut ctx.accounts.tournament;
+
+    require!(
+        tournament.status == TournamentStatus::Cancelled,
+        PokerError::TournamentNotCancelled
+    );
+
+    let entrant = tournament
+        .entrant_index(&player_account_info.key())
+        .ok_or(PokerError::PlayerNotInGame)?;
+    let entries = tournament.entries[entrant] as u64;
+    let amount = tournament.buy_in * entries;
+    require!(amount > 0, PokerError::NothingToClaim);
+
+    **tournament_account_info.try_borrow_mut_lamports()? -= amount;
+    **player_account_info.try_borrow_mut_lamports()? += amount;
+    tournament.prize_pool -= (tournament.buy_in - tournament.bounty) * entries;
+    tournament.bounties[entrant] = 0;
+    tournament.entries[entrant] = 0;
+
+    Ok(())
+}
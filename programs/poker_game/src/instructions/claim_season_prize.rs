@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PokerError;
+use crate::state::{Season, SeasonEntry};
+
+#[derive(Accounts)]
+pub struct ClaimSeasonPrize<'info> {
+    #[account(mut)]
+    pub season: Account<'info, Season>,
+    #[account(
+        mut,
+        has_one = player @ PokerError::NotAuthorized,
+        seeds = [b"season_entry", season.key().as_ref(), player.key().as_ref()],
+        bump = entry.bump,
+    )]
+    pub entry: Account<'info, SeasonEntry>,
+    #[account(mut)]
+    pub player: Signer<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<ClaimSeasonPrize>) -> Result<()> {
+    let season = &ctx.accounts.season;
+    let entry = &mut ctx.accounts.entry;
+
+    require!(Clock::get()?.unix_timestamp >= season.ends_at, PokerError::SeasonNotOver);
+    require!(!entry.prize_claimed, PokerError::NothingToClaim);
+
+    let standing = season
+        .standings
+        .iter()
+        .position(|s| s.player == entry.player)
+        .ok_or(PokerError::NothingToClaim)?;
+    let prize = season.prize_for(standing);
+    require!(prize > 0, PokerError::NothingToClaim);
+    entry.prize_claimed = true;
+
+    **season.to_account_info().try_borrow_mut_lamports()? -= prize;
+    **ctx.accounts.player.to_account_info().try_borrow_mut_lamports()? += prize;
+
+    Ok(())
+}
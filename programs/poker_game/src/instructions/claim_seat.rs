@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PokerError;
+use crate::state::{Game, Seat};
+
+#[derive(Accounts)]
+pub struct ClaimSeat<'info> {
+    pub game: AccountLoader<'info, Game>,
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + Seat::LEN,
+        seeds = [b"seat", game.key().as_ref(), player.key().as_ref()],
+        bump,
+    )]
+    pub seat: Account<'info, Seat>,
+    #[account(mut)]
+    pub player: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<ClaimSeat>) -> Result<()> {
+    let game_key = ctx.accounts.game.key();
+    let game = ctx.accounts.game.load()?;
+    let player = ctx.accounts.player.key();
+
+    let index = game
+        .players
+        .iter()
+        .position(|&p| p == player)
+        .ok_or(PokerError::PlayerNotInGame)?;
+
+    let seat = &mut ctx.accounts.seat;
+    seat.game = game_key;
+    seat.owner = player;
+    seat.index = index as u8;
+    seat.bump = ctx.bumps.seat;
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PokerError;
+use crate::state::{Ticket, Tournament, TournamentStatus};
+
+#[derive(Accounts)]
+pub struct ClaimTicket<'info> {
+    #[account(mut)]
+    pub tournament: Account<'info, Tournament>,
+    #[account(
+        init,
+        payer = player,
+        space = 8 + Ticket::LEN,
+        seeds = [b"ticket", tournament.satellite_target.as_ref(), player.key().as_ref()],
+        bump,
+    )]
+    pub ticket: Account<'info, Ticket>,
+    #[account(mut)]
+    pub player: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<ClaimTicket>) -> Result<()> {
+    let tournament_account_info = ctx.accounts.tournament.to_account_info();
+    let ticket_account_info = ctx.accounts.ticket.to_account_info();
+    let player_account_info = ctx.accounts.player.to_account_info();
+
+    let tournament = &mut ctx.accounts.tournament;
+    let player = &ctx.accounts.player;
+
+    require!(
+        tournament.status == TournamentStatus::Running,
+        PokerError::TournamentNotRunning
+    );
+    require!(
+        tournament.players_remaining <= tournament.satellite_seats,
+        PokerError::TournamentNotDecided
+    );
+
+    let entrant = tournament
+        .entrant_index(&player.key())
+        .ok_or(PokerError::PlayerNotInGame)?;
+    require!(!tournament.eliminated[entrant], PokerError::PlayerNotInGame);
+    require!(!tournament.tickets_claimed[entrant], PokerError::TicketAlreadyClaimed);
+
+    let claimed = tournament.tickets_claimed.iter().filter(|&&c| c).count();
+    let unclaimed = tournament.players_remaining as usize - claimed;
+    let share = if unclaimed == 1 {
+        tournament.prize_pool
+    } else {
+        tournament.prize_pool / unclaimed as u64
+    };
+    require!(share >= tournament.ticket_value, PokerError::SatelliteUnderfunded);
+
+    **tournament_account_info.try_borrow_mut_lamports()? -= share;
+    **ticket_account_info.try_borrow_mut_lamports()? += tournament.ticket_value;
+    **player_account_info.try_borrow_mut_lamports()? += share - tournament.ticket_value;
+
+    let ticket = &mut ctx.accounts.ticket;
+    ticket.owner = player.key();
+    ticket.tournament = tournament.satellite_target;
+    ticket.value = tournament.ticket_value;
+    ticket.bump = ctx.bumps.ticket;
+
+    tournament.prize_pool -= share;
+    tournament.tickets_claimed[entrant] = true;
+    if unclaimed == 1 {
+        tournament.status = TournamentStatus::Finished;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::HAND_HISTORY_RETENTION;
+use crate::errors::PokerError;
+use crate::state::HandHistory;
+
+#[derive(Accounts)]
+pub struct CloseHandHistory<'info> {
+    #[account(mut, close = payer, has_one = payer @ PokerError::NotAuthorized)]
+    pub history: Account<'info, HandHistory>,
+    /// Whoever opened the history gets its rent back.
+    #[account(mut)]
+    pub payer: SystemAccount<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<CloseHandHistory>) -> Result<()> {
+    require!(
+        Clock::get()?.unix_timestamp >= ctx.accounts.history.created_at + HAND_HISTORY_RETENTION,
+        PokerError::HandHistoryRetained
+    );
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PokerError;
+use crate::state::{name_hash, NameRecord, PlayerProfile};
+
+#[derive(Accounts)]
+pub struct CloseProfile<'info> {
+    #[account(
+        mut,
+        close = wallet,
+        has_one = wallet @ PokerError::NotAuthorized,
+        seeds = [b"profile", wallet.key().as_ref()],
+        bump = profile.bump,
+    )]
+    pub profile: Account<'info, PlayerProfile>,
+    #[account(
+        mut,
+        close = wallet,
+        seeds = [b"name".as_ref(), &name_hash(&profile.display_name)],
+        bump = name_record.bump,
+    )]
+    pub name_record: Account<'info, NameRecord>,
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<CloseProfile>) -> Result<()> {
+    // Closing would lift the limits a player set on themselves, and a new
+    // profile starts without any, so they have to be lifted the slow way first
+    let now = Clock::get()?.unix_timestamp;
+    let limits = &mut ctx.accounts.profile.limits;
+    require!(now >= limits.excluded_until, PokerError::SelfExcluded);
+    require!(!limits.in_force(now), PokerError::LimitsInForce);
+
+    Ok(())
+}
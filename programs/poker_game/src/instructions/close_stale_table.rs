@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::STALE_TABLE_EPOCHS;
+use crate::engine::{cash_out_all, Memo, Memos};
+use crate::errors::PokerError;
+use crate::state::{Game, MemoConfig, Season, TableConfig};
+
+#[derive(Accounts)]
+pub struct CloseStaleTable<'info> {
+    #[account(
+        mut,
+        close = caller,
+        constraint = !game.load()?.is_tournament_table() @ PokerError::TournamentTable,
+        constraint = !game.load()?.in_hand() && game.load()?.pot == 0 @ PokerError::TableNotStale
+    )]
+    pub game: AccountLoader<'info, Game>,
+    #[account(
+        mut,
+        close = caller,
+        seeds = [b"table_config", game.key().as_ref()],
+        bump = config.load()?.bump,
+    )]
+    pub config: AccountLoader<'info, TableConfig>,
+
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    /// A season listing the table, which frees its venue slot when passed.
+    #[account(mut)]
+    pub season: Option<Account<'info, Season>>,
+    /// The table's memo tag and the Memo program; required at tables that
+    /// tag withdrawals with memos and still seat players.
+    #[account(seeds = [b"memo_config", game.key().as_ref()], bump = memo_config.bump)]
+    pub memo_config: Option<Account<'info, MemoConfig>>,
+    pub memo_program: Option<Program<'info, Memo>>,
+}
+
+pub(crate) fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, CloseStaleTable<'info>>) -> Result<()> {
+    let game_info = ctx.accounts.game.to_account_info();
+    let config = ctx.accounts.config.load()?;
+    let mut game = ctx.accounts.game.load_mut()?;
+
+    require!(
+        Clock::get()?.epoch >= game.last_active_epoch + STALE_TABLE_EPOCHS,
+        PokerError::TableNotStale
+    );
+
+    // Whoever is still seated gets their stack back before the table goes
+    let memos = Memos::new(&config, ctx.accounts.memo_config.as_deref(), ctx.accounts.memo_program.as_deref());
+    cash_out_all(&mut game, &game_info, ctx.remaining_accounts, memos)?;
+
+    if let Some(season) = ctx.accounts.season.as_mut() {
+        season.remove_venue(&game_info.key());
+    }
+
+    Ok(())
+}
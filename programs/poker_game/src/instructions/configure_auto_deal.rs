@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_PLAYERS;
+use crate::errors::PokerError;
+use crate::state::{Game, TableConfig};
+
+#[derive(Accounts)]
+pub struct ConfigureTable<'info> {
+    pub game: AccountLoader<'info, Game>,
+    #[account(mut, seeds = [b"table_config", game.key().as_ref()], bump = config.load()?.bump)]
+    pub config: AccountLoader<'info, TableConfig>,
+    /// The table owner or its operator key.
+    #[account(constraint = config.load()?.is_host(&signer.key()) @ PokerError::NotAuthorized)]
+    pub signer: Signer<'info>,
+}
+
+pub(crate) fn handler(
+    ctx: Context<ConfigureTable>,
+    enabled: bool,
+    min_players: u8,
+    hand_delay: i64,
+) -> Result<()> {
+    let mut config = ctx.accounts.config.load_mut()?;
+
+    require!(
+        (2..=MAX_PLAYERS as u8).contains(&min_players) && hand_delay >= 0,
+        PokerError::InvalidAutoDeal
+    );
+
+    config.auto_deal = u8::from(enabled);
+    config.min_players = min_players;
+    config.hand_delay = hand_delay;
+
+    Ok(())
+}
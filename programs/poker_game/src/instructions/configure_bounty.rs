@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PokerError;
+use crate::instructions::ConfigureTournament;
+use crate::state::TournamentStatus;
+
+pub(crate) fn handler(ctx: Context<ConfigureTournament>, bounty: u64) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+
+    require!(
+        tournament.status == TournamentStatus::Registering,
+        PokerError::RegistrationClosed
+    );
+    // Entrants already registered bought in under the current terms
+    require!(tournament.entrant_count == 0, PokerError::BountyLocked);
+    require!(bounty <= tournament.buy_in, PokerError::InvalidBounty);
+
+    tournament.bounty = bounty;
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PokerError;
+use crate::state::{Game, TableArbiter, TableConfig};
+
+#[derive(Accounts)]
+pub struct ConfigureDisputes<'info> {
+    #[account(constraint = !game.load()?.in_hand() @ PokerError::TableInHand)]
+    pub game: AccountLoader<'info, Game>,
+    #[account(mut, seeds = [b"table_config", game.key().as_ref()], bump = config.load()?.bump)]
+    pub config: AccountLoader<'info, TableConfig>,
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + TableArbiter::LEN,
+        seeds = [b"arbiter", game.key().as_ref()],
+        bump,
+    )]
+    pub arbiter: Account<'info, TableArbiter>,
+    #[account(mut, constraint = owner.key() == config.load()?.owner @ PokerError::NotAuthorized)]
+    pub owner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(
+    ctx: Context<ConfigureDisputes>,
+    arbiter: Pubkey,
+    dispute_window: u32,
+) -> Result<()> {
+    ctx.accounts.config.load_mut()?.dispute_window = dispute_window;
+
+    let record = &mut ctx.accounts.arbiter;
+    record.game = ctx.accounts.game.key();
+    record.arbiter = arbiter;
+    record.bump = ctx.bumps.arbiter;
+
+    Ok(())
+}
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PokerError;
+use crate::state::{EntryGate, Tournament, TournamentStatus};
+
+#[derive(Accounts)]
+pub struct ConfigureTournament<'info> {
+    #[account(mut, has_one = authority @ PokerError::NotAuthorized)]
+    pub tournament: Account<'info, Tournament>,
+    pub authority: Signer<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<ConfigureTournament>, entry_gate: EntryGate) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+
+    require!(
+        tournament.status == TournamentStatus::Registering,
+        PokerError::RegistrationClosed
+    );
+    require!(tournament.buy_in == 0, PokerError::NotFreeroll);
+
+    tournament.entry_gate = entry_gate;
+
+    Ok(())
+}
@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+
+use crate::instructions::ConfigureTable;
+
+pub(crate) fn handler(ctx: Context<ConfigureTable>, gatekeeper: Pubkey) -> Result<()> {
+    let mut config = ctx.accounts.config.load_mut()?;
+    config.gatekeeper = gatekeeper;
+
+    Ok(())
+}
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PokerError;
+use crate::instructions::ConfigureTournament;
+use crate::state::TournamentStatus;
+
+pub(crate) fn handler(
+    ctx: Context<ConfigureTournament>,
+    levels: u8,
+) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+
+    require!(
+        tournament.status == TournamentStatus::Registering,
+        PokerError::RegistrationClosed
+    );
+
+    tournament.late_registration_levels = levels;
+
+    Ok(())
+}
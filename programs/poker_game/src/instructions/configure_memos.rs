@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_MEMO_TAG_LEN;
+use crate::errors::PokerError;
+use crate::state::{FundMovement, Game, MemoConfig, TableConfig};
+
+#[derive(Accounts)]
+pub struct ConfigureMemos<'info> {
+    pub game: AccountLoader<'info, Game>,
+    #[account(mut, seeds = [b"table_config", game.key().as_ref()], bump = config.load()?.bump)]
+    pub config: AccountLoader<'info, TableConfig>,
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = 8 + MemoConfig::LEN,
+        seeds = [b"memo_config", game.key().as_ref()],
+        bump,
+    )]
+    pub memo_config: Account<'info, MemoConfig>,
+    /// The table owner or its operator key.
+    #[account(mut, constraint = config.load()?.is_host(&signer.key()) @ PokerError::NotAuthorized)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<ConfigureMemos>, tag: String, movements: Vec<FundMovement>) -> Result<()> {
+    require!(tag.len() <= MAX_MEMO_TAG_LEN, PokerError::MemoTagTooLong);
+
+    let memo_config = &mut ctx.accounts.memo_config;
+    memo_config.game = ctx.accounts.game.key();
+    memo_config.tag = tag;
+    memo_config.bump = ctx.bumps.memo_config;
+
+    ctx.accounts.config.load_mut()?.memo_movements = movements.iter().fold(0, |bits, movement| bits | movement.bit());
+
+    Ok(())
+}
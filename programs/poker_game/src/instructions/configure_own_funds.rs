@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+use crate::instructions::ConfigureTableState;
+
+pub(crate) fn handler(ctx: Context<ConfigureTableState>, own_funds_only: bool) -> Result<()> {
+    let mut config = ctx.accounts.config.load_mut()?;
+    config.own_funds_only = u8::from(own_funds_only);
+    ctx.accounts.game.load_mut()?.gated_top_ups = u8::from(config.gates_top_ups());
+
+    Ok(())
+}
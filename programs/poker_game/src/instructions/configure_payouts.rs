@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PokerError;
+use crate::instructions::ConfigureTournament;
+use crate::state::{PayoutStructure, TournamentStatus};
+
+pub(crate) fn handler(
+    ctx: Context<ConfigureTournament>,
+    payout_structure: PayoutStructure,
+) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+
+    require!(
+        tournament.status == TournamentStatus::Registering,
+        PokerError::RegistrationClosed
+    );
+    require!(payout_structure.is_valid(), PokerError::InvalidPayoutStructure);
+
+    tournament.payout_structure = payout_structure;
+
+    Ok(())
+}
@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PokerError;
+use crate::instructions::ConfigureTable;
+
+pub(crate) fn handler(ctx: Context<ConfigureTable>, min_rating: u16, max_rating: u16) -> Result<()> {
+    let mut config = ctx.accounts.config.load_mut()?;
+
+    require!(max_rating == 0 || min_rating <= max_rating, PokerError::InvalidRatingRange);
+    config.min_rating = min_rating;
+    config.max_rating = max_rating;
+
+    Ok(())
+}
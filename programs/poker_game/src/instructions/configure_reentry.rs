@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PokerError;
+use crate::instructions::ConfigureTournament;
+use crate::state::TournamentStatus;
+
+pub(crate) fn handler(
+    ctx: Context<ConfigureTournament>,
+    reentry_levels: u8,
+    max_reentries: u8,
+) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+
+    require!(
+        tournament.status == TournamentStatus::Registering,
+        PokerError::RegistrationClosed
+    );
+
+    tournament.reentry_levels = reentry_levels;
+    tournament.max_reentries = max_reentries;
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_ROTATION;
+use crate::errors::PokerError;
+use crate::instructions::ConfigureTableState;
+use crate::state::GameVariant;
+
+pub(crate) fn handler(
+    ctx: Context<ConfigureTableState>,
+    variants: Vec<GameVariant>,
+    hands_per_variant: u8,
+) -> Result<()> {
+    let mut config = ctx.accounts.config.load_mut()?;
+    let mut game = ctx.accounts.game.load_mut()?;
+    game.next_seq();
+
+    require!(!game.in_hand(), PokerError::TableInHand);
+    require!(variants.len() <= MAX_ROTATION, PokerError::InvalidRotation);
+
+    config.rotation = [GameVariant::Holdem as u8; MAX_ROTATION];
+    for (slot, variant) in config.rotation.iter_mut().zip(&variants) {
+        *slot = *variant as u8;
+    }
+    config.rotation_len = variants.len() as u8;
+    config.hands_per_variant = hands_per_variant;
+    game.rotation_index = 0;
+    game.hands_in_variant = 0;
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PokerError;
+use crate::state::{Tournament, TournamentStatus};
+
+#[derive(Accounts)]
+pub struct ConfigureSatellite<'info> {
+    #[account(mut, has_one = authority @ PokerError::NotAuthorized)]
+    pub tournament: Account<'info, Tournament>,
+    #[account(constraint = target_tournament.key() != tournament.key() @ PokerError::TableMismatch)]
+    pub target_tournament: Account<'info, Tournament>,
+    pub authority: Signer<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<ConfigureSatellite>, seats: u8) -> Result<()> {
+    let target = &ctx.accounts.target_tournament;
+    let tournament = &mut ctx.accounts.tournament;
+
+    require!(
+        tournament.status == TournamentStatus::Registering,
+        PokerError::RegistrationClosed
+    );
+    require!(
+        target.status == TournamentStatus::Registering,
+        PokerError::RegistrationClosed
+    );
+    require!(seats > 0, PokerError::InvalidPayoutStructure);
+
+    tournament.satellite_target = target.key();
+    tournament.satellite_seats = seats;
+    tournament.ticket_value = target.buy_in;
+
+    Ok(())
+}
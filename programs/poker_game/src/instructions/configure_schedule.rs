@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_ENTRANTS;
+use crate::errors::PokerError;
+use crate::instructions::ConfigureTournament;
+use crate::state::TournamentStatus;
+
+pub(crate) fn handler(
+    ctx: Context<ConfigureTournament>,
+    start_time: i64,
+    min_entrants: u8,
+) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+
+    require!(
+        tournament.status == TournamentStatus::Registering,
+        PokerError::RegistrationClosed
+    );
+    require!(
+        start_time > 0 && min_entrants >= 2 && min_entrants as usize <= MAX_ENTRANTS,
+        PokerError::InvalidSchedule
+    );
+
+    tournament.start_time = start_time;
+    tournament.min_entrants = min_entrants;
+
+    Ok(())
+}
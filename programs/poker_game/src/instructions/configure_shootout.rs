@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PokerError;
+use crate::instructions::ConfigureTournament;
+use crate::state::{TournamentFormat, TournamentStatus};
+
+pub(crate) fn handler(ctx: Context<ConfigureTournament>) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+
+    require!(
+        tournament.status == TournamentStatus::Registering,
+        PokerError::RegistrationClosed
+    );
+
+    tournament.format = TournamentFormat::Shootout;
+
+    Ok(())
+}
@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_STAKING_PROGRAMS;
+use crate::errors::PokerError;
+use crate::state::{GlobalConfig, StakingProgram};
+
+#[derive(Accounts)]
+pub struct ConfigureStakingAllowlist<'info> {
+    #[account(mut, seeds = [b"global_config"], bump = global_config.bump, has_one = authority @ PokerError::NotAuthorized)]
+    pub global_config: Account<'info, GlobalConfig>,
+    pub authority: Signer<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<ConfigureStakingAllowlist>, staking_programs: Vec<StakingProgram>) -> Result<()> {
+    require!(staking_programs.len() <= MAX_STAKING_PROGRAMS, PokerError::StakingProgramNotAllowed);
+    ctx.accounts.global_config.staking_programs = staking_programs;
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PokerError;
+use crate::instructions::ConfigureTable;
+use crate::state::TableRules;
+
+pub(crate) fn handler(
+    ctx: Context<ConfigureTable>,
+    action_slot_window: u64,
+    crank_slot_window: u64,
+    all_in_protection_hands: u8,
+    blind_double_interval: i64,
+    crank_reward: u64,
+    crank_reward_cap: u64,
+) -> Result<()> {
+    let game = ctx.accounts.game.load()?;
+    let mut config = ctx.accounts.config.load_mut()?;
+
+    require!(!game.in_hand(), PokerError::TableInHand);
+    require!(blind_double_interval >= 0, PokerError::InvalidTableRules);
+    let rules = TableRules {
+        action_slot_window,
+        crank_slot_window,
+        blind_double_interval,
+        crank_reward,
+        crank_reward_cap,
+        all_in_protection_hands,
+        padding: [0; 7],
+    };
+
+    // Escalation runs from the current stakes
+    config.escalation_started_at = Clock::get()?.unix_timestamp;
+    config.base_small_blind = game.small_blind;
+    config.base_big_blind = game.big_blind;
+    config.rules = rules;
+
+    Ok(())
+}
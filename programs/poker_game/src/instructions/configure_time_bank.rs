@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_PLAYERS;
+use crate::errors::PokerError;
+use crate::state::{Game, TableConfig};
+
+/// For settings whose change also resets counters kept on the game.
+#[derive(Accounts)]
+pub struct ConfigureTableState<'info> {
+    #[account(mut)]
+    pub game: AccountLoader<'info, Game>,
+    #[account(mut, seeds = [b"table_config", game.key().as_ref()], bump = config.load()?.bump)]
+    pub config: AccountLoader<'info, TableConfig>,
+    /// The table owner or its operator key.
+    #[account(constraint = config.load()?.is_host(&signer.key()) @ PokerError::NotAuthorized)]
+    pub signer: Signer<'info>,
+}
+
+pub(crate) fn handler(
+    ctx: Context<ConfigureTableState>,
+    time_bank: i64,
+    refill_hands: u8,
+) -> Result<()> {
+    let mut config = ctx.accounts.config.load_mut()?;
+    let mut game = ctx.accounts.game.load_mut()?;
+    game.next_seq();
+
+    require!(time_bank >= 0, PokerError::InvalidTurnTimer);
+
+    config.time_bank = time_bank;
+    config.time_bank_refill_hands = refill_hands;
+    game.hands_since_refill = 0;
+    for seat in 0..MAX_PLAYERS {
+        if game.players[seat] != Pubkey::default() {
+            game.time_banks[seat] = time_bank;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PokerError;
+use crate::instructions::ConfigureTable;
+
+pub(crate) fn handler(ctx: Context<ConfigureTable>, turn_timeout: i64) -> Result<()> {
+    let mut config = ctx.accounts.config.load_mut()?;
+
+    require!(turn_timeout > 0, PokerError::InvalidTurnTimer);
+
+    config.turn_timeout = turn_timeout;
+
+    Ok(())
+}
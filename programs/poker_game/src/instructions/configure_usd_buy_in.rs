@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::engine::check_sol_feed;
+use crate::errors::PokerError;
+use crate::state::{Game, PriceGate, TableConfig};
+
+#[derive(Accounts)]
+#[instruction(feed: Pubkey)]
+pub struct ConfigureUsdBuyIn<'info> {
+    #[account(mut)]
+    pub game: AccountLoader<'info, Game>,
+    #[account(mut, seeds = [b"table_config", game.key().as_ref()], bump = config.load()?.bump)]
+    pub config: AccountLoader<'info, TableConfig>,
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = 8 + PriceGate::LEN,
+        seeds = [b"price_gate", game.key().as_ref()],
+        bump,
+    )]
+    pub price_gate: Account<'info, PriceGate>,
+    /// CHECK: the `feed` being set, checked to be a SOL/USD update; left out
+    /// when lifting the range.
+    #[account(address = feed @ PokerError::InvalidPriceFeed)]
+    pub price_feed: Option<UncheckedAccount<'info>>,
+    /// The table owner or its operator key.
+    #[account(mut, constraint = config.load()?.is_host(&signer.key()) @ PokerError::NotAuthorized)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(
+    ctx: Context<ConfigureUsdBuyIn>,
+    feed: Pubkey,
+    min_buy_in_usd: u64,
+    max_buy_in_usd: u64,
+    max_age: i64,
+) -> Result<()> {
+    require!(
+        (max_buy_in_usd == 0 || min_buy_in_usd <= max_buy_in_usd) && max_age > 0,
+        PokerError::InvalidPriceGate
+    );
+    if feed != Pubkey::default() {
+        let price_feed = ctx.accounts.price_feed.as_ref().ok_or(PokerError::InvalidPriceFeed)?;
+        check_sol_feed(price_feed)?;
+    }
+
+    let gate = &mut ctx.accounts.price_gate;
+    gate.game = ctx.accounts.game.key();
+    gate.feed = feed;
+    gate.min_buy_in_usd = min_buy_in_usd;
+    gate.max_buy_in_usd = max_buy_in_usd;
+    gate.max_age = max_age;
+    gate.bump = ctx.bumps.price_gate;
+
+    let mut config = ctx.accounts.config.load_mut()?;
+    config.usd_buy_in = u8::from(feed != Pubkey::default());
+    ctx.accounts.game.load_mut()?.gated_top_ups = u8::from(config.gates_top_ups());
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_BLIND_LEVELS;
+use crate::errors::PokerError;
+use crate::state::{BlindLevel, BlindStructure};
+
+#[derive(Accounts)]
+pub struct CreateBlindStructure<'info> {
+    #[account(init, payer = authority, space = 8 + BlindStructure::LEN)]
+    pub blind_structure: Account<'info, BlindStructure>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(
+    ctx: Context<CreateBlindStructure>,
+    levels: Vec<BlindLevel>,
+) -> Result<()> {
+    require!(
+        !levels.is_empty() && levels.len() <= MAX_BLIND_LEVELS,
+        PokerError::InvalidBlindStructure
+    );
+    require!(
+        levels.iter().all(|l| l.small_blind > 0 && l.big_blind >= l.small_blind),
+        PokerError::InvalidBlindStructure
+    );
+
+    let structure = &mut ctx.accounts.blind_structure;
+    structure.authority = ctx.accounts.authority.key();
+    structure.levels = [BlindLevel::default(); MAX_BLIND_LEVELS];
+    structure.levels[..levels.len()].copy_from_slice(&levels);
+    structure.level_count = levels.len() as u8;
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_CLUB_NAME_LEN;
+use crate::errors::PokerError;
+use crate::state::{Club, ProtocolCounters};
+
+#[derive(Accounts)]
+#[instruction(name: String)]
+pub struct CreateClub<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + Club::LEN,
+        seeds = [b"club", owner.key().as_ref(), name.as_bytes()],
+        bump,
+    )]
+    pub club: Account<'info, Club>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<CreateClub>, name: String) -> Result<()> {
+    require!(!name.is_empty() && name.len() <= MAX_CLUB_NAME_LEN, PokerError::InvalidClub);
+
+    let club = &mut ctx.accounts.club;
+    club.owner = ctx.accounts.owner.key();
+    club.name = name;
+    club.members = Vec::new();
+    club.stats = ProtocolCounters::default();
+    club.created_at = Clock::get()?.unix_timestamp;
+    club.bump = ctx.bumps.club;
+
+    Ok(())
+}
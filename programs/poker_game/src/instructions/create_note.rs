@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_NOTE_LEN;
+use crate::errors::PokerError;
+use crate::state::PlayerNote;
+
+#[derive(Accounts)]
+#[instruction(opponent: Pubkey, ciphertext: Vec<u8>)]
+pub struct CreateNote<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + PlayerNote::space(ciphertext.len()),
+        seeds = [b"note", owner.key().as_ref(), opponent.as_ref()],
+        bump,
+    )]
+    pub note: Account<'info, PlayerNote>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<CreateNote>, opponent: Pubkey, ciphertext: Vec<u8>) -> Result<()> {
+    require!(ciphertext.len() <= MAX_NOTE_LEN, PokerError::NoteTooLong);
+
+    let note = &mut ctx.accounts.note;
+    note.owner = ctx.accounts.owner.key();
+    note.opponent = opponent;
+    note.updated_at = Clock::get()?.unix_timestamp;
+    note.bump = ctx.bumps.note;
+    note.ciphertext = ciphertext;
+
+    Ok(())
+}
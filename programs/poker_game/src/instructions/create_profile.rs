@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{MAX_AVATAR_URI_LEN, MAX_DISPLAY_NAME_LEN};
+use crate::errors::PokerError;
+use crate::rules::INITIAL_RATING;
+use crate::state::{name_hash, NameRecord, PlayerLimits, PlayerProfile, PlayerStats};
+
+#[derive(Accounts)]
+#[instruction(display_name: String)]
+pub struct CreateProfile<'info> {
+    #[account(
+        init,
+        payer = wallet,
+        space = 8 + PlayerProfile::LEN,
+        seeds = [b"profile", wallet.key().as_ref()],
+        bump,
+    )]
+    pub profile: Account<'info, PlayerProfile>,
+    /// Creating it fails when the name is taken.
+    #[account(
+        init,
+        payer = wallet,
+        space = 8 + NameRecord::LEN,
+        seeds = [b"name".as_ref(), &name_hash(&display_name)],
+        bump,
+    )]
+    pub name_record: Account<'info, NameRecord>,
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<CreateProfile>, display_name: String, avatar_uri: String) -> Result<()> {
+    require!(valid_display_name(&display_name), PokerError::InvalidProfile);
+    require!(avatar_uri.len() <= MAX_AVATAR_URI_LEN, PokerError::InvalidProfile);
+
+    let name_record = &mut ctx.accounts.name_record;
+    name_record.owner = ctx.accounts.wallet.key();
+    name_record.bump = ctx.bumps.name_record;
+
+    let profile = &mut ctx.accounts.profile;
+    profile.wallet = ctx.accounts.wallet.key();
+    profile.display_name = display_name;
+    profile.avatar_uri = avatar_uri;
+    profile.created_at = Clock::get()?.unix_timestamp;
+    profile.stats = PlayerStats::default();
+    profile.rating = INITIAL_RATING;
+    profile.achievements = 0;
+    profile.friends = Vec::new();
+    profile.limits = PlayerLimits::default();
+    profile.bump = ctx.bumps.profile;
+
+    Ok(())
+}
+
+// Names are stored as given but must not be blank once trimmed
+pub(crate) fn valid_display_name(name: &str) -> bool {
+    !name.trim().is_empty() && name.len() <= MAX_DISPLAY_NAME_LEN
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::SEASON_PAID_PLACES;
+use crate::errors::PokerError;
+use crate::state::{LeaderboardEntry, Season, SeasonWeights};
+
+#[derive(Accounts)]
+#[instruction(id: u32)]
+pub struct CreateSeason<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Season::LEN,
+        seeds = [b"season", authority.key().as_ref(), &id.to_le_bytes()],
+        bump,
+    )]
+    pub season: Account<'info, Season>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(
+    ctx: Context<CreateSeason>,
+    id: u32,
+    starts_at: i64,
+    ends_at: i64,
+    weights: SeasonWeights,
+    prize_shares: [u16; SEASON_PAID_PLACES],
+) -> Result<()> {
+    require!(ends_at > starts_at, PokerError::InvalidSeason);
+    require!(
+        prize_shares.iter().map(|&bps| bps as u32).sum::<u32>() <= 10_000,
+        PokerError::InvalidSeason
+    );
+
+    let season = &mut ctx.accounts.season;
+    season.authority = ctx.accounts.authority.key();
+    season.id = id;
+    season.starts_at = starts_at;
+    season.ends_at = ends_at;
+    season.weights = weights;
+    season.prize_shares = prize_shares;
+    season.prize_pool = 0;
+    season.venue_count = 0;
+    season.standings = [LeaderboardEntry::default(); SEASON_PAID_PLACES];
+    season.bump = ctx.bumps.season;
+
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{MAX_PLAYERS, MAX_TABLES};
+use crate::engine::{init_tournament_table, seat_tournament_player};
+use crate::errors::PokerError;
+use crate::state::{Game, TableConfig, Tournament, TournamentFormat, TournamentStatus};
+
+#[derive(Accounts)]
+pub struct CreateShootoutTable<'info> {
+    #[account(mut)]
+    pub tournament: Account<'info, Tournament>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Game::LEN,
+        seeds = [
+            b"shootout_table",
+            tournament.key().as_ref(),
+            &[tournament.shootout_round + 1, tournament.table_count],
+        ],
+        bump,
+    )]
+    pub game: AccountLoader<'info, Game>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + TableConfig::LEN,
+        seeds = [b"table_config", game.key().as_ref()],
+        bump,
+    )]
+    pub config: AccountLoader<'info, TableConfig>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<CreateShootoutTable>) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+    let game_key = ctx.accounts.game.key();
+    let mut game = ctx.accounts.game.load_init()?;
+    let mut config = ctx.accounts.config.load_init()?;
+
+    require!(
+        tournament.status == TournamentStatus::Running,
+        PokerError::TournamentNotRunning
+    );
+    require!(
+        tournament.format == TournamentFormat::Shootout,
+        PokerError::NotShootout
+    );
+    require!(
+        tournament.advancer_count == tournament.round_tables,
+        PokerError::WaitingForTables
+    );
+
+    let advancers = tournament.advancer_count as usize;
+    let needed = advancers.div_ceil(MAX_PLAYERS);
+    let index = tournament.table_count as usize;
+
+    init_tournament_table(&mut game, tournament);
+    game.bump = ctx.bumps.game;
+    config.init(
+        game_key,
+        tournament.authority,
+        tournament.small_blind,
+        tournament.big_blind,
+        ctx.bumps.config,
+    );
+
+    // Advancers are dealt round-robin across the new round's tables
+    for i in (index..advancers).step_by(needed) {
+        seat_tournament_player(&mut game, &config, tournament.advancers[i], tournament.starting_stack)?;
+    }
+
+    tournament.tables[index] = game_key;
+    tournament.table_count += 1;
+
+    if tournament.table_count as usize == needed {
+        tournament.shootout_round += 1;
+        tournament.round_tables = needed as u8;
+        tournament.advancers = [Pubkey::default(); MAX_TABLES];
+        tournament.advancer_count = 0;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{MAX_ENTRANTS, MAX_PLAYERS, MAX_TABLES, NO_ELIMINATOR};
+use crate::errors::PokerError;
+use crate::state::{BlindStructure, EntryGate, PayoutStructure, Tournament, TournamentFormat, TournamentStatus};
+
+#[derive(Accounts)]
+pub struct CreateTournament<'info> {
+    #[account(init, payer = authority, space = 8 + Tournament::LEN)]
+    pub tournament: Account<'info, Tournament>,
+    /// Optional escalating blind schedule; flat blinds are used without one.
+    pub blind_structure: Option<Account<'info, BlindStructure>>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(
+    ctx: Context<CreateTournament>,
+    buy_in: u64,
+    starting_stack: u64,
+    small_blind: u64,
+    big_blind: u64,
+) -> Result<()> {
+    // The tables it seats must be able to post the blinds
+    require!(small_blind > 0, PokerError::ZeroBlind);
+    require!(big_blind >= small_blind, PokerError::BigBlindTooSmall);
+    require!(starting_stack >= big_blind, PokerError::StartingStackTooSmall);
+
+    let tournament = &mut ctx.accounts.tournament;
+
+    tournament.authority = ctx.accounts.authority.key();
+    tournament.buy_in = buy_in;
+    tournament.starting_stack = starting_stack;
+    tournament.small_blind = small_blind;
+    tournament.big_blind = big_blind;
+    tournament.prize_pool = 0;
+    tournament.entrants = [Pubkey::default(); MAX_ENTRANTS];
+    tournament.entrant_count = 0;
+    tournament.players_remaining = 0;
+    tournament.tables = [Pubkey::default(); MAX_TABLES];
+    tournament.table_count = 0;
+    tournament.status = TournamentStatus::Registering;
+    tournament.blind_structure = ctx
+        .accounts
+        .blind_structure
+        .as_ref()
+        .map_or(Pubkey::default(), |structure| structure.key());
+    tournament.started_at = 0;
+    tournament.eliminated = [false; MAX_ENTRANTS];
+    tournament.entries = [0; MAX_ENTRANTS];
+    tournament.reentry_levels = 0;
+    tournament.max_reentries = 0;
+    tournament.late_registration_levels = 0;
+    tournament.finish_positions = [0; MAX_ENTRANTS];
+    tournament.payout_structure = PayoutStructure::WinnerTakesAll;
+    tournament.satellite_target = Pubkey::default();
+    tournament.satellite_seats = 0;
+    tournament.ticket_value = 0;
+    tournament.tickets_claimed = [false; MAX_ENTRANTS];
+    tournament.hand_for_hand = false;
+    tournament.hfh_dealt = [false; MAX_TABLES];
+    tournament.hfh_ready = [false; MAX_TABLES];
+    tournament.bounty = 0;
+    tournament.bounties = [0; MAX_ENTRANTS];
+    tournament.bounty_winnings = [0; MAX_ENTRANTS];
+    tournament.format = TournamentFormat::Standard;
+    tournament.shootout_round = 0;
+    tournament.round_tables = 0;
+    tournament.advancers = [Pubkey::default(); MAX_TABLES];
+    tournament.advancer_count = 0;
+    tournament.sponsored = 0;
+    tournament.entry_gate = EntryGate::Open;
+    tournament.deal_players = [Pubkey::default(); MAX_PLAYERS];
+    tournament.deal_amounts = [0; MAX_PLAYERS];
+    tournament.deal_accepted = [false; MAX_PLAYERS];
+    tournament.deal_count = 0;
+    tournament.start_time = 0;
+    tournament.min_entrants = 0;
+    tournament.seat_seed = 0;
+    tournament.eliminated_by = [NO_ELIMINATOR; MAX_ENTRANTS];
+    tournament.elimination_levels = [0; MAX_ENTRANTS];
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::LAST_STUD_STREET;
+use crate::engine::deal_next_street;
+use crate::errors::PokerError;
+use crate::state::{Game, GameVariant, TableConfig, load_extended};
+
+#[derive(Accounts)]
+pub struct DealStreet<'info> {
+    #[account(
+        mut,
+        constraint = game.load()?.is_betting() @ PokerError::GameNotActive,
+        constraint = game.load()?.variant() == GameVariant::Stud @ PokerError::WrongVariant
+    )]
+    pub game: AccountLoader<'info, Game>,
+    #[account(seeds = [b"table_config", game.key().as_ref()], bump = config.load()?.bump)]
+    pub config: AccountLoader<'info, TableConfig>,
+}
+
+pub(crate) fn handler(ctx: Context<DealStreet>) -> Result<()> {
+    let game_key = ctx.accounts.game.key();
+    let config = ctx.accounts.config.load()?;
+    let (mut game, mut extensions) = load_extended(&ctx.accounts.game)?;
+    game.next_seq();
+
+    require!(game.street() < LAST_STUD_STREET, PokerError::HandComplete);
+    require!(game.betting_complete(), PokerError::BettingNotComplete);
+
+    deal_next_street(&mut game, &mut extensions, game_key, &config, Clock::get()?.unix_timestamp)
+}
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_PLAYERS;
+use crate::errors::PokerError;
+use crate::events::DealDeclined;
+use crate::instructions::AcceptDeal;
+
+pub(crate) fn handler(ctx: Context<AcceptDeal>) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+    let player = ctx.accounts.player.key();
+
+    require!(tournament.deal_count > 0, PokerError::NoDealProposed);
+    let n = tournament.deal_count as usize;
+    require!(
+        tournament.deal_players[..n].contains(&player),
+        PokerError::PlayerNotInGame
+    );
+
+    tournament.deal_count = 0;
+    tournament.deal_accepted = [false; MAX_PLAYERS];
+
+    emit_event!(DealDeclined {
+        tournament: tournament.key(),
+        player,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PokerError;
+use crate::state::PlayerNote;
+
+#[derive(Accounts)]
+pub struct DeleteNote<'info> {
+    #[account(
+        mut,
+        close = owner,
+        has_one = owner @ PokerError::NotAuthorized,
+        seeds = [b"note", owner.key().as_ref(), note.opponent.as_ref()],
+        bump = note.bump,
+    )]
+    pub note: Account<'info, PlayerNote>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+pub(crate) fn handler(_ctx: Context<DeleteNote>) -> Result<()> {
+    Ok(())
+}
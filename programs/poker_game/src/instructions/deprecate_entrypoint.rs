@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+use crate::instructions::ConfigureTable;
+use crate::state::LegacyEntrypoint;
+
+pub(crate) fn handler(ctx: Context<ConfigureTable>, entrypoint: LegacyEntrypoint, deprecated: bool) -> Result<()> {
+    let mut config = ctx.accounts.config.load_mut()?;
+
+    if deprecated {
+        config.deprecated |= entrypoint.bit();
+    } else {
+        config.deprecated &= !entrypoint.bit();
+    }
+
+    Ok(())
+}
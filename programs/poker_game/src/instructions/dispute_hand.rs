@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::engine::hand_id;
+use crate::errors::PokerError;
+use crate::events::HandDisputed;
+use crate::state::{Game, GamePhase, Seat, TableConfig};
+
+#[derive(Accounts)]
+pub struct DisputeHand<'info> {
+    #[account(
+        mut,
+        constraint = game.load()?.settled_hand != game.load()?.hand_number @ PokerError::AlreadySettled,
+        constraint = game.load()?.phase() == GamePhase::Showdown @ PokerError::HandNotComplete,
+        constraint = game.load()?.disputed == 0 @ PokerError::HandDisputed
+    )]
+    pub game: AccountLoader<'info, Game>,
+    #[account(seeds = [b"table_config", game.key().as_ref()], bump = config.load()?.bump)]
+    pub config: AccountLoader<'info, TableConfig>,
+    pub player: Signer<'info>,
+    #[account(
+        seeds = [b"seat", game.key().as_ref(), player.key().as_ref()],
+        bump = seat.bump,
+        has_one = game @ PokerError::SeatMismatch,
+        constraint = seat.owner == player.key() @ PokerError::SeatMismatch,
+        constraint = game.load()?.players.get(seat.index as usize) == Some(&player.key()) @ PokerError::SeatMismatch,
+    )]
+    pub seat: Account<'info, Seat>,
+}
+
+pub(crate) fn handler(ctx: Context<DisputeHand>) -> Result<()> {
+    let game_key = ctx.accounts.game.key();
+    let mut game = ctx.accounts.game.load_mut()?;
+    game.next_seq();
+
+    require!(
+        Clock::get()?.unix_timestamp < game.showdown_at + ctx.accounts.config.load()?.dispute_window as i64,
+        PokerError::DisputeWindowClosed
+    );
+    game.disputed = 1;
+
+    emit_event!(HandDisputed {
+        game: game_key,
+        seq: game.state_seq,
+        hand_id: hand_id(&game_key, game.hand_number),
+        hand_number: game.hand_number,
+        player: ctx.accounts.player.key(),
+        seat: ctx.accounts.seat.index,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_PLAYERS;
+use crate::engine::rate_limit;
+use crate::errors::PokerError;
+use crate::instructions::ViewTable;
+use crate::state::{ActionPreview, ActionTicket, ProposedAction};
+
+pub(crate) fn handler(
+    ctx: Context<ViewTable>,
+    seat: u8,
+    action: ProposedAction,
+    ticket: ActionTicket,
+) -> Result<ActionPreview> {
+    let clock = Clock::get()?;
+    let config = ctx.accounts.config.load()?;
+    // The action plays out on a copy; the account itself is never written
+    let mut game = *ctx.accounts.game.load()?;
+    let seat = seat as usize;
+
+    require!(game.in_hand(), PokerError::GameNotActive);
+    require!(game.is_betting(), PokerError::BettingClosed);
+    require!(game.halted == 0, PokerError::TableHalted);
+    require!(seat < MAX_PLAYERS && game.players[seat] != Pubkey::default(), PokerError::SeatEmpty);
+
+    // Same checks, in the same order, as `bet`, `call` and `fold`
+    if !game.is_retry(seat, &ticket) {
+        if game.folded[seat] != 0 {
+            return match action {
+                ProposedAction::Fold => err!(PokerError::PlayerAlreadyFolded),
+                _ => err!(PokerError::PlayerFolded),
+            };
+        }
+        require!(seat as u8 == game.current_turn, PokerError::NotPlayersTurn);
+        rate_limit(&mut game.last_action_slot[seat], config.rules.action_slot_window, clock.slot)?;
+        game.consume_action(seat, &ticket)?;
+
+        match action {
+            ProposedAction::Bet { amount } => {
+                game.apply_bet(seat, amount)?;
+            }
+            ProposedAction::Call => {
+                game.apply_call(seat)?;
+            }
+            ProposedAction::Fold => game.apply_fold(seat),
+        }
+        game.pass_action(clock.unix_timestamp, &config)?;
+    }
+
+    let next_turn = (game.is_betting() && !game.betting_complete()).then_some(game.current_turn);
+    Ok(ActionPreview {
+        phase: game.phase(),
+        pot: game.pot,
+        current_bet: game.current_bet,
+        stack: game.stacks[seat],
+        street_bet: game.player_bets[seat],
+        next_turn,
+        to_call: next_turn.map_or(0, |next| game.current_bet.saturating_sub(game.player_bets[next as usize])),
+    })
+}
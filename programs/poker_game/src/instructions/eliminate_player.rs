@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{MAX_PLAYERS, NO_ELIMINATOR};
+use crate::engine::{hand_id, tournament_blind_level};
+use crate::errors::PokerError;
+use crate::events::{DealDeclined, PlayerEliminated};
+use crate::state::{BlindStructure, Game, Tournament, TournamentStatus};
+
+#[derive(Accounts)]
+pub struct EliminatePlayer<'info> {
+    #[account(mut)]
+    pub tournament: Account<'info, Tournament>,
+    #[account(mut, constraint = table.load()?.tournament == tournament.key() @ PokerError::TableMismatch)]
+    pub table: AccountLoader<'info, Game>,
+    /// Required when the tournament uses a blind structure.
+    pub blind_structure: Option<Account<'info, BlindStructure>>,
+}
+
+pub(crate) fn handler(ctx: Context<EliminatePlayer>, seat: u8) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+    let table_key = ctx.accounts.table.key();
+    let mut table = ctx.accounts.table.load_mut()?;
+    table.next_seq();
+    let seat = seat as usize;
+
+    require!(
+        tournament.status == TournamentStatus::Running,
+        PokerError::TournamentNotRunning
+    );
+    require!(!table.in_hand(), PokerError::TableInHand);
+    require!(seat < MAX_PLAYERS, PokerError::SeatEmpty);
+    require!(table.players[seat] != Pubkey::default(), PokerError::SeatEmpty);
+    require!(table.stacks[seat] == 0, PokerError::PlayerHasChips);
+
+    let entrant = tournament
+        .entrant_index(&table.players[seat])
+        .ok_or(PokerError::PlayerNotInGame)?;
+    let (level, _) = tournament_blind_level(
+        tournament,
+        ctx.accounts.blind_structure.as_ref(),
+        Clock::get()?.unix_timestamp,
+    )?
+    .unwrap_or_default();
+
+    tournament.eliminated[entrant] = true;
+    tournament.finish_positions[entrant] = tournament.players_remaining;
+    tournament.elimination_levels[entrant] = level as u8;
+    tournament.eliminated_by[entrant] = NO_ELIMINATOR;
+
+    // Any pending deal was struck for a different field
+    if tournament.deal_count > 0 {
+        tournament.deal_count = 0;
+        emit_event!(DealDeclined {
+            tournament: tournament.key(),
+            player: Pubkey::default(),
+        });
+    }
+
+    // Progressive knockout: the player who won the busting pot takes half
+    // the bounty in cash and adds the other half to their own head
+    let mut bounty_won = 0;
+    let eliminator = tournament
+        .entrant_index(&table.last_winner)
+        .filter(|&eliminator| eliminator != entrant);
+    if let Some(eliminator) = eliminator {
+        let bounty = tournament.bounties[entrant];
+        let cash = bounty / 2;
+        tournament.bounty_winnings[eliminator] += cash;
+        tournament.bounties[eliminator] += bounty - cash;
+        tournament.bounties[entrant] = 0;
+        tournament.eliminated_by[entrant] = eliminator as u8;
+        bounty_won = cash;
+    }
+
+    emit_event!(PlayerEliminated {
+        game: table_key,
+        seq: table.state_seq,
+        hand_id: hand_id(&table_key, table.hand_number),
+        tournament: tournament.key(),
+        player: table.players[seat],
+        eliminator: eliminator.map_or(Pubkey::default(), |e| tournament.entrants[e]),
+        level: level as u8,
+        finish_position: tournament.finish_positions[entrant],
+        bounty_won,
+    });
+
+    table.players[seat] = Pubkey::default();
+    table.folded[seat] = 0;
+    table.player_bets[seat] = 0;
+    table.players_in_round = table.seated_count() as u8;
+    tournament.players_remaining -= 1;
+    tournament.refresh_hand_for_hand();
+
+    Ok(())
+}
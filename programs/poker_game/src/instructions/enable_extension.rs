@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PokerError;
+use crate::state::{Game, TableConfig, TableExtension, load_extended};
+
+#[derive(Accounts)]
+#[instruction(extension: TableExtension)]
+pub struct EnableExtension<'info> {
+    #[account(
+        mut,
+        constraint = !game.load()?.in_hand() @ PokerError::TableInHand,
+        realloc = TableExtension::account_len(game.load()?.extensions | extension.bit()),
+        realloc::payer = signer,
+        realloc::zero = false,
+    )]
+    pub game: AccountLoader<'info, Game>,
+    #[account(seeds = [b"table_config", game.key().as_ref()], bump = config.load()?.bump)]
+    pub config: AccountLoader<'info, TableConfig>,
+    /// The table owner or its operator key; pays the extra rent.
+    #[account(mut, constraint = config.load()?.is_host(&signer.key()) @ PokerError::NotAuthorized)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<EnableExtension>, extension: TableExtension) -> Result<()> {
+    let (mut game, mut tail) = load_extended(&ctx.accounts.game)?;
+    game.next_seq();
+    require!(game.extensions & extension.bit() == 0, PokerError::ExtensionEnabled);
+
+    game.extensions |= extension.bit();
+    if let Some(region) = extension.region(&game, &mut tail) {
+        region.fill(0);
+    }
+
+    Ok(())
+}
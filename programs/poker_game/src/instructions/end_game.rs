@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{ABANDONED_HAND_TIMEOUT, MAX_HOLE_CARDS, MAX_PLAYERS};
+use crate::engine::{cash_out_all, emit_crank, hand_id, Memo, Memos};
+use crate::errors::PokerError;
+use crate::events::{CrankReason, GameEnded};
+use crate::state::{Game, MemoConfig, TableConfig};
+
+#[derive(Accounts)]
+pub struct EndGame<'info> {
+    #[account(
+        mut,
+        constraint = !game.load()?.is_tournament_table() @ PokerError::TournamentTable,
+        constraint = game.load()?.in_hand() @ PokerError::GameNotActive
+    )]
+    pub game: AccountLoader<'info, Game>,
+    #[account(seeds = [b"table_config", game.key().as_ref()], bump = config.load()?.bump)]
+    pub config: AccountLoader<'info, TableConfig>,
+
+    /// Only the owner key can end a hand.
+    #[account(constraint = signer.key() == config.load()?.owner @ PokerError::NotAuthorized)]
+    pub signer: Signer<'info>,
+
+    /// The table's memo tag and the Memo program; required at tables that
+    /// tag withdrawals with memos.
+    #[account(seeds = [b"memo_config", game.key().as_ref()], bump = memo_config.bump)]
+    pub memo_config: Option<Account<'info, MemoConfig>>,
+    pub memo_program: Option<Program<'info, Memo>>,
+}
+
+pub(crate) fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, EndGame<'info>>) -> Result<()> {
+    let game_info = ctx.accounts.game.to_account_info();
+    let game_key = game_info.key();
+    let config = ctx.accounts.config.load()?;
+    let mut game = ctx.accounts.game.load_mut()?;
+    game.next_seq();
+
+    require!(
+        Clock::get()?.unix_timestamp > game.turn_deadline + ABANDONED_HAND_TIMEOUT,
+        PokerError::HandNotAbandoned
+    );
+    game.refund_hand()?;
+
+    // Reset hand state and send everyone home with their stack
+    game.player_hands = [[0u8; MAX_HOLE_CARDS]; MAX_PLAYERS];
+    game.community_cards = [0u8; 5];
+    game.current_turn = 0;
+    game.folded = [0; MAX_PLAYERS];
+    game.acted = [0; MAX_PLAYERS];
+
+    emit_event!(GameEnded {
+        game: game_key,
+        seq: game.state_seq,
+        hand_id: hand_id(&game_key, game.hand_number),
+        hand_number: game.hand_number,
+    });
+    let memos = Memos::new(&config, ctx.accounts.memo_config.as_deref(), ctx.accounts.memo_program.as_deref());
+    cash_out_all(&mut game, &game_info, ctx.remaining_accounts, memos)?;
+    emit_crank(&game, game_key, ctx.accounts.signer.key(), CrankReason::HandVoided);
+
+    Ok(())
+}
@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_CONSECUTIVE_TIMEOUTS;
+use crate::engine::{emit_crank, hand_id, pay_crank_reward, rate_limit, require_crank_scope, Memo, Memos};
+use crate::errors::PokerError;
+use crate::events::{CrankReason, PlayerFolded, TimeoutAction, TurnTimedOut};
+use crate::state::{CrankStep, DealerBot, Game, MemoConfig, TableConfig};
+
+#[derive(Accounts)]
+pub struct ForceTimeout<'info> {
+    #[account(mut, constraint = game.load()?.is_betting() @ PokerError::GameNotActive)]
+    pub game: AccountLoader<'info, Game>,
+    #[account(seeds = [b"table_config", game.key().as_ref()], bump = config.load()?.bump)]
+    pub config: AccountLoader<'info, TableConfig>,
+    #[account(mut)]
+    pub caller: Signer<'info>,
+    /// The caller's registration when it cranks as a dealer bot.
+    #[account(seeds = [b"dealer_bot", game.key().as_ref(), caller.key().as_ref()], bump = dealer_bot.bump)]
+    pub dealer_bot: Option<Account<'info, DealerBot>>,
+    /// The table's memo tag and the Memo program; required at tables that
+    /// tag crank rewards with memos.
+    #[account(seeds = [b"memo_config", game.key().as_ref()], bump = memo_config.bump)]
+    pub memo_config: Option<Account<'info, MemoConfig>>,
+    pub memo_program: Option<Program<'info, Memo>>,
+}
+
+pub(crate) fn handler(ctx: Context<ForceTimeout>) -> Result<()> {
+    let game_info = ctx.accounts.game.to_account_info();
+    let caller_info = ctx.accounts.caller.to_account_info();
+    let config = ctx.accounts.config.load()?;
+    let mut game = ctx.accounts.game.load_mut()?;
+    game.next_seq();
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+
+    require!(now > game.turn_deadline, PokerError::TurnNotExpired);
+    require_crank_scope(&config, &game, &caller_info.key(), ctx.accounts.dealer_bot.as_deref(), CrankStep::TimeOut)?;
+    let window = config.rules.crank_slot_window;
+    rate_limit(&mut game.last_crank_slot, window, clock.slot)?;
+
+    let memos = Memos::new(&config, ctx.accounts.memo_config.as_deref(), ctx.accounts.memo_program.as_deref());
+    time_out_turn(&mut game, &game_info, &config, &caller_info, memos, now)
+}
+
+// Body of `force_timeout` and the timeout step of `run_automation`, once the
+// turn clock is known to have run out
+pub(crate) fn time_out_turn<'info>(
+    game: &mut Game,
+    game_info: &AccountInfo<'info>,
+    config: &TableConfig,
+    caller_info: &AccountInfo<'info>,
+    memos: Memos,
+    now: i64,
+) -> Result<()> {
+    let game_key = game_info.key();
+    let seat = game.current_turn as usize;
+    let time_bank_used = game.time_banks[seat];
+    game.time_banks[seat] = 0;
+    game.turn_deadline += time_bank_used;
+
+    let action = if now <= game.turn_deadline {
+        TimeoutAction::TimeBank
+    } else if game.player_bets[seat] >= game.current_bet {
+        TimeoutAction::Check
+    } else if game.all_in_protection_available(config, seat) {
+        TimeoutAction::AllInProtection
+    } else {
+        TimeoutAction::Fold
+    };
+
+    emit_event!(TurnTimedOut {
+        game: game_key,
+        seq: game.state_seq,
+        hand_id: hand_id(&game_key, game.hand_number),
+        player: game.players[seat],
+        seat: seat as u8,
+        action,
+        time_bank_used,
+        crank: caller_info.key(),
+    });
+    let reason = match action {
+        TimeoutAction::Fold => CrankReason::ForcedFold,
+        _ => CrankReason::TurnTimeout,
+    };
+    emit_crank(game, game_key, caller_info.key(), reason);
+
+    pay_crank_reward(game, game_info, config, caller_info, memos)?;
+
+    match action {
+        TimeoutAction::TimeBank => return Ok(()),
+        TimeoutAction::Check => game.acted[seat] = 1,
+        TimeoutAction::AllInProtection => {
+            game.all_in_protected[seat] = 1;
+            game.last_protected_hand[seat] = game.hand_number;
+            game.acted[seat] = 1;
+        }
+        TimeoutAction::Fold => {
+            game.folded[seat] = 1;
+            game.players_in_round = game.players_in_round.saturating_sub(1);
+            emit_event!(PlayerFolded {
+                game: game_key,
+                seq: game.state_seq,
+                hand_id: hand_id(&game_key, game.hand_number),
+                player: game.players[seat],
+                seat: seat as u8,
+            });
+
+            // Two forced folds in a row sit the player out until they `sit_in`
+            game.consecutive_timeouts[seat] += 1;
+            if game.consecutive_timeouts[seat] >= MAX_CONSECUTIVE_TIMEOUTS {
+                game.sitting_out[seat] = 1;
+            }
+        }
+    }
+
+    if game.players_in_round > 1 {
+        let next = game.next_to_act(game.current_turn)?;
+        game.start_turn(next, now, config);
+    }
+
+    game.end_of_action()
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::errors::PokerError;
+use crate::state::Season;
+
+#[derive(Accounts)]
+pub struct FundSeason<'info> {
+    #[account(mut)]
+    pub season: Account<'info, Season>,
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<FundSeason>, amount: u64) -> Result<()> {
+    let season = &mut ctx.accounts.season;
+
+    // Prizes are fixed once the standings are
+    require!(Clock::get()?.unix_timestamp < season.ends_at, PokerError::SeasonNotActive);
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.sponsor.to_account_info(),
+                to: season.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    season.prize_pool += amount;
+
+    Ok(())
+}
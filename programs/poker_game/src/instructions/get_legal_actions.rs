@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_PLAYERS;
+use crate::errors::PokerError;
+use crate::state::{Game, LegalActions};
+
+#[derive(Accounts)]
+pub struct ViewGame<'info> {
+    pub game: AccountLoader<'info, Game>,
+}
+
+pub(crate) fn handler(ctx: Context<ViewGame>, seat: u8) -> Result<LegalActions> {
+    require!((seat as usize) < MAX_PLAYERS, PokerError::SeatEmpty);
+    Ok(ctx.accounts.game.load()?.legal_actions(seat as usize))
+}
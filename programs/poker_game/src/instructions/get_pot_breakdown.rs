@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+use crate::instructions::ViewGame;
+use crate::state::PotBreakdown;
+
+pub(crate) fn handler(ctx: Context<ViewGame>) -> Result<PotBreakdown> {
+    let game = ctx.accounts.game.load()?;
+    Ok(PotBreakdown {
+        pot: game.pot,
+        current_bet: game.current_bet,
+        crank_rewards_paid: game.crank_rewards_paid,
+        street_bets: game.player_bets,
+        contributions: game.contributions,
+    })
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_PLAYERS;
+use crate::state::{Game, SeatSnapshot, TableConfig, TableSnapshot};
+
+#[derive(Accounts)]
+pub struct ViewTable<'info> {
+    pub game: AccountLoader<'info, Game>,
+    #[account(seeds = [b"table_config", game.key().as_ref()], bump = config.load()?.bump)]
+    pub config: AccountLoader<'info, TableConfig>,
+}
+
+pub(crate) fn handler(ctx: Context<ViewTable>) -> Result<TableSnapshot> {
+    let game = ctx.accounts.game.load()?;
+    let config = ctx.accounts.config.load()?;
+
+    let mut seats = [SeatSnapshot::default(); MAX_PLAYERS];
+    for (i, seat) in seats.iter_mut().enumerate() {
+        *seat = SeatSnapshot {
+            player: game.players[i],
+            stack: game.stacks[i],
+            street_bet: game.player_bets[i],
+            contribution: game.contributions[i],
+            time_bank: game.time_banks[i],
+            folded: game.folded[i] != 0,
+            sitting_out: game.sitting_out[i] != 0,
+            all_in_protected: game.all_in_protected[i] != 0,
+        };
+    }
+
+    Ok(TableSnapshot {
+        state_seq: game.state_seq,
+        hand_number: game.hand_number,
+        phase: game.phase(),
+        variant: game.variant(),
+        halted: game.halted != 0,
+        small_blind: game.small_blind,
+        big_blind: game.big_blind,
+        ante: game.ante,
+        pot: game.pot,
+        current_bet: game.current_bet,
+        current_turn: game.current_turn,
+        turn_deadline: game.turn_deadline,
+        seats,
+        legal_actions: game.legal_actions(game.current_turn as usize),
+        owner: config.owner,
+        operator: config.operator,
+        gatekeeper: config.gatekeeper,
+        turn_timeout: config.turn_timeout,
+        time_bank: config.time_bank,
+        hand_delay: config.hand_delay,
+        dispute_window: config.dispute_window,
+        auto_deal: config.auto_deal != 0,
+        min_players: config.min_players,
+        action_slot_window: config.rules.action_slot_window,
+        crank_slot_window: config.rules.crank_slot_window,
+        blind_double_interval: config.rules.blind_double_interval,
+        crank_reward: config.rules.crank_reward,
+        crank_reward_cap: config.rules.crank_reward_cap,
+        all_in_protection_hands: config.rules.all_in_protection_hands,
+    })
+}
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::engine::read_sol_price;
+use crate::errors::PokerError;
+use crate::state::{Game, PriceGate, UsdStakes};
+
+#[derive(Accounts)]
+pub struct ViewUsdStakes<'info> {
+    pub game: AccountLoader<'info, Game>,
+    #[account(seeds = [b"price_gate", game.key().as_ref()], bump = price_gate.bump)]
+    pub price_gate: Account<'info, PriceGate>,
+    /// CHECK: must be the gate's feed; parsed by `read_sol_price`.
+    #[account(address = price_gate.feed @ PokerError::InvalidPriceFeed)]
+    pub feed: UncheckedAccount<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<ViewUsdStakes>) -> Result<UsdStakes> {
+    let game = ctx.accounts.game.load()?;
+    let gate = &ctx.accounts.price_gate;
+    let price = read_sol_price(&ctx.accounts.feed, gate.max_age, Clock::get()?.unix_timestamp)?;
+
+    let lamports = |cents: u64| if cents == 0 { 0 } else { price.lamports(cents) };
+    Ok(UsdStakes {
+        small_blind_usd: price.cents(game.small_blind),
+        big_blind_usd: price.cents(game.big_blind),
+        min_buy_in: lamports(gate.min_buy_in_usd),
+        max_buy_in: lamports(gate.max_buy_in_usd),
+        publish_time: price.publish_time,
+    })
+}
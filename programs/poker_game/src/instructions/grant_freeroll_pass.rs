@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PokerError;
+use crate::state::{FreerollPass, Tournament};
+
+#[derive(Accounts)]
+#[instruction(player: Pubkey)]
+pub struct GrantFreerollPass<'info> {
+    #[account(has_one = authority @ PokerError::NotAuthorized)]
+    pub tournament: Account<'info, Tournament>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + FreerollPass::LEN,
+        seeds = [b"freeroll_pass", tournament.key().as_ref(), player.as_ref()],
+        bump,
+    )]
+    pub pass: Account<'info, FreerollPass>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<GrantFreerollPass>, player: Pubkey) -> Result<()> {
+    let pass = &mut ctx.accounts.pass;
+    pass.tournament = ctx.accounts.tournament.key();
+    pass.player = player;
+    pass.bump = ctx.bumps.pass;
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PokerError;
+use crate::program::PokerGame;
+use crate::state::GlobalConfig;
+
+#[derive(Accounts)]
+pub struct InitGlobalConfig<'info> {
+    #[account(init, payer = authority, space = 8 + GlobalConfig::LEN, seeds = [b"global_config"], bump)]
+    pub global_config: Account<'info, GlobalConfig>,
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()) @ PokerError::NotAuthorized)]
+    pub program: Program<'info, PokerGame>,
+    #[account(constraint = program_data.upgrade_authority_address == Some(authority.key()) @ PokerError::NotAuthorized)]
+    pub program_data: Account<'info, ProgramData>,
+    /// The program's upgrade authority, which governs the config from here on.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<InitGlobalConfig>) -> Result<()> {
+    let config = &mut ctx.accounts.global_config;
+    config.authority = ctx.accounts.authority.key();
+    config.staking_programs = Vec::new();
+    config.bump = ctx.bumps.global_config;
+
+    Ok(())
+}
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{GlobalStats, ProtocolCounters};
+
+#[derive(Accounts)]
+pub struct InitGlobalStats<'info> {
+    #[account(init, payer = payer, space = 8 + GlobalStats::LEN, seeds = [b"global_stats"], bump)]
+    pub global_stats: Account<'info, GlobalStats>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<InitGlobalStats>) -> Result<()> {
+    let stats = &mut ctx.accounts.global_stats;
+    stats.totals = ProtocolCounters::default();
+    stats.merged_at = 0;
+    stats.bump = ctx.bumps.global_stats;
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::HAND_ARCHIVE_DEPTH;
+use crate::state::{Game, HandArchive};
+
+#[derive(Accounts)]
+pub struct InitHandArchive<'info> {
+    pub game: AccountLoader<'info, Game>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + HandArchive::LEN,
+        seeds = [b"hand_archive", game.key().as_ref()],
+        bump,
+    )]
+    pub archive: Account<'info, HandArchive>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<InitHandArchive>) -> Result<()> {
+    let archive = &mut ctx.accounts.archive;
+    archive.game = ctx.accounts.game.key();
+    archive.root = HandArchive::empty_root();
+    archive.next_index = 0;
+    archive.frontier = [[0u8; 32]; HAND_ARCHIVE_DEPTH];
+    archive.bump = ctx.bumps.archive;
+
+    Ok(())
+}
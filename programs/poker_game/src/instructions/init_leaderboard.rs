@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::LEADERBOARD_SIZE;
+use crate::state::{Leaderboard, LeaderboardEntry};
+
+#[derive(Accounts)]
+pub struct InitLeaderboard<'info> {
+    #[account(init, payer = authority, space = 8 + Leaderboard::LEN, seeds = [b"leaderboard"], bump)]
+    pub leaderboard: Account<'info, Leaderboard>,
+    /// Pays for the board and starts its seasons.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<InitLeaderboard>) -> Result<()> {
+    let board = &mut ctx.accounts.leaderboard;
+    board.scope = Pubkey::default();
+    board.authority = ctx.accounts.authority.key();
+    board.season = 1;
+    board.season_started_at = Clock::get()?.unix_timestamp;
+    board.by_winnings = [LeaderboardEntry::default(); LEADERBOARD_SIZE];
+    board.by_hands_won = [LeaderboardEntry::default(); LEADERBOARD_SIZE];
+    board.bump = ctx.bumps.leaderboard;
+
+    Ok(())
+}
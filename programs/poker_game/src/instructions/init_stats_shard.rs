@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::STATS_SHARDS;
+use crate::errors::PokerError;
+use crate::state::{ProtocolCounters, StatsShard};
+
+#[derive(Accounts)]
+#[instruction(index: u8)]
+pub struct InitStatsShard<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + StatsShard::LEN,
+        seeds = [b"stats_shard".as_ref(), &[index]],
+        bump,
+    )]
+    pub shard: Account<'info, StatsShard>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<InitStatsShard>, index: u8) -> Result<()> {
+    require!(index < STATS_SHARDS, PokerError::InvalidStatsShard);
+
+    let shard = &mut ctx.accounts.shard;
+    shard.index = index;
+    shard.pending = ProtocolCounters::default();
+    shard.bump = ctx.bumps.shard;
+
+    Ok(())
+}
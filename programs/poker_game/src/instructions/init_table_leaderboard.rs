@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::LEADERBOARD_SIZE;
+use crate::state::{Game, Leaderboard, LeaderboardEntry};
+
+#[derive(Accounts)]
+pub struct InitTableLeaderboard<'info> {
+    pub game: AccountLoader<'info, Game>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Leaderboard::LEN,
+        seeds = [b"leaderboard", game.key().as_ref()],
+        bump,
+    )]
+    pub leaderboard: Account<'info, Leaderboard>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<InitTableLeaderboard>) -> Result<()> {
+    let board = &mut ctx.accounts.leaderboard;
+    board.scope = ctx.accounts.game.key();
+    board.authority = Pubkey::default();
+    board.season = 1;
+    board.season_started_at = Clock::get()?.unix_timestamp;
+    board.by_winnings = [LeaderboardEntry::default(); LEADERBOARD_SIZE];
+    board.by_hands_won = [LeaderboardEntry::default(); LEADERBOARD_SIZE];
+    board.bump = ctx.bumps.leaderboard;
+
+    Ok(())
+}
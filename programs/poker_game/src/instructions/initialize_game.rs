@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{MAX_HOLE_CARDS, MAX_PLAYERS};
+use crate::engine::stats_shard_for;
+use crate::errors::PokerError;
+use crate::state::{Game, GameVariant, StatsShard, TableConfig, TableStats};
+
+#[derive(Accounts)]
+pub struct InitializeGame<'info> {
+    #[account(init, payer = user, space = 8 + Game::LEN)]
+    pub game: AccountLoader<'info, Game>,
+    #[account(
+        init,
+        payer = user,
+        space = 8 + TableConfig::LEN,
+        seeds = [b"table_config", game.key().as_ref()],
+        bump,
+    )]
+    pub config: AccountLoader<'info, TableConfig>,
+    /// Counts the table toward protocol stats when passed.
+    #[account(mut, seeds = [b"stats_shard".as_ref(), &[stats_shard_for(&game.key())]], bump = stats_shard.bump)]
+    pub stats_shard: Option<Account<'info, StatsShard>>,
+    #[account(mut)]
+    pub user: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(
+    ctx: Context<InitializeGame>,
+    small_blind: u64,
+    big_blind: u64,
+    variant: GameVariant,
+) -> Result<()> {
+    require!(small_blind > 0, PokerError::ZeroBlind);
+    require!(big_blind >= small_blind, PokerError::BigBlindTooSmall);
+
+    if let Some(shard) = ctx.accounts.stats_shard.as_mut() {
+        shard.pending.games += 1;
+    }
+
+    let game_key = ctx.accounts.game.key();
+    ctx.accounts.config.load_init()?.init(
+        game_key,
+        ctx.accounts.user.key(),
+        small_blind,
+        big_blind,
+        ctx.bumps.config,
+    );
+
+    let mut game = ctx.accounts.game.load_init()?;
+
+    game.players = [Pubkey::default(); MAX_PLAYERS];
+    game.player_hands = [[0u8; MAX_HOLE_CARDS]; MAX_PLAYERS];
+    game.community_cards = [0u8; 5];
+    game.pot = 0;
+    game.small_blind = small_blind;
+    game.big_blind = big_blind;
+    game.current_bet = 0;
+    game.current_turn = 0;
+    game.phase = 0;
+    game.betting_street = 0;
+    game.folded = [0; MAX_PLAYERS];
+    game.player_bets = [0; MAX_PLAYERS];
+    game.players_in_round = 0;
+    game.tournament = Pubkey::default();
+    game.stacks = [0; MAX_PLAYERS];
+    game.ante = 0;
+    game.variant = variant as u8;
+    game.deck_seed = 0;
+    game.deck_index = 0;
+    game.acted = [0; MAX_PLAYERS];
+    game.rotation_index = 0;
+    game.hands_in_variant = 0;
+    game.last_winner = Pubkey::default();
+    game.turn_deadline = 0;
+    game.time_banks = [0; MAX_PLAYERS];
+    game.hands_since_refill = 0;
+    game.hand_ended_at = 0;
+    game.contributions = [0; MAX_PLAYERS];
+    game.last_action_slot = [0; MAX_PLAYERS];
+    game.last_client_nonce = [0; MAX_PLAYERS];
+    game.last_crank_slot = 0;
+    game.hand_number = 0;
+    game.all_in_protected = [0; MAX_PLAYERS];
+    game.last_protected_hand = [0; MAX_PLAYERS];
+    game.last_active_epoch = Clock::get()?.epoch;
+    game.crank_rewards_paid = 0;
+    game.consecutive_timeouts = [0; MAX_PLAYERS];
+    game.sitting_out = [0; MAX_PLAYERS];
+    game.settled_hand = 0;
+    game.action_nonce = 0;
+    game.halted = 0;
+    game.state_seq = 0;
+    game.hand_started_at = 0;
+    game.stats = TableStats::default();
+    game.showdown_at = 0;
+    game.disputed = 0;
+    game.extensions = 0;
+    game.bump = 0;
+    game.dealt_in = 0;
+    game.vpip = 0;
+    game.pfr = 0;
+    game.escrow_seats = 0;
+    game.gated_top_ups = 0;
+    game.reserved = [0; 57];
+
+    Ok(())
+}
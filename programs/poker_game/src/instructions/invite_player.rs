@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PokerError;
+use crate::events::PlayerInvited;
+use crate::state::{Game, Invitation, PlayerProfile, TableConfig};
+
+#[derive(Accounts)]
+#[instruction(invitee: Pubkey)]
+pub struct InvitePlayer<'info> {
+    pub game: AccountLoader<'info, Game>,
+    #[account(seeds = [b"table_config", game.key().as_ref()], bump = config.load()?.bump)]
+    pub config: AccountLoader<'info, TableConfig>,
+    #[account(
+        init,
+        payer = inviter,
+        space = 8 + Invitation::LEN,
+        seeds = [b"invitation", game.key().as_ref(), invitee.as_ref()],
+        bump,
+    )]
+    pub invitation: Account<'info, Invitation>,
+    /// The table's host, or a seated player inviting one of their friends.
+    #[account(mut)]
+    pub inviter: Signer<'info>,
+    /// Required when the inviter is not the host.
+    #[account(seeds = [b"profile", inviter.key().as_ref()], bump = inviter_profile.bump)]
+    pub inviter_profile: Option<Account<'info, PlayerProfile>>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<InvitePlayer>, invitee: Pubkey) -> Result<()> {
+    let inviter = ctx.accounts.inviter.key();
+    let allowed = ctx.accounts.config.load()?.is_host(&inviter)
+        || (ctx.accounts.game.load()?.players.contains(&inviter)
+            && ctx
+                .accounts
+                .inviter_profile
+                .as_ref()
+                .is_some_and(|profile| profile.friends.contains(&invitee)));
+    require!(allowed, PokerError::NotAuthorized);
+
+    let invitation = &mut ctx.accounts.invitation;
+    invitation.game = ctx.accounts.game.key();
+    invitation.inviter = inviter;
+    invitation.invitee = invitee;
+    invitation.created_at = Clock::get()?.unix_timestamp;
+    invitation.accepted = false;
+    invitation.bump = ctx.bumps.invitation;
+
+    emit_event!(PlayerInvited {
+        game: invitation.game,
+        inviter,
+        invitee,
+    });
+
+    Ok(())
+}
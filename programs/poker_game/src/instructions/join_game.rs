@@ -0,0 +1,186 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar;
+use anchor_lang::system_program;
+
+use crate::constants::MAX_PLAYERS;
+use crate::engine::{charge_buy_in, check_attestation, check_usd_buy_in, hand_id, load_profile, require_own_funds, write_memo, Memo, Memos};
+use crate::errors::PokerError;
+use crate::events::PlayerJoined;
+use crate::state::{Club, FundMovement, Game, Invitation, MemoConfig, PriceGate, Seat, TableConfig};
+
+#[derive(Accounts)]
+pub struct JoinGame<'info> {
+    /// Tournament seats are assigned by the coordinator.
+    #[account(mut, constraint = !game.load()?.is_tournament_table() @ PokerError::TournamentTable)]
+    pub game: AccountLoader<'info, Game>,
+    #[account(seeds = [b"table_config", game.key().as_ref()], bump = config.load()?.bump)]
+    pub config: AccountLoader<'info, TableConfig>,
+    #[account(mut, constraint = !game.load()?.players.contains(&player.key()) @ PokerError::AlreadySeated)]
+    pub player: Signer<'info>,
+    #[account(
+        init,
+        payer = player,
+        space = 8 + Seat::LEN,
+        seeds = [b"seat", game.key().as_ref(), player.key().as_ref()],
+        bump,
+    )]
+    pub seat: Account<'info, Seat>,
+    /// CHECK: owner and contents are validated against `config.gatekeeper`.
+    /// Only required when the table has a gatekeeper.
+    pub attestation: Option<UncheckedAccount<'info>>,
+    /// An accepted invitation to this table, in place of the attestation.
+    #[account(seeds = [b"invitation", game.key().as_ref(), player.key().as_ref()], bump = invitation.bump)]
+    pub invitation: Option<Account<'info, Invitation>>,
+    /// CHECK: the player's profile PDA, read only if it has been created.
+    /// Its limits are enforced, its rating checked at rating-gated tables
+    /// and its display name put in `PlayerJoined`.
+    #[account(mut, seeds = [b"profile", player.key().as_ref()], bump)]
+    pub profile: UncheckedAccount<'info>,
+    /// The table's club; required at club tables.
+    #[account(constraint = club.key() == config.load()?.club @ PokerError::NotClubMember)]
+    pub club: Option<Account<'info, Club>>,
+    pub system_program: Program<'info, System>,
+}
+
+/// `join_game`'s accounts, then the ones it gained after its account list
+/// was frozen for CPI.
+#[derive(Accounts)]
+pub struct JoinGameV2<'info> {
+    pub join: JoinGame<'info>,
+    /// CHECK: the instructions sysvar; required at tables that take only
+    /// the player's own funds.
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+    /// The table's USD buy-in range and its SOL/USD feed; required while the
+    /// range is on.
+    #[account(seeds = [b"price_gate", join.game.key().as_ref()], bump = price_gate.bump)]
+    pub price_gate: Option<Account<'info, PriceGate>>,
+    /// CHECK: must be the gate's feed; checked by `check_usd_buy_in`.
+    pub price_feed: Option<UncheckedAccount<'info>>,
+    /// The table's memo tag and the Memo program; required at tables that
+    /// tag deposits with memos.
+    #[account(seeds = [b"memo_config", join.game.key().as_ref()], bump = memo_config.bump)]
+    pub memo_config: Option<Account<'info, MemoConfig>>,
+    pub memo_program: Option<Program<'info, Memo>>,
+}
+
+// Accounts only `join_game_v2` takes. `join_game` runs without them, so
+// tables whose settings need them turn it away, and it tags no memos.
+struct JoinExtras<'a, 'info> {
+    instructions_sysvar: Option<&'a AccountInfo<'info>>,
+    price_gate: Option<&'a PriceGate>,
+    price_feed: Option<&'a AccountInfo<'info>>,
+    memos: Memos<'a, 'info>,
+}
+
+pub(crate) fn handler(ctx: Context<JoinGame>, deposit: u64) -> Result<()> {
+    let extras = JoinExtras { instructions_sysvar: None, price_gate: None, price_feed: None, memos: Memos::none() };
+    join(ctx.accounts, ctx.bumps.seat, deposit, extras)
+}
+
+pub(crate) fn handler_v2(ctx: Context<JoinGameV2>, deposit: u64) -> Result<()> {
+    let accounts = ctx.accounts;
+    let memos = Memos::new(&*accounts.join.config.load()?, accounts.memo_config.as_deref(), accounts.memo_program.as_deref());
+    let extras = JoinExtras {
+        instructions_sysvar: accounts.instructions_sysvar.as_deref(),
+        price_gate: accounts.price_gate.as_deref(),
+        price_feed: accounts.price_feed.as_deref(),
+        memos,
+    };
+    join(&mut accounts.join, ctx.bumps.join.seat, deposit, extras)
+}
+
+fn join(accounts: &mut JoinGame, seat_bump: u8, deposit: u64, extras: JoinExtras) -> Result<()> {
+    let game_key = accounts.game.key();
+    let config = accounts.config.load()?;
+    let mut game = accounts.game.load_mut()?;
+    game.next_seq();
+    let player = &accounts.player;
+    let mut profile = load_profile(&accounts.profile)?;
+    charge_buy_in(&accounts.profile, profile.as_mut(), deposit, Clock::get()?.unix_timestamp)?;
+
+    // Permissioned tables only seat wallets holding a live pass or an invitation
+    let invited = accounts.invitation.as_ref().is_some_and(|invitation| invitation.accepted);
+    if config.gatekeeper != Pubkey::default() && !invited {
+        let attestation = accounts
+            .attestation
+            .as_ref()
+            .ok_or(PokerError::InvalidAttestation)?;
+        check_attestation(attestation, &config.gatekeeper, &player.key(), Clock::get()?.unix_timestamp)?;
+    }
+
+    require_own_funds(&config, extras.instructions_sysvar)?;
+    check_usd_buy_in(
+        &config,
+        extras.price_gate,
+        extras.price_feed,
+        deposit,
+        false,
+        Clock::get()?.unix_timestamp,
+    )?;
+
+    // Club tables only seat the club's members
+    if config.club != Pubkey::default() {
+        let club = accounts.club.as_ref().ok_or(PokerError::NotClubMember)?;
+        require!(club.admits(&player.key()), PokerError::NotClubMember);
+    }
+
+    // Rating-gated tables only seat profiles within their range
+    if config.is_rating_gated() {
+        let profile = profile.as_ref().ok_or(PokerError::RatingOutOfRange)?;
+        require!(config.admits_rating(profile.rating), PokerError::RatingOutOfRange);
+    }
+
+    // Prevent joining a full game
+    let mut joined = false;
+
+    for i in 0..MAX_PLAYERS {
+        if game.players[i] == Pubkey::default() {
+            game.players[i] = player.key();
+            game.stacks[i] = deposit;
+            let seat = &mut accounts.seat;
+            seat.game = game_key;
+            seat.owner = player.key();
+            seat.index = i as u8;
+            seat.bump = seat_bump;
+            game.time_banks[i] = config.time_bank;
+            game.last_client_nonce[i] = 0;
+            game.consecutive_timeouts[i] = 0;
+            game.sitting_out[i] = 0;
+            game.escrow_seats &= !(1 << i);
+            joined = true;
+            game.players_in_round += 1;
+            break;
+        }
+    }
+
+    require!(joined, PokerError::GameFull);
+    game.last_active_epoch = Clock::get()?.epoch;
+
+    emit_event!(PlayerJoined {
+        game: game_key,
+        seq: game.state_seq,
+        hand_id: hand_id(&game_key, game.hand_number),
+        player: player.key(),
+        seat: accounts.seat.index,
+        deposit,
+        display_name: profile.map(|profile| profile.display_name).unwrap_or_default(),
+    });
+
+    // The deposit becomes the seat's stack; the lamports sit in the game account
+    if deposit > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: player.to_account_info(),
+                    to: accounts.game.to_account_info(),
+                },
+            ),
+            deposit,
+        )?;
+    }
+    write_memo(extras.memos, FundMovement::Deposit, &player.key(), deposit)?;
+
+    Ok(())
+}
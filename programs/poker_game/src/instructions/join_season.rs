@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PokerError;
+use crate::state::{Season, SeasonEntry};
+
+#[derive(Accounts)]
+pub struct JoinSeason<'info> {
+    pub season: Account<'info, Season>,
+    #[account(
+        init,
+        payer = player,
+        space = 8 + SeasonEntry::LEN,
+        seeds = [b"season_entry", season.key().as_ref(), player.key().as_ref()],
+        bump,
+    )]
+    pub entry: Account<'info, SeasonEntry>,
+    #[account(mut)]
+    pub player: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<JoinSeason>) -> Result<()> {
+    require!(Clock::get()?.unix_timestamp < ctx.accounts.season.ends_at, PokerError::SeasonNotActive);
+
+    let entry = &mut ctx.accounts.entry;
+    entry.season = ctx.accounts.season.key();
+    entry.player = ctx.accounts.player.key();
+    entry.points = 0;
+    entry.tournaments_scored = 0;
+    entry.prize_claimed = false;
+    entry.bump = ctx.bumps.entry;
+
+    Ok(())
+}
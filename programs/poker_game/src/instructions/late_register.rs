@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_ENTRANTS;
+use crate::engine::{collect_buy_in, seat_tournament_player, tournament_blind_level};
+use crate::errors::PokerError;
+use crate::state::{BlindStructure, Game, TableConfig, Tournament, TournamentStatus};
+
+#[derive(Accounts)]
+pub struct LateRegister<'info> {
+    #[account(mut)]
+    pub tournament: Account<'info, Tournament>,
+    #[account(mut, constraint = table.load()?.tournament == tournament.key() @ PokerError::TableMismatch)]
+    pub table: AccountLoader<'info, Game>,
+    #[account(seeds = [b"table_config", table.key().as_ref()], bump = config.load()?.bump)]
+    pub config: AccountLoader<'info, TableConfig>,
+    /// Required when the tournament uses a blind structure.
+    pub blind_structure: Option<Account<'info, BlindStructure>>,
+    #[account(mut)]
+    pub player: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<LateRegister>) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+    let config = ctx.accounts.config.load()?;
+    let mut table = ctx.accounts.table.load_mut()?;
+    table.next_seq();
+    let player = &ctx.accounts.player;
+
+    require!(
+        tournament.status == TournamentStatus::Running,
+        PokerError::TournamentNotRunning
+    );
+
+    let (level, _) = tournament_blind_level(
+        tournament,
+        ctx.accounts.blind_structure.as_ref(),
+        Clock::get()?.unix_timestamp,
+    )?
+    .unwrap_or_default();
+    require!(
+        level < tournament.late_registration_levels as usize,
+        PokerError::RegistrationClosed
+    );
+
+    let count = tournament.entrant_count as usize;
+    require!(count < MAX_ENTRANTS, PokerError::TournamentFull);
+    require!(
+        tournament.entrant_index(&player.key()).is_none(),
+        PokerError::AlreadyRegistered
+    );
+    require!(!table.in_hand(), PokerError::TableInHand);
+
+    collect_buy_in(tournament, player, &ctx.accounts.system_program)?;
+    seat_tournament_player(&mut table, &config, player.key(), tournament.starting_stack)?;
+
+    tournament.entrants[count] = player.key();
+    tournament.entries[count] = 1;
+    tournament.bounties[count] = tournament.bounty;
+    tournament.entrant_count += 1;
+    tournament.players_remaining += 1;
+    tournament.refresh_hand_for_hand();
+
+    Ok(())
+}
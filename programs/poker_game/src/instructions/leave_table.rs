@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+use crate::engine::{cash_out, Memo, Memos};
+use crate::errors::PokerError;
+use crate::state::{Game, MemoConfig, Seat, TableConfig};
+
+#[derive(Accounts)]
+pub struct LeaveTable<'info> {
+    /// Stacks only change between hands, and tournament chips are never
+    /// cashed out.
+    #[account(
+        mut,
+        constraint = game.load()?.halted == 0 @ PokerError::TableHalted,
+        constraint = !game.load()?.in_hand() @ PokerError::TableInHand,
+        constraint = !game.load()?.is_tournament_table() @ PokerError::TournamentTable,
+    )]
+    pub game: AccountLoader<'info, Game>,
+    #[account(seeds = [b"table_config", game.key().as_ref()], bump = config.load()?.bump)]
+    pub config: AccountLoader<'info, TableConfig>,
+    #[account(mut)]
+    pub player: Signer<'info>,
+    /// Closed, refunding its rent to the player.
+    #[account(
+        mut,
+        close = player,
+        seeds = [b"seat", game.key().as_ref(), player.key().as_ref()],
+        bump = seat.bump,
+        has_one = game @ PokerError::SeatMismatch,
+        constraint = seat.owner == player.key() @ PokerError::SeatMismatch,
+        constraint = game.load()?.players.get(seat.index as usize) == Some(&player.key()) @ PokerError::SeatMismatch,
+    )]
+    pub seat: Account<'info, Seat>,
+}
+
+/// `leave_table`'s accounts, then the ones it gained after its account list
+/// was frozen for CPI.
+#[derive(Accounts)]
+pub struct LeaveTableV2<'info> {
+    pub leave: LeaveTable<'info>,
+    /// The table's memo tag and the Memo program; required at tables that
+    /// tag withdrawals with memos.
+    #[account(seeds = [b"memo_config", leave.game.key().as_ref()], bump = memo_config.bump)]
+    pub memo_config: Option<Account<'info, MemoConfig>>,
+    pub memo_program: Option<Program<'info, Memo>>,
+}
+
+pub(crate) fn handler(ctx: Context<LeaveTable>) -> Result<()> {
+    leave(ctx.accounts, Memos::none())
+}
+
+pub(crate) fn handler_v2(ctx: Context<LeaveTableV2>) -> Result<()> {
+    let accounts = ctx.accounts;
+    let memos = Memos::new(&*accounts.leave.config.load()?, accounts.memo_config.as_deref(), accounts.memo_program.as_deref());
+    leave(&mut accounts.leave, memos)
+}
+
+fn leave(accounts: &mut LeaveTable, memos: Memos) -> Result<()> {
+    let game_info = accounts.game.to_account_info();
+    let mut game = accounts.game.load_mut()?;
+    game.next_seq();
+
+    cash_out(
+        &mut game,
+        &game_info,
+        accounts.seat.index as usize,
+        &accounts.player.to_account_info(),
+        memos,
+    )?;
+    game.last_active_epoch = Clock::get()?.epoch;
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PokerError;
+use crate::instructions::{bet, call, fold, PlayerAction};
+use crate::state::{ActionTicketV1, LegacyEntrypoint};
+
+// The `_v1` betting entrypoints: the same actions, for clients whose tickets
+// carry no client nonce
+
+pub(crate) fn bet_v1(ctx: Context<PlayerAction>, amount: u64, ticket: ActionTicketV1) -> Result<()> {
+    require_supported(&ctx)?;
+    bet::handler(ctx, amount, ticket.into())
+}
+
+pub(crate) fn call_v1(ctx: Context<PlayerAction>, ticket: ActionTicketV1) -> Result<()> {
+    require_supported(&ctx)?;
+    call::handler(ctx, ticket.into())
+}
+
+pub(crate) fn fold_v1(ctx: Context<PlayerAction>, ticket: ActionTicketV1) -> Result<()> {
+    require_supported(&ctx)?;
+    fold::handler(ctx, ticket.into())
+}
+
+fn require_supported(ctx: &Context<PlayerAction>) -> Result<()> {
+    require!(
+        !ctx.accounts.config.load()?.is_deprecated(LegacyEntrypoint::ActionsV1),
+        PokerError::InstructionDeprecated
+    );
+    Ok(())
+}
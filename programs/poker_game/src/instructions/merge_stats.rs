@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{GlobalStats, ProtocolCounters, StatsShard};
+
+#[derive(Accounts)]
+pub struct MergeStats<'info> {
+    #[account(mut, seeds = [b"global_stats"], bump = global_stats.bump)]
+    pub global_stats: Account<'info, GlobalStats>,
+    #[account(mut, seeds = [b"stats_shard".as_ref(), &[shard.index]], bump = shard.bump)]
+    pub shard: Account<'info, StatsShard>,
+}
+
+pub(crate) fn handler(ctx: Context<MergeStats>) -> Result<()> {
+    let shard = &mut ctx.accounts.shard;
+    let stats = &mut ctx.accounts.global_stats;
+
+    stats.totals.games += shard.pending.games;
+    stats.totals.hands += shard.pending.hands;
+    stats.totals.volume += shard.pending.volume;
+    stats.totals.rake += shard.pending.rake;
+    stats.merged_at = Clock::get()?.unix_timestamp;
+    shard.pending = ProtocolCounters::default();
+
+    Ok(())
+}
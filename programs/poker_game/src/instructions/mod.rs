@@ -0,0 +1,215 @@
+pub mod accept_deal;
+pub mod accept_invitation;
+pub mod add_chips;
+pub mod add_club_member;
+pub mod add_friend;
+pub mod add_season_venue;
+pub mod add_tournament_table;
+pub mod advance_game;
+pub mod advance_shootout_winner;
+pub mod archive_hand_history;
+pub mod assert_invariants;
+pub mod authorize_session;
+pub mod balance_tables;
+pub mod bet;
+pub mod break_table;
+pub mod call;
+pub mod cancel_tournament;
+pub mod claim_achievement;
+pub mod claim_bounty;
+pub mod claim_refund;
+pub mod claim_season_prize;
+pub mod claim_seat;
+pub mod claim_ticket;
+pub mod close_hand_history;
+pub mod close_profile;
+pub mod close_stale_table;
+pub mod configure_auto_deal;
+pub mod configure_bounty;
+pub mod configure_disputes;
+pub mod configure_freeroll;
+pub mod configure_gatekeeper;
+pub mod configure_late_registration;
+pub mod configure_memos;
+pub mod configure_own_funds;
+pub mod configure_payouts;
+pub mod configure_rating_gate;
+pub mod configure_reentry;
+pub mod configure_rotation;
+pub mod configure_satellite;
+pub mod configure_schedule;
+pub mod configure_shootout;
+pub mod configure_staking_allowlist;
+pub mod configure_table_rules;
+pub mod configure_time_bank;
+pub mod configure_turn_timer;
+pub mod configure_usd_buy_in;
+pub mod create_blind_structure;
+pub mod create_club;
+pub mod create_note;
+pub mod create_profile;
+pub mod create_season;
+pub mod create_shootout_table;
+pub mod create_tournament;
+pub mod deal_street;
+pub mod delete_note;
+pub mod deprecate_entrypoint;
+pub mod decline_deal;
+pub mod dispute_hand;
+pub mod dry_run_action;
+pub mod eliminate_player;
+pub mod enable_extension;
+pub mod end_game;
+pub mod fold;
+pub mod force_timeout;
+pub mod fund_season;
+pub mod get_legal_actions;
+pub mod get_pot_breakdown;
+pub mod get_table_snapshot;
+pub mod get_usd_stakes;
+pub mod grant_freeroll_pass;
+pub mod init_global_config;
+pub mod init_global_stats;
+pub mod init_hand_archive;
+pub mod init_leaderboard;
+pub mod init_stats_shard;
+pub mod init_table_leaderboard;
+pub mod initialize_game;
+pub mod invite_player;
+pub mod join_game;
+pub mod join_season;
+pub mod late_register;
+pub mod leave_table;
+pub mod legacy_actions;
+pub mod merge_stats;
+pub mod open_hand_history;
+pub mod propose_deal;
+pub mod re_enter;
+pub mod register_dealer_bot;
+pub mod register_freeroll;
+pub mod register_spectator;
+pub mod register_tournament;
+pub mod remove_club_member;
+pub mod remove_friend;
+pub mod rename_profile;
+pub mod reset_leaderboard;
+pub mod resolve_dispute;
+pub mod resume_table;
+pub mod reveal_winner;
+pub mod revoke_dealer_bot;
+pub mod run_automation;
+pub mod score_tournament_finish;
+pub mod seat_draw;
+pub mod send_emote;
+pub mod set_operator;
+pub mod set_payout_route;
+pub mod set_player_limits;
+pub mod set_table_club;
+pub mod settle_tournament;
+pub mod sit_in;
+pub mod sponsor_tournament;
+pub mod start_round;
+pub mod start_tournament;
+pub mod table_hand_finished;
+pub mod tip_player;
+pub mod transfer_ownership;
+pub mod try_advance;
+pub mod verify_archived_hand;
+pub mod void_hand;
+pub mod write_note;
+
+pub use accept_deal::*;
+pub use accept_invitation::*;
+pub use add_chips::*;
+pub use add_club_member::*;
+pub use add_friend::*;
+pub use add_season_venue::*;
+pub use add_tournament_table::*;
+pub use advance_game::*;
+pub use advance_shootout_winner::*;
+pub use archive_hand_history::*;
+pub use assert_invariants::*;
+pub use authorize_session::*;
+pub use balance_tables::*;
+pub use bet::*;
+pub use break_table::*;
+pub use claim_achievement::*;
+pub use claim_bounty::*;
+pub use claim_refund::*;
+pub use claim_season_prize::*;
+pub use claim_seat::*;
+pub use claim_ticket::*;
+pub use close_hand_history::*;
+pub use close_profile::*;
+pub use close_stale_table::*;
+pub use configure_auto_deal::*;
+pub use configure_disputes::*;
+pub use configure_freeroll::*;
+pub use configure_memos::*;
+pub use configure_satellite::*;
+pub use configure_staking_allowlist::*;
+pub use configure_time_bank::*;
+pub use configure_usd_buy_in::*;
+pub use create_blind_structure::*;
+pub use create_club::*;
+pub use create_note::*;
+pub use create_profile::*;
+pub use create_season::*;
+pub use create_shootout_table::*;
+pub use create_tournament::*;
+pub use deal_street::*;
+pub use delete_note::*;
+pub use dispute_hand::*;
+pub use eliminate_player::*;
+pub use enable_extension::*;
+pub use end_game::*;
+pub use force_timeout::*;
+pub use fund_season::*;
+pub use get_legal_actions::*;
+pub use get_table_snapshot::*;
+pub use get_usd_stakes::*;
+pub use grant_freeroll_pass::*;
+pub use init_global_config::*;
+pub use init_global_stats::*;
+pub use init_hand_archive::*;
+pub use init_leaderboard::*;
+pub use init_stats_shard::*;
+pub use init_table_leaderboard::*;
+pub use initialize_game::*;
+pub use invite_player::*;
+pub use join_game::*;
+pub use join_season::*;
+pub use late_register::*;
+pub use leave_table::*;
+pub use merge_stats::*;
+pub use open_hand_history::*;
+pub use propose_deal::*;
+pub use re_enter::*;
+pub use register_dealer_bot::*;
+pub use register_freeroll::*;
+pub use register_spectator::*;
+pub use register_tournament::*;
+pub use remove_club_member::*;
+pub use rename_profile::*;
+pub use reset_leaderboard::*;
+pub use resolve_dispute::*;
+pub use resume_table::*;
+pub use reveal_winner::*;
+pub use revoke_dealer_bot::*;
+pub use score_tournament_finish::*;
+pub use seat_draw::*;
+pub use send_emote::*;
+pub use set_operator::*;
+pub use set_payout_route::*;
+pub use set_player_limits::*;
+pub use set_table_club::*;
+pub use settle_tournament::*;
+pub use sit_in::*;
+pub use sponsor_tournament::*;
+pub use start_round::*;
+pub use start_tournament::*;
+pub use table_hand_finished::*;
+pub use tip_player::*;
+pub use verify_archived_hand::*;
+pub use void_hand::*;
+pub use write_note::*;
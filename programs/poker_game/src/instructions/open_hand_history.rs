@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_HAND_ACTIONS;
+use crate::engine::hand_id;
+use crate::errors::PokerError;
+use crate::state::{Game, HandAction, HandHistory};
+
+#[derive(Accounts)]
+pub struct OpenHandHistory<'info> {
+    #[account(constraint = game.load()?.in_hand() @ PokerError::GameNotActive)]
+    pub game: AccountLoader<'info, Game>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + HandHistory::LEN,
+        seeds = [b"hand_history", game.key().as_ref(), &game.load()?.hand_number.to_le_bytes()],
+        bump,
+    )]
+    pub history: Account<'info, HandHistory>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<OpenHandHistory>) -> Result<()> {
+    let game_key = ctx.accounts.game.key();
+    let game = ctx.accounts.game.load()?;
+    let history = &mut ctx.accounts.history;
+
+    history.game = game_key;
+    history.hand_number = game.hand_number;
+    history.hand_id = hand_id(&game_key, game.hand_number);
+    history.variant = game.variant();
+    history.actions = [HandAction::default(); MAX_HAND_ACTIONS];
+    history.action_count = 0;
+    history.truncated = false;
+    history.board = [0u8; 5];
+    history.winner = Pubkey::default();
+    history.pot = 0;
+    history.settled = false;
+    history.payer = ctx.accounts.payer.key();
+    history.created_at = Clock::get()?.unix_timestamp;
+    history.bump = ctx.bumps.history;
+
+    Ok(())
+}
@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_PLAYERS;
+use crate::engine::{chip_chop, icm_equities};
+use crate::errors::PokerError;
+use crate::events::DealProposed;
+use crate::state::{DealKind, Game, Tournament, TournamentStatus};
+
+#[derive(Accounts)]
+pub struct ProposeDeal<'info> {
+    #[account(mut)]
+    pub tournament: Account<'info, Tournament>,
+    #[account(constraint = table.load()?.tournament == tournament.key() @ PokerError::TableMismatch)]
+    pub table: AccountLoader<'info, Game>,
+    pub player: Signer<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<ProposeDeal>, kind: DealKind) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+    let table = ctx.accounts.table.load()?;
+    let proposer = ctx.accounts.player.key();
+
+    require!(
+        tournament.status == TournamentStatus::Running,
+        PokerError::TournamentNotRunning
+    );
+    require!(
+        tournament.satellite_target == Pubkey::default(),
+        PokerError::SatelliteTournament
+    );
+    require!(tournament.table_count == 1, PokerError::DealNotFinalTable);
+    require!(!table.in_hand(), PokerError::TableInHand);
+
+    let mut players = [Pubkey::default(); MAX_PLAYERS];
+    let mut stacks = [0u64; MAX_PLAYERS];
+    let mut n = 0;
+    for seat in 0..MAX_PLAYERS {
+        if table.players[seat] != Pubkey::default() {
+            require!(table.stacks[seat] > 0, PokerError::InvalidDeal);
+            players[n] = table.players[seat];
+            stacks[n] = table.stacks[seat];
+            n += 1;
+        }
+    }
+    require!(
+        n > 1 && n == tournament.players_remaining as usize,
+        PokerError::DealNotFinalTable
+    );
+
+    // The deal splits the prizes for the places still being played for
+    let (prizes, paid) = tournament.place_prizes();
+    let mut place_prizes = [0u64; MAX_PLAYERS];
+    let covered = n.min(paid);
+    place_prizes[..covered].copy_from_slice(&prizes[..covered]);
+    let deal_pool: u64 = place_prizes[..n].iter().sum();
+
+    let amounts = match kind {
+        DealKind::ChipChop => chip_chop(&stacks[..n], &place_prizes[..n]),
+        DealKind::Icm => icm_equities(&stacks[..n], &place_prizes[..n]),
+        DealKind::Custom { amounts } => amounts,
+    };
+    require!(
+        amounts[..n].iter().sum::<u64>() == deal_pool && amounts[n..].iter().all(|&a| a == 0),
+        PokerError::InvalidDeal
+    );
+
+    let proposer_index = players[..n]
+        .iter()
+        .position(|&p| p == proposer)
+        .ok_or(PokerError::PlayerNotInGame)?;
+
+    tournament.deal_players = players;
+    tournament.deal_amounts = amounts;
+    tournament.deal_accepted = [false; MAX_PLAYERS];
+    tournament.deal_accepted[proposer_index] = true;
+    tournament.deal_count = n as u8;
+
+    emit_event!(DealProposed {
+        tournament: tournament.key(),
+        proposer,
+        players,
+        amounts,
+        count: n as u8,
+    });
+
+    Ok(())
+}
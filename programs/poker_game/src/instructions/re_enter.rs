@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::NO_ELIMINATOR;
+use crate::engine::{collect_buy_in, seat_tournament_player, tournament_blind_level};
+use crate::errors::PokerError;
+use crate::state::{BlindStructure, Game, TableConfig, Tournament, TournamentStatus};
+
+#[derive(Accounts)]
+pub struct ReEnter<'info> {
+    #[account(mut)]
+    pub tournament: Account<'info, Tournament>,
+    #[account(mut, constraint = table.load()?.tournament == tournament.key() @ PokerError::TableMismatch)]
+    pub table: AccountLoader<'info, Game>,
+    #[account(seeds = [b"table_config", table.key().as_ref()], bump = config.load()?.bump)]
+    pub config: AccountLoader<'info, TableConfig>,
+    /// Required when the tournament uses a blind structure.
+    pub blind_structure: Option<Account<'info, BlindStructure>>,
+    #[account(mut)]
+    pub player: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<ReEnter>) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+    let config = ctx.accounts.config.load()?;
+    let mut table = ctx.accounts.table.load_mut()?;
+    table.next_seq();
+    let player = &ctx.accounts.player;
+
+    require!(
+        tournament.status == TournamentStatus::Running,
+        PokerError::TournamentNotRunning
+    );
+
+    let (level, _) = tournament_blind_level(
+        tournament,
+        ctx.accounts.blind_structure.as_ref(),
+        Clock::get()?.unix_timestamp,
+    )?
+    .unwrap_or_default();
+    require!(
+        level < tournament.reentry_levels as usize,
+        PokerError::ReentryClosed
+    );
+
+    let entrant = tournament
+        .entrant_index(&player.key())
+        .ok_or(PokerError::PlayerNotInGame)?;
+    require!(tournament.eliminated[entrant], PokerError::PlayerNotEliminated);
+    require!(
+        tournament.entries[entrant] <= tournament.max_reentries,
+        PokerError::ReentryLimitReached
+    );
+    require!(!table.in_hand(), PokerError::TableInHand);
+
+    collect_buy_in(tournament, player, &ctx.accounts.system_program)?;
+    seat_tournament_player(&mut table, &config, player.key(), tournament.starting_stack)?;
+
+    tournament.eliminated[entrant] = false;
+    tournament.finish_positions[entrant] = 0;
+    tournament.eliminated_by[entrant] = NO_ELIMINATOR;
+    tournament.elimination_levels[entrant] = 0;
+    tournament.entries[entrant] += 1;
+    tournament.bounties[entrant] = tournament.bounty;
+    tournament.players_remaining += 1;
+    tournament.refresh_hand_for_hand();
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_DEALER_BOTS;
+use crate::errors::PokerError;
+use crate::state::{BotScopes, DealerBot, Game, TableConfig};
+
+#[derive(Accounts)]
+#[instruction(bot: Pubkey)]
+pub struct RegisterDealerBot<'info> {
+    pub game: AccountLoader<'info, Game>,
+    #[account(mut, seeds = [b"table_config", game.key().as_ref()], bump = config.load()?.bump)]
+    pub config: AccountLoader<'info, TableConfig>,
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = 8 + DealerBot::LEN,
+        seeds = [b"dealer_bot", game.key().as_ref(), bot.as_ref()],
+        bump,
+    )]
+    pub dealer_bot: Account<'info, DealerBot>,
+    /// The table owner or its operator key.
+    #[account(mut, constraint = config.load()?.is_host(&signer.key()) @ PokerError::NotAuthorized)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<RegisterDealerBot>, bot: Pubkey, scopes: BotScopes) -> Result<()> {
+    let record = &mut ctx.accounts.dealer_bot;
+    // Registering a bot again only changes its scopes
+    if record.registered_at == 0 {
+        let mut config = ctx.accounts.config.load_mut()?;
+        require!(config.dealer_bots < MAX_DEALER_BOTS, PokerError::TooManyDealerBots);
+        config.dealer_bots += 1;
+        record.game = ctx.accounts.game.key();
+        record.bot = bot;
+        record.registered_at = Clock::get()?.unix_timestamp;
+        record.bump = ctx.bumps.dealer_bot;
+    }
+    record.scopes = scopes;
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_ENTRANTS;
+use crate::engine::redeem_ticket;
+use crate::errors::PokerError;
+use crate::state::{EntryGate, FreerollPass, Ticket, Tournament, TournamentStatus};
+
+#[derive(Accounts)]
+pub struct RegisterFreeroll<'info> {
+    #[account(mut)]
+    pub tournament: Account<'info, Tournament>,
+    /// Required for allowlist-gated freerolls.
+    #[account(
+        seeds = [b"freeroll_pass", tournament.key().as_ref(), player.key().as_ref()],
+        bump = pass.bump,
+    )]
+    pub pass: Option<Account<'info, FreerollPass>>,
+    /// Required for ticket-gated freerolls; closed back to the player.
+    #[account(
+        mut,
+        close = player,
+        seeds = [b"ticket", tournament.key().as_ref(), player.key().as_ref()],
+        bump = ticket.bump,
+        constraint = ticket.owner == player.key() @ PokerError::InvalidTicket,
+    )]
+    pub ticket: Option<Account<'info, Ticket>>,
+    #[account(mut)]
+    pub player: Signer<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<RegisterFreeroll>) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+    let player = &ctx.accounts.player;
+
+    require!(
+        tournament.status == TournamentStatus::Registering,
+        PokerError::RegistrationClosed
+    );
+    require!(tournament.buy_in == 0, PokerError::NotFreeroll);
+
+    match tournament.entry_gate {
+        EntryGate::Open => {}
+        EntryGate::Allowlist => {
+            let pass = ctx
+                .accounts
+                .pass
+                .as_ref()
+                .ok_or(PokerError::EntryNotAllowed)?;
+            require_keys_eq!(pass.tournament, tournament.key(), PokerError::EntryNotAllowed);
+        }
+        EntryGate::Ticket => {
+            let ticket = ctx
+                .accounts
+                .ticket
+                .as_ref()
+                .ok_or(PokerError::EntryNotAllowed)?;
+            redeem_ticket(tournament, ticket)?;
+        }
+    }
+
+    let count = tournament.entrant_count as usize;
+    require!(count < MAX_ENTRANTS, PokerError::TournamentFull);
+    require!(
+        !tournament.entrants[..count].contains(&player.key()),
+        PokerError::AlreadyRegistered
+    );
+
+    tournament.entrants[count] = player.key();
+    tournament.entries[count] = 1;
+    tournament.entrant_count += 1;
+    tournament.mix_seat_seed(&player.key(), Clock::get()?.slot);
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PokerError;
+use crate::state::{Game, Spectator};
+
+#[derive(Accounts)]
+pub struct RegisterSpectator<'info> {
+    #[account(constraint = !game.load()?.players.contains(&wallet.key()) @ PokerError::AlreadySeated)]
+    pub game: AccountLoader<'info, Game>,
+    #[account(
+        init,
+        payer = wallet,
+        space = 8 + Spectator::LEN,
+        seeds = [b"spectator", game.key().as_ref(), wallet.key().as_ref()],
+        bump,
+    )]
+    pub spectator: Account<'info, Spectator>,
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<RegisterSpectator>) -> Result<()> {
+    let spectator = &mut ctx.accounts.spectator;
+    spectator.game = ctx.accounts.game.key();
+    spectator.wallet = ctx.accounts.wallet.key();
+    spectator.registered_at = Clock::get()?.unix_timestamp;
+    spectator.tipped = 0;
+    spectator.bump = ctx.bumps.spectator;
+
+    Ok(())
+}
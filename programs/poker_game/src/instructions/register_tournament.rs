@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_ENTRANTS;
+use crate::engine::{collect_buy_in, redeem_ticket};
+use crate::errors::PokerError;
+use crate::state::{Ticket, Tournament, TournamentStatus};
+
+#[derive(Accounts)]
+pub struct RegisterTournament<'info> {
+    #[account(mut)]
+    pub tournament: Account<'info, Tournament>,
+    /// Satellite ticket redeemed for the buy-in; closed back to the player.
+    #[account(
+        mut,
+        close = player,
+        seeds = [b"ticket", tournament.key().as_ref(), player.key().as_ref()],
+        bump = ticket.bump,
+        constraint = ticket.owner == player.key() @ PokerError::InvalidTicket,
+    )]
+    pub ticket: Option<Account<'info, Ticket>>,
+    #[account(mut)]
+    pub player: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<RegisterTournament>) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+    let player = &ctx.accounts.player;
+
+    require!(
+        tournament.status == TournamentStatus::Registering,
+        PokerError::RegistrationClosed
+    );
+    require!(tournament.buy_in > 0, PokerError::FreerollTournament);
+
+    let count = tournament.entrant_count as usize;
+    require!(count < MAX_ENTRANTS, PokerError::TournamentFull);
+    require!(
+        !tournament.entrants[..count].contains(&player.key()),
+        PokerError::AlreadyRegistered
+    );
+
+    // A satellite ticket pays the buy-in in place of the player's own funds
+    if let Some(ticket) = &ctx.accounts.ticket {
+        redeem_ticket(tournament, ticket)?;
+    } else {
+        collect_buy_in(tournament, player, &ctx.accounts.system_program)?;
+    }
+
+    tournament.entrants[count] = player.key();
+    tournament.entries[count] = 1;
+    tournament.bounties[count] = tournament.bounty;
+    tournament.entrant_count += 1;
+    tournament.mix_seat_seed(&player.key(), Clock::get()?.slot);
+
+    Ok(())
+}
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PokerError;
+use crate::state::Club;
+
+#[derive(Accounts)]
+#[instruction(member: Pubkey)]
+pub struct RemoveClubMember<'info> {
+    #[account(mut)]
+    pub club: Account<'info, Club>,
+    /// The club owner, or the member leaving.
+    #[account(constraint = signer.key() == club.owner || signer.key() == member @ PokerError::NotAuthorized)]
+    pub signer: Signer<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<RemoveClubMember>, member: Pubkey) -> Result<()> {
+    let members = &mut ctx.accounts.club.members;
+    let index = members.iter().position(|m| *m == member).ok_or(PokerError::NotClubMember)?;
+    members.remove(index);
+
+    Ok(())
+}
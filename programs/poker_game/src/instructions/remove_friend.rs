@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PokerError;
+use crate::instructions::EditFriends;
+
+pub(crate) fn handler(ctx: Context<EditFriends>, friend: Pubkey) -> Result<()> {
+    let friends = &mut ctx.accounts.profile.friends;
+    let index = friends.iter().position(|f| *f == friend).ok_or(PokerError::InvalidFriend)?;
+    friends.remove(index);
+
+    Ok(())
+}
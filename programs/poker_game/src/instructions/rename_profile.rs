@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PokerError;
+use crate::instructions::create_profile::valid_display_name;
+use crate::state::{name_hash, NameRecord, PlayerProfile};
+
+#[derive(Accounts)]
+#[instruction(display_name: String)]
+pub struct RenameProfile<'info> {
+    #[account(mut, has_one = wallet @ PokerError::NotAuthorized, seeds = [b"profile", wallet.key().as_ref()], bump = profile.bump)]
+    pub profile: Account<'info, PlayerProfile>,
+    /// Released to whoever claims the name next.
+    #[account(
+        mut,
+        close = wallet,
+        seeds = [b"name".as_ref(), &name_hash(&profile.display_name)],
+        bump = old_name.bump,
+    )]
+    pub old_name: Account<'info, NameRecord>,
+    #[account(
+        init,
+        payer = wallet,
+        space = 8 + NameRecord::LEN,
+        seeds = [b"name".as_ref(), &name_hash(&display_name)],
+        bump,
+    )]
+    pub new_name: Account<'info, NameRecord>,
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<RenameProfile>, display_name: String) -> Result<()> {
+    require!(valid_display_name(&display_name), PokerError::InvalidProfile);
+
+    let new_name = &mut ctx.accounts.new_name;
+    new_name.owner = ctx.accounts.wallet.key();
+    new_name.bump = ctx.bumps.new_name;
+    ctx.accounts.profile.display_name = display_name;
+
+    Ok(())
+}
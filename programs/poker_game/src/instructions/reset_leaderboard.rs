@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PokerError;
+use crate::state::{Leaderboard, TableConfig};
+
+#[derive(Accounts)]
+pub struct ResetLeaderboard<'info> {
+    #[account(mut)]
+    pub leaderboard: Account<'info, Leaderboard>,
+    /// The ranked table's config; required for table leaderboards.
+    #[account(seeds = [b"table_config", leaderboard.scope.as_ref()], bump = config.load()?.bump)]
+    pub config: Option<AccountLoader<'info, TableConfig>>,
+    pub signer: Signer<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<ResetLeaderboard>) -> Result<()> {
+    let signer = ctx.accounts.signer.key();
+    let board = &mut ctx.accounts.leaderboard;
+
+    let allowed = if board.scope == Pubkey::default() {
+        signer == board.authority
+    } else {
+        let config = ctx.accounts.config.as_ref().ok_or(PokerError::NotAuthorized)?;
+        config.load()?.is_host(&signer)
+    };
+    require!(allowed, PokerError::NotAuthorized);
+
+    board.start_season(Clock::get()?.unix_timestamp);
+
+    Ok(())
+}
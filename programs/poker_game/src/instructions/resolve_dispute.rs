@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+
+use crate::engine::{award_pot, hand_id, record_club, record_leaderboards, record_profiles, record_season, stats_shard_for, Escrow, Memo, Memos, Payee};
+use crate::errors::PokerError;
+use crate::events::DisputeResolved;
+use crate::state::{Club, Game, GamePhase, GlobalConfig, HandHistory, Leaderboard, MemoConfig, PayoutRoute, Season, StatsShard, TableArbiter, TableConfig};
+
+#[derive(Accounts)]
+pub struct ResolveDispute<'info> {
+    #[account(
+        mut,
+        constraint = game.load()?.phase() == GamePhase::Showdown @ PokerError::HandNotComplete,
+        constraint = game.load()?.disputed != 0 @ PokerError::HandNotDisputed
+    )]
+    pub game: AccountLoader<'info, Game>,
+    #[account(seeds = [b"table_config", game.key().as_ref()], bump = config.load()?.bump)]
+    pub config: AccountLoader<'info, TableConfig>,
+    #[account(seeds = [b"arbiter", game.key().as_ref()], bump = arbiter.bump, has_one = game)]
+    pub arbiter: Account<'info, TableArbiter>,
+    #[account(constraint = authority.key() == arbiter.arbiter @ PokerError::NotAuthorized)]
+    pub authority: Signer<'info>,
+
+    /// Wallet of the seat holding the best live hand; only paid when upheld.
+    #[account(
+        mut,
+        constraint = game.load()?.showdown_winner_wallet() == Some(winner.key())
+            @ PokerError::NotBestHand
+    )]
+    pub winner: SystemAccount<'info>,
+
+    /// Records the result when passed.
+    #[account(
+        mut,
+        seeds = [b"hand_history", game.key().as_ref(), &game.load()?.hand_number.to_le_bytes()],
+        bump = history.bump,
+    )]
+    pub history: Option<Account<'info, HandHistory>>,
+
+    /// Counts the hand toward protocol stats when passed.
+    #[account(mut, seeds = [b"stats_shard".as_ref(), &[stats_shard_for(&game.key())]], bump = stats_shard.bump)]
+    pub stats_shard: Option<Account<'info, StatsShard>>,
+    /// Ranks the winner on the protocol-wide leaderboard when passed. Every
+    /// table settling with it shares its write lock.
+    #[account(mut, seeds = [b"leaderboard"], bump = leaderboard.bump)]
+    pub leaderboard: Option<Box<Account<'info, Leaderboard>>>,
+    /// Ranks the winner on the table's leaderboard when passed.
+    #[account(mut, seeds = [b"leaderboard", game.key().as_ref()], bump = table_leaderboard.bump)]
+    pub table_leaderboard: Option<Box<Account<'info, Leaderboard>>>,
+    /// Scores the hand for the season's entries among the remaining
+    /// accounts when passed.
+    #[account(mut)]
+    pub season: Option<Box<Account<'info, Season>>>,
+    /// Adds the pot to the table's club stats when passed.
+    #[account(mut, constraint = club.key() == config.load()?.club @ PokerError::NotClubMember)]
+    pub club: Option<Box<Account<'info, Club>>>,
+
+    /// The winner's payout route, when their seat routes winnings into a
+    /// staking program. Left out, the pot goes to the winner's wallet.
+    #[account(seeds = [b"payout_route", game.key().as_ref(), payout_route.wallet.as_ref()], bump = payout_route.bump)]
+    pub payout_route: Option<Box<Account<'info, PayoutRoute>>>,
+    /// Required with `payout_route`.
+    #[account(seeds = [b"global_config"], bump = global_config.bump)]
+    pub global_config: Option<Box<Account<'info, GlobalConfig>>>,
+    /// CHECK: the route's deposit account; checked by `pay_into_escrow`.
+    #[account(mut)]
+    pub staking_deposit: Option<UncheckedAccount<'info>>,
+    /// CHECK: the route's staking program; checked by `pay_into_escrow`.
+    pub staking_program: Option<UncheckedAccount<'info>>,
+    /// The table's memo tag and the Memo program; required at tables that
+    /// tag payouts with memos.
+    #[account(seeds = [b"memo_config", game.key().as_ref()], bump = memo_config.bump)]
+    pub memo_config: Option<Account<'info, MemoConfig>>,
+    pub memo_program: Option<Program<'info, Memo>>,
+}
+
+pub(crate) fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ResolveDispute<'info>>, uphold: bool) -> Result<()> {
+    let game_info = ctx.accounts.game.to_account_info();
+    let game_key = game_info.key();
+    let config = ctx.accounts.config.load()?;
+    let memos = Memos::new(&config, ctx.accounts.memo_config.as_deref(), ctx.accounts.memo_program.as_deref());
+    let mut game = ctx.accounts.game.load_mut()?;
+    game.next_seq();
+    game.disputed = 0;
+
+    emit_event!(DisputeResolved {
+        game: game_key,
+        seq: game.state_seq,
+        hand_id: hand_id(&game_key, game.hand_number),
+        hand_number: game.hand_number,
+        upheld: uphold,
+    });
+
+    if !uphold {
+        return game.refund_hand();
+    }
+
+    let winner_index = game.showdown_winner().ok_or(PokerError::NoActivePlayers)?;
+    let winner_info = ctx.accounts.winner.to_account_info();
+    let escrow = Escrow::from_accounts(
+        ctx.accounts.payout_route.as_deref().map(|route| &**route),
+        ctx.accounts.global_config.as_deref().map(|config| &**config),
+        ctx.accounts.staking_deposit.as_deref(),
+        ctx.accounts.staking_program.as_deref(),
+    )?;
+    let pot = game.pot;
+    let now = Clock::get()?.unix_timestamp;
+    let won = award_pot(
+        &mut game,
+        &game_info,
+        winner_index,
+        Some(Payee { wallet: &winner_info, escrow }),
+        now,
+        ctx.accounts.stats_shard.as_mut(),
+        memos,
+    )?;
+    record_profiles(&game, winner_index, won, ctx.remaining_accounts)?;
+    record_season(ctx.accounts.season.as_mut(), game_key, &game, winner_index, ctx.remaining_accounts, now)?;
+    record_club(ctx.accounts.club.as_mut(), &game, won);
+    record_leaderboards(
+        game.players[winner_index],
+        won,
+        [ctx.accounts.leaderboard.as_mut(), ctx.accounts.table_leaderboard.as_mut()],
+    );
+    if let Some(history) = ctx.accounts.history.as_mut() {
+        history.record_result(&game, winner_index, pot);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PokerError;
+use crate::state::{Game, TableConfig};
+
+#[derive(Accounts)]
+pub struct ResumeTable<'info> {
+    #[account(mut)]
+    pub game: AccountLoader<'info, Game>,
+    #[account(seeds = [b"table_config", game.key().as_ref()], bump = config.load()?.bump)]
+    pub config: AccountLoader<'info, TableConfig>,
+    #[account(constraint = owner.key() == config.load()?.owner @ PokerError::NotAuthorized)]
+    pub owner: Signer<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<ResumeTable>) -> Result<()> {
+    let mut game = ctx.accounts.game.load_mut()?;
+    game.next_seq();
+    game.halted = 0;
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PokerError;
+use crate::state::{DealerBot, Game, TableConfig};
+
+#[derive(Accounts)]
+pub struct RevokeDealerBot<'info> {
+    pub game: AccountLoader<'info, Game>,
+    #[account(mut, seeds = [b"table_config", game.key().as_ref()], bump = config.load()?.bump)]
+    pub config: AccountLoader<'info, TableConfig>,
+    #[account(
+        mut,
+        close = signer,
+        seeds = [b"dealer_bot", game.key().as_ref(), dealer_bot.bot.as_ref()],
+        bump = dealer_bot.bump,
+    )]
+    pub dealer_bot: Account<'info, DealerBot>,
+    /// The table owner or its operator key.
+    #[account(mut, constraint = config.load()?.is_host(&signer.key()) @ PokerError::NotAuthorized)]
+    pub signer: Signer<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<RevokeDealerBot>) -> Result<()> {
+    let mut config = ctx.accounts.config.load_mut()?;
+    config.dealer_bots = config.dealer_bots.saturating_sub(1);
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::engine::{rate_limit, require_crank_scope, Memos};
+use crate::instructions::{advance_v2, time_out_turn, AdvanceGameV2};
+use crate::state::{AdvanceStatus, CrankStep};
+
+pub(crate) fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, AdvanceGameV2<'info>>,
+) -> Result<AdvanceStatus> {
+    let clock = Clock::get()?;
+    {
+        let accounts = &ctx.accounts.advance;
+        let game_info = accounts.game.to_account_info();
+        let caller_info = accounts.caller.to_account_info();
+        let config = accounts.config.load()?;
+        let mut game = accounts.game.load_mut()?;
+
+        let window = config.rules.crank_slot_window;
+        if window > 0 && game.last_crank_slot > 0 && clock.slot < game.last_crank_slot + window {
+            return Ok(AdvanceStatus::RateLimited);
+        }
+
+        // A seat whose clock ran out holds up everything else
+        let stalled = game.is_betting() && !game.all_in_runout() && !game.betting_complete();
+        if stalled && clock.unix_timestamp > game.turn_deadline {
+            require_crank_scope(&config, &game, &caller_info.key(), ctx.accounts.dealer_bot.as_deref(), CrankStep::TimeOut)?;
+            game.next_seq();
+            rate_limit(&mut game.last_crank_slot, window, clock.slot)?;
+            let memos = Memos::new(&config, ctx.accounts.memo_config.as_deref(), ctx.accounts.memo_program.as_deref());
+            time_out_turn(&mut game, &game_info, &config, &caller_info, memos, clock.unix_timestamp)?;
+            return Ok(AdvanceStatus::TurnTimedOut);
+        }
+    }
+
+    advance_v2(ctx, true)
+}
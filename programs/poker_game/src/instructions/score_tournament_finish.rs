@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PokerError;
+use crate::state::{Season, SeasonEntry, Tournament, TournamentStatus};
+
+#[derive(Accounts)]
+pub struct ScoreTournamentFinish<'info> {
+    #[account(mut)]
+    pub season: Account<'info, Season>,
+    #[account(
+        mut,
+        seeds = [b"season_entry", season.key().as_ref(), entry.player.as_ref()],
+        bump = entry.bump,
+    )]
+    pub entry: Account<'info, SeasonEntry>,
+    pub tournament: Account<'info, Tournament>,
+}
+
+pub(crate) fn handler(ctx: Context<ScoreTournamentFinish>) -> Result<()> {
+    let season = &mut ctx.accounts.season;
+    let entry = &mut ctx.accounts.entry;
+    let tournament = &ctx.accounts.tournament;
+
+    require!(season.is_active(Clock::get()?.unix_timestamp), PokerError::SeasonNotActive);
+    require!(
+        tournament.status == TournamentStatus::Finished,
+        PokerError::TournamentNotRunning
+    );
+    let venue = season
+        .venue_index(&tournament.key())
+        .ok_or(PokerError::NotSeasonVenue)?;
+    require!(entry.tournaments_scored & (1 << venue) == 0, PokerError::NothingToClaim);
+
+    let position = tournament
+        .entrant_index(&entry.player)
+        .map_or(0, |entrant| tournament.finish_positions[entrant] as usize);
+    let points = match position {
+        0 => 0,
+        p => season.weights.finishes.get(p - 1).copied().unwrap_or(0),
+    };
+    entry.tournaments_scored |= 1 << venue;
+    season.award(entry, points as u64);
+
+    Ok(())
+}
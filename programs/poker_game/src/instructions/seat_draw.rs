@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+use crate::engine::draw_seats;
+use crate::errors::PokerError;
+use crate::state::Tournament;
+
+#[derive(Accounts)]
+pub struct SeatDraw<'info> {
+    #[account(mut, has_one = authority @ PokerError::NotAuthorized)]
+    pub tournament: Account<'info, Tournament>,
+    pub authority: Signer<'info>,
+}
+
+pub(crate) fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, SeatDraw<'info>>) -> Result<()> {
+    draw_seats(
+        &mut ctx.accounts.tournament,
+        ctx.remaining_accounts,
+        Clock::get()?.unix_timestamp,
+    )
+}
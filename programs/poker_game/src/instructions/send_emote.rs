@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::engine::hand_id;
+use crate::errors::PokerError;
+use crate::events::{Emote, EmoteSent};
+use crate::state::{Game, Seat};
+
+#[derive(Accounts)]
+pub struct SendEmote<'info> {
+    pub game: AccountLoader<'info, Game>,
+    pub player: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"seat", game.key().as_ref(), player.key().as_ref()],
+        bump = seat.bump,
+        has_one = game @ PokerError::SeatMismatch,
+        constraint = seat.owner == player.key() @ PokerError::SeatMismatch,
+        constraint = game.load()?.players.get(seat.index as usize) == Some(&player.key()) @ PokerError::SeatMismatch,
+    )]
+    pub seat: Account<'info, Seat>,
+}
+
+pub(crate) fn handler(ctx: Context<SendEmote>, emote: Emote) -> Result<()> {
+    let game_key = ctx.accounts.game.key();
+    let game = ctx.accounts.game.load()?;
+    let seat = &mut ctx.accounts.seat;
+
+    // Between streets and between hands count as a street of their own
+    let street = if game.is_betting() { game.street() } else { u8::MAX };
+    require!(seat.spend_emote(game.hand_number, street), PokerError::EmoteLimit);
+
+    emit_event!(EmoteSent {
+        game: game_key,
+        seq: game.state_seq,
+        hand_id: hand_id(&game_key, game.hand_number),
+        seat: seat.index,
+        emote,
+    });
+
+    Ok(())
+}
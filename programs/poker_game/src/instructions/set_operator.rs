@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PokerError;
+use crate::state::{Game, TableConfig};
+
+#[derive(Accounts)]
+pub struct ConfigureOwner<'info> {
+    pub game: AccountLoader<'info, Game>,
+    #[account(mut, seeds = [b"table_config", game.key().as_ref()], bump = config.load()?.bump)]
+    pub config: AccountLoader<'info, TableConfig>,
+    #[account(constraint = owner.key() == config.load()?.owner @ PokerError::NotAuthorized)]
+    pub owner: Signer<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<ConfigureOwner>, operator: Pubkey) -> Result<()> {
+    ctx.accounts.config.load_mut()?.operator = operator;
+
+    Ok(())
+}
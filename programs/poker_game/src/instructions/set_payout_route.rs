@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PokerError;
+use crate::state::{Game, GlobalConfig, PayoutRoute, Seat};
+
+#[derive(Accounts)]
+pub struct SetPayoutRoute<'info> {
+    #[account(mut, constraint = !game.load()?.is_tournament_table() @ PokerError::TournamentTable)]
+    pub game: AccountLoader<'info, Game>,
+    #[account(seeds = [b"global_config"], bump = global_config.bump)]
+    pub global_config: Account<'info, GlobalConfig>,
+    #[account(
+        seeds = [b"seat", game.key().as_ref(), player.key().as_ref()],
+        bump = seat.bump,
+        has_one = game @ PokerError::SeatMismatch,
+        constraint = seat.owner == player.key() @ PokerError::SeatMismatch,
+        constraint = game.load()?.players.get(seat.index as usize) == Some(&player.key()) @ PokerError::PlayerNotInGame,
+    )]
+    pub seat: Account<'info, Seat>,
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + PayoutRoute::LEN,
+        seeds = [b"payout_route", game.key().as_ref(), player.key().as_ref()],
+        bump,
+    )]
+    pub route: Account<'info, PayoutRoute>,
+    #[account(mut)]
+    pub player: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<SetPayoutRoute>, program: Pubkey, deposit: Pubkey) -> Result<()> {
+    let bit = 1 << ctx.accounts.seat.index;
+    let mut game = ctx.accounts.game.load_mut()?;
+
+    // The default program sends winnings back to the wallet
+    if program == Pubkey::default() {
+        game.escrow_seats &= !bit;
+        return Ok(());
+    }
+
+    require!(
+        ctx.accounts.global_config.staking_program(&program).is_some(),
+        PokerError::StakingProgramNotAllowed
+    );
+    require!(deposit != Pubkey::default(), PokerError::InvalidPayoutRoute);
+
+    let route = &mut ctx.accounts.route;
+    route.game = ctx.accounts.game.key();
+    route.wallet = ctx.accounts.player.key();
+    route.program = program;
+    route.deposit = deposit;
+    route.bump = ctx.bumps.route;
+    game.escrow_seats |= bit;
+
+    Ok(())
+}
@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PokerError;
+use crate::state::PlayerProfile;
+
+#[derive(Accounts)]
+pub struct SetPlayerLimits<'info> {
+    #[account(mut, has_one = wallet @ PokerError::NotAuthorized, seeds = [b"profile", wallet.key().as_ref()], bump = profile.bump)]
+    pub profile: Account<'info, PlayerProfile>,
+    pub wallet: Signer<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<SetPlayerLimits>, daily_buy_in: u64, excluded_until: i64) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.profile.limits.update(daily_buy_in, excluded_until, now);
+
+    Ok(())
+}
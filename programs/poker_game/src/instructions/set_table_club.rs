@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PokerError;
+use crate::state::{Club, Game, TableConfig};
+
+#[derive(Accounts)]
+pub struct SetTableClub<'info> {
+    #[account(mut, constraint = !game.load()?.in_hand() @ PokerError::TableInHand)]
+    pub game: AccountLoader<'info, Game>,
+    #[account(mut, seeds = [b"table_config", game.key().as_ref()], bump = config.load()?.bump)]
+    pub config: AccountLoader<'info, TableConfig>,
+    /// Must host the table and, when a club is passed, own it.
+    #[account(constraint = config.load()?.is_host(&signer.key()) @ PokerError::NotAuthorized)]
+    pub signer: Signer<'info>,
+    /// The club to bring the table into; omit to take it out of its club.
+    #[account(mut, constraint = club.owner == signer.key() @ PokerError::NotAuthorized)]
+    pub club: Option<Account<'info, Club>>,
+}
+
+pub(crate) fn handler(ctx: Context<SetTableClub>) -> Result<()> {
+    let mut config = ctx.accounts.config.load_mut()?;
+    ctx.accounts.game.load_mut()?.next_seq();
+
+    match ctx.accounts.club.as_mut() {
+        Some(club) => {
+            if config.club != club.key() {
+                club.stats.games += 1;
+            }
+            config.club = club.key();
+        }
+        None => config.club = Pubkey::default(),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::engine::pay_finisher;
+use crate::errors::PokerError;
+use crate::state::{Tournament, TournamentStatus};
+
+#[derive(Accounts)]
+pub struct SettleTournament<'info> {
+    #[account(mut)]
+    pub tournament: Account<'info, Tournament>,
+}
+
+pub(crate) fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SettleTournament<'info>>,
+) -> Result<()> {
+    let tournament_account_info = ctx.accounts.tournament.to_account_info();
+    let tournament = &mut ctx.accounts.tournament;
+
+    require!(
+        tournament.status == TournamentStatus::Running,
+        PokerError::TournamentNotRunning
+    );
+    require!(tournament.players_remaining == 1, PokerError::TournamentNotDecided);
+    require!(
+        tournament.satellite_target == Pubkey::default(),
+        PokerError::SatelliteTournament
+    );
+
+    // The last player standing finishes first
+    let count = tournament.entrant_count as usize;
+    let winner = tournament.eliminated[..count]
+        .iter()
+        .position(|&e| !e)
+        .ok_or(PokerError::TournamentNotDecided)?;
+    tournament.finish_positions[winner] = 1;
+
+    // The winner collects the bounty on their own head
+    tournament.bounty_winnings[winner] += tournament.bounties[winner];
+    tournament.bounties[winner] = 0;
+
+    let (amounts, paid) = tournament.place_prizes();
+    require!(
+        ctx.remaining_accounts.len() == paid,
+        PokerError::PayoutAccountsMismatch
+    );
+
+    for (place, info) in ctx.remaining_accounts.iter().enumerate() {
+        pay_finisher(tournament, &tournament_account_info, info, place + 1, amounts[place])?;
+    }
+
+    tournament.prize_pool = 0;
+    tournament.status = TournamentStatus::Finished;
+
+    Ok(())
+}
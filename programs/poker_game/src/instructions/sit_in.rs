@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PokerError;
+use crate::state::{Game, Seat};
+
+#[derive(Accounts)]
+pub struct SitIn<'info> {
+    #[account(mut)]
+    pub game: AccountLoader<'info, Game>,
+    pub player: Signer<'info>,
+    #[account(
+        seeds = [b"seat", game.key().as_ref(), player.key().as_ref()],
+        bump = seat.bump,
+        has_one = game @ PokerError::SeatMismatch,
+        constraint = seat.owner == player.key() @ PokerError::SeatMismatch,
+        constraint = game.load()?.players.get(seat.index as usize) == Some(&player.key()) @ PokerError::SeatMismatch,
+    )]
+    pub seat: Account<'info, Seat>,
+}
+
+pub(crate) fn handler(ctx: Context<SitIn>) -> Result<()> {
+    let mut game = ctx.accounts.game.load_mut()?;
+    game.next_seq();
+
+    let seat = ctx.accounts.seat.index as usize;
+    require!(game.sitting_out[seat] != 0, PokerError::NotSittingOut);
+
+    game.sitting_out[seat] = 0;
+    game.consecutive_timeouts[seat] = 0;
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::errors::PokerError;
+use crate::state::{Tournament, TournamentStatus};
+
+#[derive(Accounts)]
+pub struct SponsorTournament<'info> {
+    #[account(mut)]
+    pub tournament: Account<'info, Tournament>,
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<SponsorTournament>, amount: u64) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+
+    require!(
+        tournament.status != TournamentStatus::Finished,
+        PokerError::TournamentNotRunning
+    );
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.sponsor.to_account_info(),
+                to: tournament.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    tournament.prize_pool += amount;
+    tournament.sponsored += amount;
+
+    Ok(())
+}
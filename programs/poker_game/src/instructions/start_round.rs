@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+use crate::engine::{begin_hand, bot_allows};
+use crate::errors::PokerError;
+use crate::state::{BlindStructure, CrankStep, DealerBot, Game, GamePhase, TableConfig, Tournament, load_extended};
+
+#[derive(Accounts)]
+pub struct StartGame<'info> {
+    #[account(mut, constraint = game.load()?.phase() == GamePhase::WaitingForPlayers @ PokerError::GameAlreadyStarted)]
+    pub game: AccountLoader<'info, Game>,
+    #[account(seeds = [b"table_config", game.key().as_ref()], bump = config.load()?.bump)]
+    pub config: AccountLoader<'info, TableConfig>,
+
+    /// Required for tournament tables.
+    #[account(mut)]
+    pub tournament: Option<Account<'info, Tournament>>,
+    /// Required when the tournament uses a blind structure.
+    pub blind_structure: Option<Account<'info, BlindStructure>>,
+
+    /// A seated player, the host, or the tournament authority; a dealer bot
+    /// deals through `start_round_v2`.
+    pub signer: Signer<'info>,
+}
+
+/// `start_round`'s accounts, then the ones it gained after its account list
+/// was frozen for CPI.
+#[derive(Accounts)]
+pub struct StartGameV2<'info> {
+    pub start: StartGame<'info>,
+    /// The signer's registration when it deals as a dealer bot.
+    #[account(
+        seeds = [b"dealer_bot", start.game.key().as_ref(), start.signer.key().as_ref()],
+        bump = dealer_bot.bump,
+    )]
+    pub dealer_bot: Option<Account<'info, DealerBot>>,
+}
+
+pub(crate) fn handler(ctx: Context<StartGame>) -> Result<()> {
+    start(ctx.accounts, None)
+}
+
+pub(crate) fn handler_v2(ctx: Context<StartGameV2>) -> Result<()> {
+    let accounts = ctx.accounts;
+    start(&mut accounts.start, accounts.dealer_bot.as_deref())
+}
+
+fn start(accounts: &mut StartGame, dealer_bot: Option<&DealerBot>) -> Result<()> {
+    let game_key = accounts.game.key();
+    let config = accounts.config.load()?;
+    let (mut game, mut extensions) = load_extended(&accounts.game)?;
+    game.next_seq();
+    let signer = accounts.signer.key();
+
+    let tournament_authority = accounts
+        .tournament
+        .as_ref()
+        .is_some_and(|t| t.key() == game.tournament && t.authority == signer);
+    require!(
+        game.players.contains(&signer)
+            || config.is_host(&signer)
+            || tournament_authority
+            || bot_allows(dealer_bot, CrankStep::Deal),
+        PokerError::NotAuthorized
+    );
+
+    begin_hand(
+        &mut game,
+        &mut extensions,
+        game_key,
+        &config,
+        accounts.tournament.as_mut(),
+        accounts.blind_structure.as_ref(),
+        Clock::get()?.unix_timestamp,
+    )
+}
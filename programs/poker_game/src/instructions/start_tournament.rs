@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+use crate::engine::draw_seats;
+use crate::errors::PokerError;
+use crate::state::Tournament;
+
+#[derive(Accounts)]
+pub struct StartTournament<'info> {
+    #[account(mut)]
+    pub tournament: Account<'info, Tournament>,
+}
+
+pub(crate) fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, StartTournament<'info>>,
+) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+
+    require!(tournament.start_time > 0, PokerError::NotScheduled);
+
+    draw_seats(tournament, ctx.remaining_accounts, Clock::get()?.unix_timestamp)
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_TABLES;
+use crate::errors::PokerError;
+use crate::state::{Game, Tournament};
+
+#[derive(Accounts)]
+pub struct TableHandFinished<'info> {
+    #[account(mut)]
+    pub tournament: Account<'info, Tournament>,
+    #[account(constraint = table.load()?.tournament == tournament.key() @ PokerError::TableMismatch)]
+    pub table: AccountLoader<'info, Game>,
+}
+
+pub(crate) fn handler(ctx: Context<TableHandFinished>) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+    let table_key = ctx.accounts.table.key();
+    let table = ctx.accounts.table.load()?;
+
+    require!(tournament.hand_for_hand, PokerError::NotHandForHand);
+    require!(!table.in_hand(), PokerError::TableInHand);
+
+    let index = tournament
+        .table_index(&table_key)
+        .ok_or(PokerError::TableMismatch)?;
+    require!(tournament.hfh_dealt[index], PokerError::WaitingForTables);
+    tournament.hfh_ready[index] = true;
+
+    let count = tournament.table_count as usize;
+    if tournament.hfh_ready[..count].iter().all(|&ready| ready) {
+        tournament.hfh_dealt = [false; MAX_TABLES];
+        tournament.hfh_ready = [false; MAX_TABLES];
+    }
+
+    Ok(())
+}
@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::constants::MAX_TIP;
+use crate::engine::{charge_buy_in, load_profile, write_memo, Memo, Memos};
+use crate::errors::PokerError;
+use crate::events::PlayerTipped;
+use crate::state::{FundMovement, Game, MemoConfig, Spectator, TableConfig};
+
+#[derive(Accounts)]
+pub struct TipPlayer<'info> {
+    /// Tips land on the stack, so like a top-up they wait for the hand to
+    /// end; tournament chips are never bought.
+    #[account(
+        mut,
+        constraint = game.load()?.halted == 0 @ PokerError::TableHalted,
+        constraint = !game.load()?.in_hand() @ PokerError::TableInHand,
+        constraint = !game.load()?.is_tournament_table() @ PokerError::TournamentTable,
+        constraint = !game.load()?.players.contains(&wallet.key()) @ PokerError::AlreadySeated,
+    )]
+    pub game: AccountLoader<'info, Game>,
+    #[account(
+        mut,
+        seeds = [b"spectator", game.key().as_ref(), wallet.key().as_ref()],
+        bump = spectator.bump,
+        has_one = game @ PokerError::TableMismatch,
+        has_one = wallet @ PokerError::NotAuthorized,
+    )]
+    pub spectator: Account<'info, Spectator>,
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// `tip_player`'s accounts, then the ones it gained after its account list
+/// was frozen for CPI.
+#[derive(Accounts)]
+pub struct TipPlayerV2<'info> {
+    pub tip: TipPlayer<'info>,
+    #[account(seeds = [b"table_config", tip.game.key().as_ref()], bump = config.load()?.bump)]
+    pub config: AccountLoader<'info, TableConfig>,
+    /// CHECK: the tipper's profile PDA, read only if it has been created,
+    /// whose limits apply to the tip like any other buy-in.
+    #[account(mut, seeds = [b"profile", tip.wallet.key().as_ref()], bump)]
+    pub profile: UncheckedAccount<'info>,
+    /// The table's memo tag and the Memo program; required at tables that
+    /// tag tips with memos.
+    #[account(seeds = [b"memo_config", tip.game.key().as_ref()], bump = memo_config.bump)]
+    pub memo_config: Option<Account<'info, MemoConfig>>,
+    pub memo_program: Option<Program<'info, Memo>>,
+}
+
+pub(crate) fn handler(ctx: Context<TipPlayer>, seat: u8, amount: u64) -> Result<()> {
+    tip(ctx.accounts, seat, amount, Memos::none())
+}
+
+pub(crate) fn handler_v2(ctx: Context<TipPlayerV2>, seat: u8, amount: u64) -> Result<()> {
+    let accounts = ctx.accounts;
+    let mut profile = load_profile(&accounts.profile)?;
+    charge_buy_in(&accounts.profile, profile.as_mut(), amount, Clock::get()?.unix_timestamp)?;
+
+    let config = accounts.config.load()?;
+    let memos = Memos::new(&config, accounts.memo_config.as_deref(), accounts.memo_program.as_deref());
+    tip(&mut accounts.tip, seat, amount, memos)
+}
+
+fn tip(accounts: &mut TipPlayer, seat: u8, amount: u64, memos: Memos) -> Result<()> {
+    require!(amount > 0 && amount <= MAX_TIP, PokerError::InvalidTip);
+
+    let game_key = accounts.game.key();
+    let mut game = accounts.game.load_mut()?;
+    let player = game.players.get(seat as usize).copied().unwrap_or_default();
+    require_keys_neq!(player, Pubkey::default(), PokerError::SeatEmpty);
+
+    game.next_seq();
+    game.stacks[seat as usize] += amount;
+    accounts.spectator.tipped += amount;
+
+    emit_event!(PlayerTipped {
+        game: game_key,
+        seq: game.state_seq,
+        spectator: accounts.wallet.key(),
+        player,
+        seat,
+        amount,
+    });
+    drop(game);
+
+    system_program::transfer(
+        CpiContext::new(
+            accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: accounts.wallet.to_account_info(),
+                to: accounts.game.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    write_memo(memos, FundMovement::Tip, &accounts.wallet.key(), amount)
+}
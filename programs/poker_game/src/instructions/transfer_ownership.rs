@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PokerError;
+use crate::instructions::ConfigureOwner;
+
+pub(crate) fn handler(ctx: Context<ConfigureOwner>, new_owner: Pubkey) -> Result<()> {
+    let mut config = ctx.accounts.config.load_mut()?;
+    require!(new_owner != Pubkey::default(), PokerError::InvalidOwner);
+
+    config.owner = new_owner;
+    config.operator = Pubkey::default();
+
+    Ok(())
+}
@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+use crate::instructions::{advance_v2, AdvanceGameV2};
+use crate::state::AdvanceStatus;
+
+pub(crate) fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, AdvanceGameV2<'info>>,
+) -> Result<AdvanceStatus> {
+    {
+        let game = ctx.accounts.advance.game.load()?;
+        let window = ctx.accounts.advance.config.load()?.rules.crank_slot_window;
+        if window > 0 && game.last_crank_slot > 0 && Clock::get()?.slot < game.last_crank_slot + window {
+            return Ok(AdvanceStatus::RateLimited);
+        }
+    }
+
+    advance_v2(ctx, false)
+}
@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+use crate::state::HandArchive;
+
+#[derive(Accounts)]
+pub struct ViewArchive<'info> {
+    pub archive: Account<'info, HandArchive>,
+}
+
+pub(crate) fn handler(ctx: Context<ViewArchive>, leaf: [u8; 32], index: u64, proof: Vec<[u8; 32]>) -> Result<bool> {
+    Ok(ctx.accounts.archive.verify(leaf, index, &proof))
+}
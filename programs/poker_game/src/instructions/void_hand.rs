@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{ABANDONED_HAND_TIMEOUT, MAX_HOLE_CARDS, MAX_PLAYERS};
+use crate::engine::emit_crank;
+use crate::errors::PokerError;
+use crate::events::CrankReason;
+use crate::state::Game;
+
+#[derive(Accounts)]
+pub struct VoidHand<'info> {
+    #[account(mut, constraint = game.load()?.in_hand() @ PokerError::GameNotActive)]
+    pub game: AccountLoader<'info, Game>,
+    pub caller: Signer<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<VoidHand>) -> Result<()> {
+    let game_key = ctx.accounts.game.key();
+    let mut game = ctx.accounts.game.load_mut()?;
+    game.next_seq();
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(
+        now > game.turn_deadline + ABANDONED_HAND_TIMEOUT,
+        PokerError::HandNotAbandoned
+    );
+    // Contributions go back on the stacks, as when `end_game` ends a hand,
+    // and leave the table with the player
+    game.refund_hand()?;
+
+    game.player_hands = [[0u8; MAX_HOLE_CARDS]; MAX_PLAYERS];
+    game.community_cards = [0u8; 5];
+    game.current_turn = 0;
+    game.folded = [0; MAX_PLAYERS];
+    game.acted = [0; MAX_PLAYERS];
+    game.all_in_protected = [0; MAX_PLAYERS];
+    game.hand_ended_at = now;
+    emit_crank(&game, game_key, ctx.accounts.caller.key(), CrankReason::HandVoided);
+
+    Ok(())
+}
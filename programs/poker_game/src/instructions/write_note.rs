@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_NOTE_LEN;
+use crate::errors::PokerError;
+use crate::state::PlayerNote;
+
+#[derive(Accounts)]
+#[instruction(ciphertext: Vec<u8>)]
+pub struct WriteNote<'info> {
+    /// Grows or shrinks to the new ciphertext; the owner pays or is refunded
+    /// the difference in rent.
+    #[account(
+        mut,
+        has_one = owner @ PokerError::NotAuthorized,
+        seeds = [b"note", owner.key().as_ref(), note.opponent.as_ref()],
+        bump = note.bump,
+        realloc = 8 + PlayerNote::space(ciphertext.len()),
+        realloc::payer = owner,
+        realloc::zero = false,
+    )]
+    pub note: Account<'info, PlayerNote>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<WriteNote>, ciphertext: Vec<u8>) -> Result<()> {
+    require!(ciphertext.len() <= MAX_NOTE_LEN, PokerError::NoteTooLong);
+
+    let note = &mut ctx.accounts.note;
+    note.updated_at = Clock::get()?.unix_timestamp;
+    note.ciphertext = ciphertext;
+
+    Ok(())
+}
@@ -1,10 +1,64 @@
+// The `#[program]` expansion still calls `AccountInfo::realloc` for IDL buffers.
+#![allow(deprecated)]
+
+#[cfg(feature = "solana")]
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::system_instruction;
 
+#[cfg(feature = "solana")]
 declare_id!("CEDDEA8Z7kmVL2199EgKMAm4JBYpAPZtCvtnvE1kiaBH");
 
-const MAX_PLAYERS: usize = 6;
+// Emits one of the event structs in `events`, or with `compact-events` the
+// same struct wrapped in a `GameEvent`. Defined ahead of the modules so they
+// can all use it.
+#[cfg(feature = "solana")]
+macro_rules! emit_event {
+    ($event:ident { $($field:tt)* }) => {{
+        #[cfg(not(feature = "compact-events"))]
+        emit!($event { $($field)* });
+        #[cfg(feature = "compact-events")]
+        emit!($crate::events::GameEvent {
+            version: $crate::constants::EVENT_SCHEMA_VERSION,
+            body: $crate::events::GameEventBody::$event($event { $($field)* }),
+        });
+    }};
+}
 
+// Everything but `constants`, `rules` and `wasm` is the on-chain program;
+// without the `solana` feature the crate is a plain Rust rules library.
+#[cfg(feature = "client")]
+pub mod client;
+pub mod constants;
+#[cfg(feature = "solana")]
+mod engine;
+#[cfg(feature = "solana")]
+pub mod errors;
+#[cfg(feature = "solana")]
+pub mod events;
+#[cfg(feature = "export")]
+pub mod export;
+#[cfg(feature = "solana")]
+pub mod instructions;
+pub mod rules;
+#[cfg(feature = "solana")]
+pub mod state;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+pub use constants::*;
+#[cfg(feature = "solana")]
+pub use errors::*;
+#[cfg(feature = "solana")]
+pub use events::*;
+#[cfg(feature = "solana")]
+pub use instructions::*;
+#[cfg(feature = "solana")]
+pub use state::*;
+
+// Other programs build on tables through the `cpi` feature. The README's
+// "Building on tables" list names the instructions whose `cpi::accounts`
+// and arguments are held stable: change one of those only by adding a new
+// versioned entrypoint, as `bet_v1` was kept for `bet`.
+#[cfg(feature = "solana")]
 #[program]
 pub mod poker_game {
     use super::*;
@@ -13,375 +67,900 @@ pub mod poker_game {
         ctx: Context<InitializeGame>,
         small_blind: u64,
         big_blind: u64,
+        variant: GameVariant,
     ) -> Result<()> {
-        let game = &mut ctx.accounts.game;
+        instructions::initialize_game::handler(ctx, small_blind, big_blind, variant)
+    }
 
-        game.players = [Pubkey::default(); MAX_PLAYERS];
-        game.player_hands = [[0u8; 2]; MAX_PLAYERS];
-        game.community_cards = [0u8; 5];
-        game.pot = 0;
-        game.small_blind = small_blind;
-        game.big_blind = big_blind;
-        game.current_bet = 0;
-        game.current_turn = 0;
-        game.betting_round = 0;
-        game.is_active = false;
-        game.folded = [false; MAX_PLAYERS];
-        game.player_bets = [0; MAX_PLAYERS];
-        game.players_in_round = 0;
+    pub fn join_game(ctx: Context<JoinGame>, deposit: u64) -> Result<()> {
+        instructions::join_game::handler(ctx, deposit)
+    }
 
-        Ok(())
+    /// `join_game`, also taking the instructions sysvar that own-funds
+    /// tables check deposits against, the price gate and feed of
+    /// USD-priced tables and the memo accounts of tables that tag deposits.
+    pub fn join_game_v2(ctx: Context<JoinGameV2>, deposit: u64) -> Result<()> {
+        instructions::join_game::handler_v2(ctx, deposit)
     }
 
-    pub fn join_game(ctx: Context<JoinGame>, deposit: u64) -> Result<()> {
-        let game = &mut ctx.accounts.game;
-        let player = &ctx.accounts.player;
+    /// Creates the caller's profile: a display name and avatar URI that
+    /// tables show in place of the wallet key.
+    pub fn create_profile(ctx: Context<CreateProfile>, display_name: String, avatar_uri: String) -> Result<()> {
+        instructions::create_profile::handler(ctx, display_name, avatar_uri)
+    }
 
-        // Prevent joining a full game
-        let mut joined = false;
+    /// Moves the caller's profile to a new display name, releasing the old
+    /// one.
+    pub fn rename_profile(ctx: Context<RenameProfile>, display_name: String) -> Result<()> {
+        instructions::rename_profile::handler(ctx, display_name)
+    }
+
+    /// Deletes the caller's profile and releases its name, returning the
+    /// rent. Not allowed while the player is self-excluded, has a daily
+    /// limit, or has a limit change waiting out its cooling-off period.
+    pub fn close_profile(ctx: Context<CloseProfile>) -> Result<()> {
+        instructions::close_profile::handler(ctx)
+    }
 
-        for i in 0..MAX_PLAYERS {
-            if game.players[i] == Pubkey::default() {
-                game.players[i] = player.key();
-                joined = true;
-                game.players_in_round += 1;
-                break;
-            }
-        }
+    /// Sends a reaction to the table. Nothing is stored but the seat's
+    /// count, which allows `MAX_EMOTES_PER_STREET` per street.
+    pub fn send_emote(ctx: Context<SendEmote>, emote: Emote) -> Result<()> {
+        instructions::send_emote::handler(ctx, emote)
+    }
 
-        require!(joined, PokerError::GameFull);
+    /// Sends the caller's cash winnings at this table to their `deposit`
+    /// account in an allowlisted staking `program` instead of their wallet;
+    /// the default program switches back to the wallet. Whoever settles the
+    /// hand passes the route, the global config, the deposit and the
+    /// program; a settlement without them pays the wallet.
+    pub fn set_payout_route(ctx: Context<SetPayoutRoute>, program: Pubkey, deposit: Pubkey) -> Result<()> {
+        instructions::set_payout_route::handler(ctx, program, deposit)
+    }
+
+    /// Sets the caller's own limits: the most they may buy in for per day
+    /// (0 for none) and a time before which they cannot join or top up.
+    /// Tighter settings apply at once; looser limits after a cooling-off
+    /// period, and an exclusion cannot be shortened.
+    pub fn set_player_limits(ctx: Context<SetPlayerLimits>, daily_buy_in: u64, excluded_until: i64) -> Result<()> {
+        instructions::set_player_limits::handler(ctx, daily_buy_in, excluded_until)
+    }
 
-        // Transfer SOL to game pot if deposit > 0
-        if deposit > 0 {
-            let ix = system_instruction::transfer(&player.key(), &game.key(), deposit);
-            anchor_lang::solana_program::program::invoke(
-                &ix,
-                &[player.to_account_info(), game.to_account_info()],
-            )?;
-            game.pot += deposit;
-        }
+    /// Registers the caller as a spectator at a table they are not seated
+    /// at, which lets them tip its players.
+    pub fn register_spectator(ctx: Context<RegisterSpectator>) -> Result<()> {
+        instructions::register_spectator::handler(ctx)
+    }
+
+    /// Tips a seated player up to `MAX_TIP` lamports from a registered
+    /// spectator, credited to their stack between hands at a cash table.
+    pub fn tip_player(ctx: Context<TipPlayer>, seat: u8, amount: u64) -> Result<()> {
+        instructions::tip_player::handler(ctx, seat, amount)
+    }
+
+    /// `tip_player`, counting the tip against the spectator's own deposit
+    /// limits and tagging it with a memo at tables that tag tips.
+    pub fn tip_player_v2(ctx: Context<TipPlayerV2>, seat: u8, amount: u64) -> Result<()> {
+        instructions::tip_player::handler_v2(ctx, seat, amount)
+    }
+
+    /// Tops up the caller's stack between hands at a cash table, within
+    /// their buy-in limits. Refused at own-funds and USD-priced tables.
+    pub fn add_chips(ctx: Context<AddChips>, amount: u64) -> Result<()> {
+        instructions::add_chips::handler(ctx, amount)
+    }
+
+    /// `add_chips`, also taking the accounts that own-funds, USD-priced and
+    /// memo-tagged tables check or tag top-ups with.
+    pub fn add_chips_v2(ctx: Context<AddChipsV2>, amount: u64) -> Result<()> {
+        instructions::add_chips::handler_v2(ctx, amount)
+    }
+
+    /// Gives up the caller's seat between hands at a cash table, paying
+    /// their stack back to their wallet and closing their `Seat`.
+    pub fn leave_table(ctx: Context<LeaveTable>) -> Result<()> {
+        instructions::leave_table::handler(ctx)
+    }
+
+    /// `leave_table`, also taking the memo accounts of tables that tag
+    /// withdrawals.
+    pub fn leave_table_v2(ctx: Context<LeaveTableV2>) -> Result<()> {
+        instructions::leave_table::handler_v2(ctx)
+    }
 
-        Ok(())
+    /// Adds `friend` to the caller's friends list, whom they can then invite
+    /// to tables they sit at.
+    pub fn add_friend(ctx: Context<EditFriends>, friend: Pubkey) -> Result<()> {
+        instructions::add_friend::handler(ctx, friend)
     }
 
+    /// Drops `friend` from the caller's friends list. Invitations already
+    /// sent stay open.
+    pub fn remove_friend(ctx: Context<EditFriends>, friend: Pubkey) -> Result<()> {
+        instructions::remove_friend::handler(ctx, friend)
+    }
+
+    /// Offers `invitee` a seat at this table. The host can invite anyone; a
+    /// seated player can invite their friends.
+    pub fn invite_player(ctx: Context<InvitePlayer>, invitee: Pubkey) -> Result<()> {
+        instructions::invite_player::handler(ctx, invitee)
+    }
+
+    /// Accepts an invitation, which then lets the invitee join without the
+    /// table's gatekeeper attestation.
+    pub fn accept_invitation(ctx: Context<AcceptInvitation>) -> Result<()> {
+        instructions::accept_invitation::handler(ctx)
+    }
+
+    /// Founds a home-game club owned by the caller.
+    pub fn create_club(ctx: Context<CreateClub>, name: String) -> Result<()> {
+        instructions::create_club::handler(ctx, name)
+    }
+
+    pub fn add_club_member(ctx: Context<AddClubMember>, member: Pubkey) -> Result<()> {
+        instructions::add_club_member::handler(ctx, member)
+    }
+
+    /// Takes `member` off the club's list; the owner or the member may call
+    /// this.
+    pub fn remove_club_member(ctx: Context<RemoveClubMember>, member: Pubkey) -> Result<()> {
+        instructions::remove_club_member::handler(ctx, member)
+    }
+
+    /// Brings a table into the host's club, which then seats only members
+    /// and collects the table's stats. Without a club, takes the table out.
+    pub fn set_table_club(ctx: Context<SetTableClub>) -> Result<()> {
+        instructions::set_table_club::handler(ctx)
+    }
+
+    /// Stores the caller's note on `opponent`. Notes are encrypted by the
+    /// client; the program never reads them.
+    pub fn create_note(ctx: Context<CreateNote>, opponent: Pubkey, ciphertext: Vec<u8>) -> Result<()> {
+        instructions::create_note::handler(ctx, opponent, ciphertext)
+    }
+
+    /// Replaces a note's contents, resizing the account to fit.
+    pub fn write_note(ctx: Context<WriteNote>, ciphertext: Vec<u8>) -> Result<()> {
+        instructions::write_note::handler(ctx, ciphertext)
+    }
+
+    /// Deletes a note and returns its rent to the owner.
+    pub fn delete_note(ctx: Context<DeleteNote>) -> Result<()> {
+        instructions::delete_note::handler(ctx)
+    }
+
+    /// Awards the caller a badge for an achievement they have earned: a
+    /// royal flush won at showdown with their profile passed, or first place
+    /// in the `tournament` given. Each kind is awarded once per player.
+    pub fn claim_achievement(ctx: Context<ClaimAchievement>, kind: Achievement) -> Result<()> {
+        instructions::claim_achievement::handler(ctx, kind)
+    }
+
+    /// Deals the next hand. Only a seated player, the host, the tournament
+    /// authority at a tournament table, or a dealer bot scoped to deal may
+    /// deal, and only between hands with at least `min_players` seats ready
+    /// to play. Dealer bots deal through `start_round_v2`.
     pub fn start_round(ctx: Context<StartGame>) -> Result<()> {
-        let game = &mut ctx.accounts.game;
+        instructions::start_round::handler(ctx)
+    }
 
-        require!(!game.is_active, PokerError::GameAlreadyStarted);
+    /// `start_round`, also taking the signer's `DealerBot` as `dealer_bot`
+    /// when it deals as a bot.
+    pub fn start_round_v2(ctx: Context<StartGameV2>) -> Result<()> {
+        instructions::start_round::handler_v2(ctx)
+    }
 
-        // Shuffle and deal cards
-        let clock = Clock::get()?;
-        let seed = clock.unix_timestamp as u64 + game.key().to_bytes()[0] as u64;
+    /// Lets `session_key` bet, call, check and fold for the caller's seat
+    /// for `minutes` (at most `MAX_SESSION_MINUTES`), so a client can act
+    /// without a wallet prompt each time. It can do nothing else, so it
+    /// cannot move chips off the table. `Pubkey::default()` ends the session.
+    pub fn authorize_session(ctx: Context<AuthorizeSession>, session_key: Pubkey, minutes: u16) -> Result<()> {
+        instructions::authorize_session::handler(ctx, session_key, minutes)
+    }
 
-        let mut deck: Vec<u8> = (0..52).collect();
-        pseudo_shuffle(&mut deck, seed);
+    #[instruction(discriminator = &BET_V2)]
+    pub fn bet(ctx: Context<PlayerAction>, amount: u64, ticket: ActionTicket) -> Result<()> {
+        instructions::bet::handler(ctx, amount, ticket)
+    }
 
-        // Reset folded and bets
-        game.folded = [false; MAX_PLAYERS];
-        game.player_bets = [0; MAX_PLAYERS];
-        game.pot = 0;
+    #[instruction(discriminator = &CALL_V2)]
+    pub fn call(ctx: Context<PlayerAction>, ticket: ActionTicket) -> Result<()> {
+        instructions::call::handler(ctx, ticket)
+    }
 
-        // Deal hole cards
-        let mut deck_index = 0;
-        for i in 0..MAX_PLAYERS {
-            if game.players[i] != Pubkey::default() {
-                game.player_hands[i][0] = deck[deck_index];
-                game.player_hands[i][1] = deck[deck_index + 1];
-                deck_index += 2;
-            }
-        }
+    #[instruction(discriminator = &FOLD_V2)]
+    pub fn fold(ctx: Context<PlayerAction>, ticket: ActionTicket) -> Result<()> {
+        instructions::fold::handler(ctx, ticket)
+    }
 
-        // Deal community cards
-        for i in 0..5 {
-            game.community_cards[i] = deck[deck_index];
-            deck_index += 1;
-        }
+    /// `bet` for clients that predate client nonces, under the discriminator
+    /// they were built with. Fails once the table deprecates
+    /// `LegacyEntrypoint::ActionsV1`.
+    #[instruction(discriminator = &BET_V1)]
+    pub fn bet_v1(ctx: Context<PlayerAction>, amount: u64, ticket: ActionTicketV1) -> Result<()> {
+        instructions::legacy_actions::bet_v1(ctx, amount, ticket)
+    }
 
-        game.is_active = true;
-        game.betting_round = 0;
-        game.current_turn = 0;
-        game.current_bet = game.big_blind; // Start betting at big blind
-        Ok(())
+    /// `call` for clients that predate client nonces; see `bet_v1`.
+    #[instruction(discriminator = &CALL_V1)]
+    pub fn call_v1(ctx: Context<PlayerAction>, ticket: ActionTicketV1) -> Result<()> {
+        instructions::legacy_actions::call_v1(ctx, ticket)
     }
 
-    pub fn bet(ctx: Context<PlayerAction>, amount: u64) -> Result<()> {
-        let game = &mut ctx.accounts.game;
-        let player = &ctx.accounts.player;
+    /// `fold` for clients that predate client nonces; see `bet_v1`.
+    #[instruction(discriminator = &FOLD_V1)]
+    pub fn fold_v1(ctx: Context<PlayerAction>, ticket: ActionTicketV1) -> Result<()> {
+        instructions::legacy_actions::fold_v1(ctx, ticket)
+    }
 
-        require!(game.is_active, PokerError::GameNotActive);
+    /// Switches an older instruction shape off (or back on) for this
+    /// table, once its clients have upgraded. Only the host can call this.
+    pub fn deprecate_entrypoint(
+        ctx: Context<ConfigureTable>,
+        entrypoint: LegacyEntrypoint,
+        deprecated: bool,
+    ) -> Result<()> {
+        instructions::deprecate_entrypoint::handler(ctx, entrypoint, deprecated)
+    }
 
-        let player_index = game
-            .players
-            .iter()
-            .position(|&p| p == player.key())
-            .ok_or(PokerError::PlayerNotInGame)?;
+    /// Pays the pot to the showdown winner. The account constraints tie the
+    /// payout wallet to the seat holding the best live hand. Profiles and
+    /// season entries of players dealt in may follow as remaining accounts
+    /// to update their lifetime stats, ratings and season points. Passing
+    /// any profile takes the profile address of every player dealt in, and
+    /// passing the season takes every such player's entry address.
+    pub fn reveal_winner<'info>(ctx: Context<'_, '_, 'info, 'info, RevealWinner<'info>>) -> Result<()> {
+        instructions::reveal_winner::handler(ctx)
+    }
 
-        require!(!game.folded[player_index], PokerError::PlayerFolded);
-        require!(player_index as u8 == game.current_turn, PokerError::NotPlayersTurn);
+    /// Flags the hand at showdown as disputed, within `dispute_window`
+    /// seconds of reaching it. The pot is held until the table's arbiter
+    /// rules, using the hand history as evidence.
+    pub fn dispute_hand(ctx: Context<DisputeHand>) -> Result<()> {
+        instructions::dispute_hand::handler(ctx)
+    }
 
-        require!(amount >= game.current_bet, PokerError::BetTooLow);
+    /// Arbiter's ruling on a disputed hand: `uphold` pays the showdown
+    /// winner as usual, otherwise the hand is voided and every seat gets
+    /// its contribution back. Takes player profiles and season entries like
+    /// `reveal_winner`.
+    pub fn resolve_dispute<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ResolveDispute<'info>>,
+        uphold: bool,
+    ) -> Result<()> {
+        instructions::resolve_dispute::handler(ctx, uphold)
+    }
 
-        game.player_bets[player_index] = amount;
-        game.pot += amount;
-        game.current_bet = amount;
+    /// Deals the next stud street once every live player has acted and
+    /// matched the current bet. The best showing hand acts first.
+    pub fn deal_street(ctx: Context<DealStreet>) -> Result<()> {
+        instructions::deal_street::handler(ctx)
+    }
 
-        // Advance turn
-        game.current_turn = next_active_player(&game.players, &game.folded, game.current_turn)?;
+    /// Crank for automated tables: performs whichever automatic step the
+    /// table is waiting on and does nothing when none is due. Deals the next
+    /// hand at auto-deal tables, deals the next stud street once betting
+    /// closes, or pays the showdown winner, whose wallet must be the first
+    /// remaining account; player profiles and season entries may follow it,
+    /// as for `reveal_winner`. When everyone left is all-in, the remaining
+    /// streets are run out and the hand goes straight to showdown. At
+    /// tables with dealer bots, bots crank through `advance_game_v2`, and
+    /// winnings go into a payout route only through it.
+    pub fn advance_game<'info>(ctx: Context<'_, '_, 'info, 'info, AdvanceGame<'info>>) -> Result<()> {
+        instructions::advance_game::handler(ctx)
+    }
 
-        Ok(())
+    /// `advance_game`, also taking the caller's `DealerBot` as `dealer_bot`
+    /// when it cranks as a bot, the winner's payout route accounts and the
+    /// memo accounts of tables that tag payouts or crank rewards.
+    pub fn advance_game_v2<'info>(ctx: Context<'_, '_, 'info, 'info, AdvanceGameV2<'info>>) -> Result<()> {
+        instructions::advance_game::handler_v2(ctx)
     }
 
-    pub fn call(ctx: Context<PlayerAction>) -> Result<()> {
-        let game = &mut ctx.accounts.game;
-        let player = &ctx.accounts.player;
+    /// Same as `advance_game_v2`, but reports what happened in the return
+    /// data instead of failing when there is nothing to do or the crank is
+    /// rate limited, so automation can tell an idle table from a real
+    /// failure.
+    pub fn try_advance<'info>(
+        ctx: Context<'_, '_, 'info, 'info, AdvanceGameV2<'info>>,
+    ) -> Result<AdvanceStatus> {
+        instructions::try_advance::handler(ctx)
+    }
 
-        require!(game.is_active, PokerError::GameNotActive);
+    /// The step an automation thread runs on a self-driving table: times
+    /// out a seat whose clock ran out, otherwise does what `try_advance`
+    /// does. Every account is fixed for the table and nothing is paid by
+    /// the caller, which is the thread's signer and collects the crank
+    /// rewards that fund it. The pot goes onto the winner's stack, so no
+    /// wallet has to be passed; deals need auto-deal switched on.
+    pub fn run_automation<'info>(
+        ctx: Context<'_, '_, 'info, 'info, AdvanceGameV2<'info>>,
+    ) -> Result<AdvanceStatus> {
+        instructions::run_automation::handler(ctx)
+    }
 
-        let player_index = game
-            .players
-            .iter()
-            .position(|&p| p == player.key())
-            .ok_or(PokerError::PlayerNotInGame)?;
+    /// Read-only: what `seat` may do right now, for clients to simulate
+    /// instead of reimplementing the betting rules.
+    pub fn get_legal_actions(ctx: Context<ViewGame>, seat: u8) -> Result<LegalActions> {
+        instructions::get_legal_actions::handler(ctx, seat)
+    }
 
-        require!(!game.folded[player_index], PokerError::PlayerFolded);
-        require!(player_index as u8 == game.current_turn, PokerError::NotPlayersTurn);
+    /// Read-only: the blinds in cents and the USD buy-in range in lamports,
+    /// at the price in the table's feed.
+    pub fn get_usd_stakes(ctx: Context<ViewUsdStakes>) -> Result<UsdStakes> {
+        instructions::get_usd_stakes::handler(ctx)
+    }
 
-        let to_call = game.current_bet.saturating_sub(game.player_bets[player_index]);
-        game.player_bets[player_index] += to_call;
-        game.pot += to_call;
+    /// Read-only: plays `action` for `seat` on a copy of the table and
+    /// returns where it would leave the pot and the turn, or the error the
+    /// real instruction would fail with. For clients to simulate before
+    /// asking the player to sign; nothing is written.
+    pub fn dry_run_action(
+        ctx: Context<ViewTable>,
+        seat: u8,
+        action: ProposedAction,
+        ticket: ActionTicket,
+    ) -> Result<ActionPreview> {
+        instructions::dry_run_action::handler(ctx, seat, action, ticket)
+    }
 
-        // Advance turn
-        game.current_turn = next_active_player(&game.players, &game.folded, game.current_turn)?;
+    /// Read-only: the pot and who put what into it this hand.
+    pub fn get_pot_breakdown(ctx: Context<ViewGame>) -> Result<PotBreakdown> {
+        instructions::get_pot_breakdown::handler(ctx)
+    }
 
-        Ok(())
+    /// Read-only: config, seats, hand state and the legal actions of the
+    /// seat on turn, so a client can draw the table from one call.
+    pub fn get_table_snapshot(ctx: Context<ViewTable>) -> Result<TableSnapshot> {
+        instructions::get_table_snapshot::handler(ctx)
     }
 
-    pub fn fold(ctx: Context<PlayerAction>) -> Result<()> {
-        let game = &mut ctx.accounts.game;
-        let player = &ctx.accounts.player;
+    /// Turns auto-deal on or off. While on, anyone can deal the next hand
+    /// through `advance_game` once `min_players` seats are ready and
+    /// `hand_delay` seconds have passed since the last hand.
+    pub fn configure_auto_deal(
+        ctx: Context<ConfigureTable>,
+        enabled: bool,
+        min_players: u8,
+        hand_delay: i64,
+    ) -> Result<()> {
+        instructions::configure_auto_deal::handler(ctx, enabled, min_players, hand_delay)
+    }
 
-        require!(game.is_active, PokerError::GameNotActive);
+    /// Registers `bot` as a dealer bot for the table, or changes the scopes
+    /// of one already registered. From the first registration on, only the
+    /// host, the seated players and bots scoped for the step may deal, time
+    /// out or settle through the crank instructions; a bot passes its
+    /// `DealerBot` as their `dealer_bot` account, which `start_round` and
+    /// `advance_game` take only in their `_v2` entrypoints.
+    pub fn register_dealer_bot(ctx: Context<RegisterDealerBot>, bot: Pubkey, scopes: BotScopes) -> Result<()> {
+        instructions::register_dealer_bot::handler(ctx, bot, scopes)
+    }
 
-        let player_index = game
-            .players
-            .iter()
-            .position(|&p| p == player.key())
-            .ok_or(PokerError::PlayerNotInGame)?;
+    /// Removes a dealer bot, refunding its rent to the host. Once the last
+    /// one goes, anyone may crank the table again.
+    pub fn revoke_dealer_bot(ctx: Context<RevokeDealerBot>) -> Result<()> {
+        instructions::revoke_dealer_bot::handler(ctx)
+    }
 
-        require!(!game.folded[player_index], PokerError::PlayerAlreadyFolded);
-        require!(player_index as u8 == game.current_turn, PokerError::NotPlayersTurn);
+    /// Acts for a player who let their turn clock run out. A remaining time
+    /// bank is spent first, extending the deadline by the whole balance;
+    /// after that the player checks when they owe nothing and folds
+    /// otherwise. Anyone may call this, unless the table has dealer bots.
+    pub fn force_timeout(ctx: Context<ForceTimeout>) -> Result<()> {
+        instructions::force_timeout::handler(ctx)
+    }
 
-        game.folded[player_index] = true;
-        game.players_in_round -= 1;
+    /// Voids a hand nobody has acted in for `ABANDONED_HAND_TIMEOUT` seconds
+    /// past the turn deadline, putting what every seat paid into the hand
+    /// back on its stack. Anyone may call this.
+    pub fn void_hand(ctx: Context<VoidHand>) -> Result<()> {
+        instructions::void_hand::handler(ctx)
+    }
 
-        // Check if only one player remains (winner)
-        if game.players_in_round == 1 {
-            game.is_active = false;
-        } else {
-            game.current_turn = next_active_player(&game.players, &game.folded, game.current_turn)?;
-        }
+    /// Returns a player who was sat out for timing out to the game from the
+    /// next hand.
+    pub fn sit_in(ctx: Context<SitIn>) -> Result<()> {
+        instructions::sit_in::handler(ctx)
+    }
 
-        Ok(())
+    /// Replaces the table rules, documented on `TableRules`. Only between hands.
+    pub fn configure_table_rules(
+        ctx: Context<ConfigureTable>,
+        action_slot_window: u64,
+        crank_slot_window: u64,
+        all_in_protection_hands: u8,
+        blind_double_interval: i64,
+        crank_reward: u64,
+        crank_reward_cap: u64,
+    ) -> Result<()> {
+        instructions::configure_table_rules::handler(ctx, action_slot_window, crank_slot_window, all_in_protection_hands, blind_double_interval, crank_reward, crank_reward_cap)
     }
 
-    pub fn reveal_winner(ctx: Context<RevealWinner>, winner: Pubkey) -> Result<()> {
-        // Immutable borrow at first
-        let game_key = ctx.accounts.game.key();
+    /// Maintenance crank: closes a cash table that has sat idle for
+    /// `STALE_TABLE_EPOCHS` epochs, paying its rent to the caller as the
+    /// incentive for keeping the program's accounts tidy. Anyone still
+    /// seated is cashed out first, with their wallet and `Seat` passed as
+    /// remaining accounts in seat order, and a season passed along drops
+    /// the table from its venues.
+    pub fn close_stale_table<'info>(ctx: Context<'_, '_, 'info, 'info, CloseStaleTable<'info>>) -> Result<()> {
+        instructions::close_stale_table::handler(ctx)
+    }
 
-        // Check game status & winner
-        let game = &ctx.accounts.game;
+    /// Creates or refreshes the caller's seat account after the tournament
+    /// seated them or moved them to this table. Cash players get theirs
+    /// from `join_game`.
+    pub fn claim_seat(ctx: Context<ClaimSeat>) -> Result<()> {
+        instructions::claim_seat::handler(ctx)
+    }
 
-        require!(game.is_active, PokerError::GameNotActive);
+    /// Opens a history account for the hand in progress. Betting actions and
+    /// the showdown are recorded into it when it is passed along.
+    pub fn open_hand_history(ctx: Context<OpenHandHistory>) -> Result<()> {
+        instructions::open_hand_history::handler(ctx)
+    }
 
-        let winner_index = game.players.iter()
-            .position(|&p| p == winner)
-            .ok_or(PokerError::PlayerNotInGame)?;
+    /// Creates one of the `STATS_SHARDS` counters that settlements write to.
+    /// Anyone may pay for them.
+    pub fn init_stats_shard(ctx: Context<InitStatsShard>, index: u8) -> Result<()> {
+        instructions::init_stats_shard::handler(ctx, index)
+    }
 
-        require!(!game.folded[winner_index], PokerError::PlayerFolded);
+    pub fn init_global_stats(ctx: Context<InitGlobalStats>) -> Result<()> {
+        instructions::init_global_stats::handler(ctx)
+    }
 
-        // Drop immutable borrow before mutably borrowing lamports
-        drop(game);
+    /// Creates the protocol-wide config. Only the program's upgrade
+    /// authority may, and it governs the config from then on.
+    pub fn init_global_config(ctx: Context<InitGlobalConfig>) -> Result<()> {
+        instructions::init_global_config::handler(ctx)
+    }
 
-        // Mutably borrow lamports from game and winner
-        let game_account_info = ctx.accounts.game.to_account_info();
-        let winner_account_info = ctx.accounts.winner.to_account_info();
+    /// Replaces the staking programs players may route winnings into, up to
+    /// `MAX_STAKING_PROGRAMS`. Routes to a program taken off the list pay
+    /// the wallet again.
+    pub fn configure_staking_allowlist(
+        ctx: Context<ConfigureStakingAllowlist>,
+        staking_programs: Vec<StakingProgram>,
+    ) -> Result<()> {
+        instructions::configure_staking_allowlist::handler(ctx, staking_programs)
+    }
 
-        **game_account_info.try_borrow_mut_lamports()? -= ctx.accounts.game.pot;
-        **winner_account_info.try_borrow_mut_lamports()? += ctx.accounts.game.pot;
+    /// Creates the protocol-wide leaderboard; the caller starts its seasons.
+    pub fn init_leaderboard(ctx: Context<InitLeaderboard>) -> Result<()> {
+        instructions::init_leaderboard::handler(ctx)
+    }
 
-        // Now mutably borrow game to update pot and status
-        let game = &mut ctx.accounts.game;
-        game.pot = 0;
-        game.is_active = false;
+    /// Creates a table's leaderboard. Anyone may pay for it.
+    pub fn init_table_leaderboard(ctx: Context<InitTableLeaderboard>) -> Result<()> {
+        instructions::init_table_leaderboard::handler(ctx)
+    }
 
-        Ok(())
+    /// Clears a leaderboard for a new season. The global board's authority
+    /// or a table's host can call this.
+    pub fn reset_leaderboard(ctx: Context<ResetLeaderboard>) -> Result<()> {
+        instructions::reset_leaderboard::handler(ctx)
     }
-    pub fn end_game(ctx: Context<EndGame>) -> Result<()> {
-        // Get AccountInfos first to avoid conflicting borrows
-        let game_account_info = ctx.accounts.game.to_account_info();
-        let signer_account_info = ctx.accounts.signer.to_account_info();
 
-        // Now get mutable borrow for the game state
-        let game = &mut ctx.accounts.game;
-        let signer = &ctx.accounts.signer;
+    /// Opens a league running from `starts_at` to `ends_at`. Players score
+    /// `weights` at the venues the authority adds, and the final standings
+    /// split the sponsored pool by `prize_shares`.
+    pub fn create_season(
+        ctx: Context<CreateSeason>,
+        id: u32,
+        starts_at: i64,
+        ends_at: i64,
+        weights: SeasonWeights,
+        prize_shares: [u16; SEASON_PAID_PLACES],
+    ) -> Result<()> {
+        instructions::create_season::handler(ctx, id, starts_at, ends_at, weights, prize_shares)
+    }
 
-        // Authorization check: only first player can end the game
-        require!(signer.key() == game.players[0], PokerError::NotAuthorized);
-        require!(game.is_active, PokerError::GameNotActive);
+    /// Lets a table or tournament earn points in the season.
+    pub fn add_season_venue(ctx: Context<AddSeasonVenue>, venue: Pubkey) -> Result<()> {
+        instructions::add_season_venue::handler(ctx, venue)
+    }
 
-        // Refund pot to signer if pot > 0
-        if game.pot > 0 {
-            **game_account_info.try_borrow_mut_lamports()? -= game.pot;
-            **signer_account_info.try_borrow_mut_lamports()? += game.pot;
-            game.pot = 0;
-        }
+    /// Adds lamports to a season's prize pool. Open to anyone until it ends.
+    pub fn fund_season(ctx: Context<FundSeason>, amount: u64) -> Result<()> {
+        instructions::fund_season::handler(ctx, amount)
+    }
 
-        // Reset game state
-        game.is_active = false;
-        game.players = [Pubkey::default(); MAX_PLAYERS];
-        game.player_hands = [[0u8; 2]; MAX_PLAYERS];
-        game.community_cards = [0u8; 5];
-        game.current_bet = 0;
-        game.current_turn = 0;
-        game.betting_round = 0;
-        game.folded = [false; MAX_PLAYERS];
-        game.player_bets = [0; MAX_PLAYERS];
-        game.players_in_round = 0;
+    /// Registers the caller for a season. Their entry then scores whenever
+    /// it rides along with a settlement at one of the season's tables.
+    pub fn join_season(ctx: Context<JoinSeason>) -> Result<()> {
+        instructions::join_season::handler(ctx)
+    }
 
-        Ok(())
+    /// Scores an entry's finish in a settled season tournament. Anyone can
+    /// call this, once per entry and tournament, until the season ends.
+    pub fn score_tournament_finish(ctx: Context<ScoreTournamentFinish>) -> Result<()> {
+        instructions::score_tournament_finish::handler(ctx)
     }
-}
 
-// Utility function to get next active player's turn
-fn next_active_player(players: &[Pubkey; MAX_PLAYERS], folded: &[bool; MAX_PLAYERS], current_turn: u8) -> Result<u8> {
-    let mut next = current_turn;
-    for _ in 0..MAX_PLAYERS {
-        next = (next + 1) % (MAX_PLAYERS as u8);
-        if players[next as usize] != Pubkey::default() && !folded[next as usize] {
-            return Ok(next);
-        }
+    /// Pays a player their share of the prize pool for their final standing.
+    pub fn claim_season_prize(ctx: Context<ClaimSeasonPrize>) -> Result<()> {
+        instructions::claim_season_prize::handler(ctx)
     }
-    Err(PokerError::NoActivePlayers.into())
-}
 
-fn pseudo_shuffle(deck: &mut Vec<u8>, seed: u64) {
-    let mut state = seed;
+    /// Crank: folds one shard's pending counts into `GlobalStats`. Tables
+    /// write to their own shard at settlement so they don't contend for the
+    /// global account.
+    pub fn merge_stats(ctx: Context<MergeStats>) -> Result<()> {
+        instructions::merge_stats::handler(ctx)
+    }
 
-    for i in (1..deck.len()).rev() {
-        state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
-        let j = (state % (i as u64 + 1)) as usize;
-        deck.swap(i, j);
+    /// Returns a hand history's rent to whoever opened it once
+    /// `HAND_HISTORY_RETENTION` seconds have passed. Anyone may call this.
+    pub fn close_hand_history(ctx: Context<CloseHandHistory>) -> Result<()> {
+        instructions::close_hand_history::handler(ctx)
     }
-}
 
-#[derive(Accounts)]
-pub struct InitializeGame<'info> {
-    #[account(init, payer = user, space = 8 + Game::LEN)]
-    pub game: Account<'info, Game>,
-    #[account(mut)]
-    pub user: Signer<'info>,
-    pub system_program: Program<'info, System>,
-}
+    /// Creates the table's hand archive. Anyone may pay for it.
+    pub fn init_hand_archive(ctx: Context<InitHandArchive>) -> Result<()> {
+        instructions::init_hand_archive::handler(ctx)
+    }
 
-#[derive(Accounts)]
-pub struct JoinGame<'info> {
-    #[account(mut)]
-    pub game: Account<'info, Game>,
-    #[account(mut)]
-    pub player: Signer<'info>,
-    pub system_program: Program<'info, System>,
-}
+    /// Appends a settled hand history to the table's archive tree, emits it
+    /// in `HandArchived` and closes the account, returning the rent to
+    /// whoever opened it without waiting out the retention period. Anyone
+    /// may call this.
+    pub fn archive_hand_history(ctx: Context<ArchiveHandHistory>) -> Result<()> {
+        instructions::archive_hand_history::handler(ctx)
+    }
 
-#[derive(Accounts)]
-pub struct StartGame<'info> {
-    #[account(mut)]
-    pub game: Account<'info, Game>,
-}
+    /// Read-only: whether `leaf` is the archive's entry at `index`, given
+    /// the sibling hashes from the leaf up.
+    pub fn verify_archived_hand(
+        ctx: Context<ViewArchive>,
+        leaf: [u8; 32],
+        index: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<bool> {
+        instructions::verify_archived_hand::handler(ctx, leaf, index, proof)
+    }
 
-#[derive(Accounts)]
-pub struct PlayerAction<'info> {
-    #[account(mut)]
-    pub game: Account<'info, Game>,
-    #[account(mut)]
-    pub player: Signer<'info>,
-    pub system_program: Program<'info, System>,
-}
+    /// Checks the table's bookkeeping: the pot holds exactly what was put into
+    /// the hand, a cash table's lamports cover its pot and stacks, and the
+    /// turn sits with a live seat. A failed check halts the table and emits
+    /// `TableHalted`. Anyone may call this.
+    pub fn assert_invariants(ctx: Context<AssertInvariants>) -> Result<()> {
+        instructions::assert_invariants::handler(ctx)
+    }
 
-#[derive(Accounts)]
-pub struct RevealWinner<'info> {
-    #[account(mut)]
-    pub game: Account<'info, Game>,
+    /// Lets a halted table deal again.
+    pub fn resume_table(ctx: Context<ResumeTable>) -> Result<()> {
+        instructions::resume_table::handler(ctx)
+    }
 
-    /// CHECK: This account is not validated by Anchor but is expected to be the winner’s wallet.
-    #[account(mut)]
-    pub winner: AccountInfo<'info>,
-}
+    /// Registers a hot key that can run routine table operations without the
+    /// owner key. `Pubkey::default()` revokes it.
+    pub fn set_operator(ctx: Context<ConfigureOwner>, operator: Pubkey) -> Result<()> {
+        instructions::set_operator::handler(ctx, operator)
+    }
 
-#[derive(Accounts)]
-pub struct EndGame<'info> {
-    #[account(mut)]
-    pub game: Account<'info, Game>,
+    /// Hands the table to a new owner key. The operator key is revoked.
+    pub fn transfer_ownership(ctx: Context<ConfigureOwner>, new_owner: Pubkey) -> Result<()> {
+        instructions::transfer_ownership::handler(ctx, new_owner)
+    }
 
-    #[account(mut)]
-    pub signer: Signer<'info>,
-}
+    /// Lets seated players dispute a showdown for `dispute_window` seconds
+    /// before it is paid, with `arbiter` ruling on disputes. A window of 0
+    /// turns disputes off.
+    pub fn configure_disputes(
+        ctx: Context<ConfigureDisputes>,
+        arbiter: Pubkey,
+        dispute_window: u32,
+    ) -> Result<()> {
+        instructions::configure_disputes::handler(ctx, arbiter, dispute_window)
+    }
 
+    /// Requires every new seat to present an attestation issued by the
+    /// `gatekeeper` program: an `Attestation` account (Anchor discriminator,
+    /// wallet, expiry). `Pubkey::default()` opens the table again.
+    pub fn configure_gatekeeper(ctx: Context<ConfigureTable>, gatekeeper: Pubkey) -> Result<()> {
+        instructions::configure_gatekeeper::handler(ctx, gatekeeper)
+    }
 
-#[account]
-pub struct Game {
-    pub players: [Pubkey; MAX_PLAYERS],
-    pub player_hands: [[u8; 2]; MAX_PLAYERS],
-    pub community_cards: [u8; 5],
-    pub pot: u64,
-    pub small_blind: u64,
-    pub big_blind: u64,
-    pub current_bet: u64,
-    pub current_turn: u8,
-    pub betting_round: u8,
-    pub is_active: bool,
-
-    pub folded: [bool; MAX_PLAYERS],
-    pub player_bets: [u64; MAX_PLAYERS],
-    pub players_in_round: u8,
-}
+    /// Takes `join_game` and `add_chips` deposits only from top-level
+    /// transactions that call nothing but this program, the System Program
+    /// and the Compute Budget program, so no deposit can be flash-borrowed.
+    /// Deposits then come through `join_game_v2` and `add_chips_v2` with
+    /// the instructions sysvar. Off by default.
+    pub fn configure_own_funds(ctx: Context<ConfigureTableState>, own_funds_only: bool) -> Result<()> {
+        instructions::configure_own_funds::handler(ctx, own_funds_only)
+    }
 
-impl Game {
-    pub const LEN: usize =
-        32 * MAX_PLAYERS +    // players: 6 * Pubkey
-        2 * MAX_PLAYERS +     // player_hands: 6 * 2 bytes
-        5 +                   // community_cards: 5 bytes
-        8 +                   // pot
-        8 +                   // small_blind
-        8 +                   // big_blind
-        8 +                   // current_bet
-        1 +                   // current_turn
-        1 +                   // betting_round
-        1 +                   // is_active
-        MAX_PLAYERS +         // folded (bool per player)
-        8 * MAX_PLAYERS +     // player_bets (u64 per player)
-        1;                    // players_in_round
-}
+    /// Tags the listed fund movements with an SPL Memo of the form
+    /// `<tag>:<movement>:<wallet>:<lamports>`, for operators whose
+    /// transactions must carry a reference. The instructions that move
+    /// those funds then pass the table's `MemoConfig` and the Memo program
+    /// as `memo_config` and `memo_program`, through the `_v2` entrypoints of
+    /// the stable ones, whose v1 entrypoints tag nothing; an empty list
+    /// turns memos off.
+    pub fn configure_memos(ctx: Context<ConfigureMemos>, tag: String, movements: Vec<FundMovement>) -> Result<()> {
+        instructions::configure_memos::handler(ctx, tag, movements)
+    }
 
-#[error_code]
-pub enum PokerError {
-    #[msg("Game is full.")]
-    GameFull,
-    #[msg("Game already started.")]
-    GameAlreadyStarted,
-    #[msg("Game is not active.")]
-    GameNotActive,
-    #[msg("Player not in game.")]
-    PlayerNotInGame,
-    #[msg("Player has already folded.")]
-    PlayerAlreadyFolded,
-    #[msg("Player has folded.")]
-    PlayerFolded,
-    #[msg("Not player's turn.")]
-    NotPlayersTurn,
-    #[msg("Bet amount is too low.")]
-    BetTooLow,
-    #[msg("No active players remaining.")]
-    NoActivePlayers,
-    #[msg("Not authorized to perform this action.")]
-    NotAuthorized,
+    /// Holds `join_game` and `add_chips` to a buy-in range in cents,
+    /// converted at the SOL/USD price in the Pyth `feed` no older than
+    /// `max_age` seconds; 0 leaves an end open. The feed must be a SOL/USD
+    /// update and is passed as `price_feed`; deposits then come through
+    /// `join_game_v2` and `add_chips_v2` with the table's `PriceGate` and
+    /// the feed. Prices with a confidence interval over 2% are refused.
+    /// `Pubkey::default()` as the feed lifts the range.
+    pub fn configure_usd_buy_in(
+        ctx: Context<ConfigureUsdBuyIn>,
+        feed: Pubkey,
+        min_buy_in_usd: u64,
+        max_buy_in_usd: u64,
+        max_age: i64,
+    ) -> Result<()> {
+        instructions::configure_usd_buy_in::handler(ctx, feed, min_buy_in_usd, max_buy_in_usd, max_age)
+    }
+
+    /// Only seats players whose profile rating is within `min_rating` and
+    /// `max_rating`; 0 leaves that end open, and both 0 lifts the gate.
+    pub fn configure_rating_gate(ctx: Context<ConfigureTable>, min_rating: u16, max_rating: u16) -> Result<()> {
+        instructions::configure_rating_gate::handler(ctx, min_rating, max_rating)
+    }
+
+    /// Sets how many seconds each player has to act.
+    pub fn configure_turn_timer(ctx: Context<ConfigureTable>, turn_timeout: i64) -> Result<()> {
+        instructions::configure_turn_timer::handler(ctx, turn_timeout)
+    }
+
+    /// Gives every seat a `time_bank` of extra seconds, topped back up every
+    /// `refill_hands` hands (never when zero).
+    pub fn configure_time_bank(
+        ctx: Context<ConfigureTableState>,
+        time_bank: i64,
+        refill_hands: u8,
+    ) -> Result<()> {
+        instructions::configure_time_bank::handler(ctx, time_bank, refill_hands)
+    }
+
+    /// Sets a mixed-game rotation, moving to the next variant every
+    /// `hands_per_variant` hands, or every orbit when zero. An empty list
+    /// returns the table to a single variant.
+    pub fn configure_rotation(
+        ctx: Context<ConfigureTableState>,
+        variants: Vec<GameVariant>,
+        hands_per_variant: u8,
+    ) -> Result<()> {
+        instructions::configure_rotation::handler(ctx, variants, hands_per_variant)
+    }
+
+    /// Grows the table account to hold an optional `extension`, with the
+    /// signer paying the extra rent. Only between hands.
+    pub fn enable_extension(ctx: Context<EnableExtension>, extension: TableExtension) -> Result<()> {
+        instructions::enable_extension::handler(ctx, extension)
+    }
+
+    /// Ends a hand nobody has acted in for `ABANDONED_HAND_TIMEOUT` seconds
+    /// past the turn deadline. Every seat's contribution goes back on its
+    /// stack, each stack is paid out to its wallet and the seats are
+    /// cleared; a hand still being played can't be ended, so the owner
+    /// can't call off a hand they're losing. Each seated player's wallet and
+    /// `Seat` follow as remaining accounts, in seat order.
+    pub fn end_game<'info>(ctx: Context<'_, '_, 'info, 'info, EndGame<'info>>) -> Result<()> {
+        instructions::end_game::handler(ctx)
+    }
+
+    pub fn create_blind_structure(
+        ctx: Context<CreateBlindStructure>,
+        levels: Vec<BlindLevel>,
+    ) -> Result<()> {
+        instructions::create_blind_structure::handler(ctx, levels)
+    }
+
+    pub fn create_tournament(
+        ctx: Context<CreateTournament>,
+        buy_in: u64,
+        starting_stack: u64,
+        small_blind: u64,
+        big_blind: u64,
+    ) -> Result<()> {
+        instructions::create_tournament::handler(ctx, buy_in, starting_stack, small_blind, big_blind)
+    }
+
+    /// Sets who may enter a freeroll (zero buy-in) tournament.
+    pub fn configure_freeroll(ctx: Context<ConfigureTournament>, entry_gate: EntryGate) -> Result<()> {
+        instructions::configure_freeroll::handler(ctx, entry_gate)
+    }
+
+    /// Switches the tournament to the shootout format: tables are never
+    /// balanced, and each table's winner advances to the next round.
+    pub fn configure_shootout(ctx: Context<ConfigureTournament>) -> Result<()> {
+        instructions::configure_shootout::handler(ctx)
+    }
+
+    /// Makes the tournament a progressive knockout: `bounty` lamports of every
+    /// buy-in are placed on the entrant's head instead of in the prize pool.
+    pub fn configure_bounty(ctx: Context<ConfigureTournament>, bounty: u64) -> Result<()> {
+        instructions::configure_bounty::handler(ctx, bounty)
+    }
+
+    /// Turns the tournament into a satellite that awards `seats` tickets to
+    /// the target tournament instead of paying out cash.
+    pub fn configure_satellite(ctx: Context<ConfigureSatellite>, seats: u8) -> Result<()> {
+        instructions::configure_satellite::handler(ctx, seats)
+    }
+
+    pub fn configure_payouts(
+        ctx: Context<ConfigureTournament>,
+        payout_structure: PayoutStructure,
+    ) -> Result<()> {
+        instructions::configure_payouts::handler(ctx, payout_structure)
+    }
+
+    /// Opens re-entry for the first `reentry_levels` blind levels, allowing each
+    /// player up to `max_reentries` additional buy-ins. Zero levels disables it.
+    pub fn configure_reentry(
+        ctx: Context<ConfigureTournament>,
+        reentry_levels: u8,
+        max_reentries: u8,
+    ) -> Result<()> {
+        instructions::configure_reentry::handler(ctx, reentry_levels, max_reentries)
+    }
+
+    /// Keeps registration open for the first `levels` blind levels after the
+    /// seat draw. Zero disables late registration.
+    pub fn configure_late_registration(
+        ctx: Context<ConfigureTournament>,
+        levels: u8,
+    ) -> Result<()> {
+        instructions::configure_late_registration::handler(ctx, levels)
+    }
+
+    pub fn register_tournament(ctx: Context<RegisterTournament>) -> Result<()> {
+        instructions::register_tournament::handler(ctx)
+    }
+
+    /// Registers for a freeroll. Entry is free, but depending on the entry
+    /// gate the player must hold an allowlist pass or a satellite ticket.
+    pub fn register_freeroll(ctx: Context<RegisterFreeroll>) -> Result<()> {
+        instructions::register_freeroll::handler(ctx)
+    }
+
+    /// Adds sponsor money to the prize pool. Open to anyone until the
+    /// tournament finishes; this is how freerolls are funded.
+    pub fn sponsor_tournament(ctx: Context<SponsorTournament>, amount: u64) -> Result<()> {
+        instructions::sponsor_tournament::handler(ctx, amount)
+    }
+
+    /// Grants a player a pass into an allowlist-gated freeroll.
+    pub fn grant_freeroll_pass(ctx: Context<GrantFreerollPass>, player: Pubkey) -> Result<()> {
+        instructions::grant_freeroll_pass::handler(ctx, player)
+    }
+
+    /// Registers into a running tournament during late registration, seating
+    /// the player at an open seat of the given table with a starting stack.
+    pub fn late_register(ctx: Context<LateRegister>) -> Result<()> {
+        instructions::late_register::handler(ctx)
+    }
+
+    pub fn add_tournament_table(ctx: Context<AddTournamentTable>) -> Result<()> {
+        instructions::add_tournament_table::handler(ctx)
+    }
+
+    /// Closes registration and seats every entrant. The tournament's tables
+    /// must be passed as writable remaining accounts, in registration order.
+    pub fn seat_draw<'info>(ctx: Context<'_, '_, 'info, 'info, SeatDraw<'info>>) -> Result<()> {
+        instructions::seat_draw::handler(ctx)
+    }
+
+    /// Schedules the tournament to start at `start_time` once at least
+    /// `min_entrants` have registered. After that anyone can start it, or
+    /// cancel it if the field is too small.
+    pub fn configure_schedule(
+        ctx: Context<ConfigureTournament>,
+        start_time: i64,
+        min_entrants: u8,
+    ) -> Result<()> {
+        instructions::configure_schedule::handler(ctx, start_time, min_entrants)
+    }
+
+    /// Starts a scheduled tournament with the seat draw. Anyone may call this
+    /// once the start time has passed; tables go in remaining accounts as for
+    /// `seat_draw`.
+    pub fn start_tournament<'info>(
+        ctx: Context<'_, '_, 'info, 'info, StartTournament<'info>>,
+    ) -> Result<()> {
+        instructions::start_tournament::handler(ctx)
+    }
+
+    /// Calls off a scheduled tournament that reached its start time without
+    /// enough entrants, opening refunds. Anyone may call this.
+    pub fn cancel_tournament(ctx: Context<StartTournament>) -> Result<()> {
+        instructions::cancel_tournament::handler(ctx)
+    }
+
+    /// Returns a player's buy-in from a cancelled tournament.
+    pub fn claim_refund(ctx: Context<ClaimRefund>) -> Result<()> {
+        instructions::claim_refund::handler(ctx)
+    }
+
+    /// Moves one player from the fuller table to the shorter one. Anyone can
+    /// call this between hands whenever the tables differ by two or more.
+    pub fn balance_tables(ctx: Context<MoveTournamentPlayers>) -> Result<()> {
+        instructions::balance_tables::handler(ctx)
+    }
+
+    /// Breaks a table by moving all of its players onto another one. Allowed
+    /// once the remaining field fits on one fewer table, which is also how the
+    /// final table is consolidated.
+    pub fn break_table(ctx: Context<BreakTable>) -> Result<()> {
+        instructions::break_table::handler(ctx)
+    }
+
+    /// Advances the last player at a shootout table to the next round and
+    /// retires the table. Permissionless.
+    pub fn advance_shootout_winner(ctx: Context<AdvanceShootoutWinner>) -> Result<()> {
+        instructions::advance_shootout_winner::handler(ctx)
+    }
+
+    /// Creates the next table of the following shootout round once every
+    /// table of the current round has produced its winner, seating its share
+    /// of the advancers with fresh starting stacks. Called once per table.
+    pub fn create_shootout_table(ctx: Context<CreateShootoutTable>) -> Result<()> {
+        instructions::create_shootout_table::handler(ctx)
+    }
+
+    /// Reports that a table has finished its hand-for-hand hand. Once every
+    /// table has, the next hand-for-hand round opens. Permissionless.
+    pub fn table_hand_finished(ctx: Context<TableHandFinished>) -> Result<()> {
+        instructions::table_hand_finished::handler(ctx)
+    }
+
+    /// Removes a busted player from their table. Permissionless once the
+    /// seat has no chips left and no hand is in progress.
+    pub fn eliminate_player(ctx: Context<EliminatePlayer>, seat: u8) -> Result<()> {
+        instructions::eliminate_player::handler(ctx, seat)
+    }
+
+    /// Buys an eliminated player back in during the re-entry window, seating
+    /// them at an open seat of the given table with a fresh starting stack.
+    pub fn re_enter(ctx: Context<ReEnter>) -> Result<()> {
+        instructions::re_enter::handler(ctx)
+    }
+
+    /// Pays out the prize pool once a single player remains. Finishers'
+    /// wallets are passed as writable remaining accounts in finishing order
+    /// (winner first), one per paid place.
+    pub fn settle_tournament<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SettleTournament<'info>>,
+    ) -> Result<()> {
+        instructions::settle_tournament::handler(ctx)
+    }
+
+    /// Proposes a deal between the players left at the final table, replacing
+    /// any earlier proposal. The proposer accepts it implicitly.
+    pub fn propose_deal(ctx: Context<ProposeDeal>, kind: DealKind) -> Result<()> {
+        instructions::propose_deal::handler(ctx, kind)
+    }
+
+    /// Accepts the proposed deal. The last acceptance settles the tournament:
+    /// pass the deal players' wallets in deal order, followed by the wallets
+    /// of already-eliminated finishers in the money, in finishing order.
+    pub fn accept_deal<'info>(ctx: Context<'_, '_, 'info, 'info, AcceptDeal<'info>>) -> Result<()> {
+        instructions::accept_deal::handler(ctx)
+    }
+
+    /// Turns down the proposed deal, withdrawing it for everyone. Any deal
+    /// player may decline, including one who already accepted.
+    pub fn decline_deal(ctx: Context<AcceptDeal>) -> Result<()> {
+        instructions::decline_deal::handler(ctx)
+    }
+
+    /// Issues a satellite survivor their ticket once the field is down to the
+    /// number of seats. The prize pool is split evenly between survivors: each
+    /// ticket escrows the target buy-in and any excess is paid out in lamports.
+    pub fn claim_ticket(ctx: Context<ClaimTicket>) -> Result<()> {
+        instructions::claim_ticket::handler(ctx)
+    }
+
+    /// Pays out the bounties a player collected once the tournament is over.
+    pub fn claim_bounty(ctx: Context<ClaimBounty>) -> Result<()> {
+        instructions::claim_bounty::handler(ctx)
+    }
 }
@@ -0,0 +1,80 @@
+#[cfg(feature = "solana")]
+use anchor_lang::prelude::*;
+
+use crate::rules::GameVariant;
+
+/// Fixed-limit bet size on `street`: the big blind for the first two
+/// streets, twice that after.
+pub fn fixed_limit_unit(big_blind: u64, street: u8) -> u64 {
+    if street < 2 {
+        big_blind
+    } else {
+        big_blind * 2
+    }
+}
+
+/// The only total a fixed-limit bet can make: the next multiple of the
+/// street's bet size, which also completes a stud bring-in.
+pub fn fixed_limit_raise_to(current_bet: u64, unit: u64) -> u64 {
+    let unit = unit.max(1);
+    (current_bet / unit + 1) * unit
+}
+
+/// Largest total a pot-limit bet can make: a call, then a raise of the pot
+/// after the call.
+pub fn pot_limit_max(current_bet: u64, pot: u64, to_call: u64) -> u64 {
+    current_bet + pot + to_call
+}
+
+/// Returned by `get_legal_actions`. Everything but the amounts is false
+/// when it isn't the seat's turn.
+#[cfg_attr(feature = "solana", derive(AnchorSerialize, AnchorDeserialize))]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct LegalActions {
+    pub on_turn: bool,
+    pub can_fold: bool,
+    pub can_check: bool,
+    pub can_call: bool,
+    pub call_amount: u64,
+    pub can_bet: bool,
+    pub min_bet: u64,
+    pub max_bet: u64,
+}
+
+/// What a seat that has put `street_bet` in this street and has `stack`
+/// behind may do against `current_bet`. `fixed_unit` is the street's
+/// fixed-limit bet size, ignored by the other variants.
+pub fn legal_actions(
+    variant: GameVariant,
+    on_turn: bool,
+    current_bet: u64,
+    pot: u64,
+    street_bet: u64,
+    stack: u64,
+    fixed_unit: u64,
+) -> LegalActions {
+    let to_call = current_bet.saturating_sub(street_bet);
+    let all_in = street_bet + stack;
+
+    // A stack too short for the smallest bet can still go all-in
+    let (min_bet, max_bet) = if variant.is_fixed_limit() {
+        let next = fixed_limit_raise_to(current_bet, fixed_unit).min(all_in);
+        (next, next)
+    } else if variant.is_pot_limit() {
+        (current_bet.min(all_in), all_in.min(pot_limit_max(current_bet, pot, to_call)))
+    } else {
+        (current_bet.min(all_in), all_in)
+    };
+
+    // A short stack calls all-in for what it has
+    LegalActions {
+        on_turn,
+        can_fold: on_turn,
+        can_check: on_turn && to_call == 0,
+        can_call: on_turn && to_call > 0 && stack > 0,
+        call_amount: to_call.min(stack),
+        can_bet: on_turn && max_bet >= min_bet,
+        min_bet,
+        max_bet,
+    }
+}
@@ -0,0 +1,18 @@
+use crate::constants::DECK_SIZE;
+
+/// Unshuffled deck, built on the stack.
+pub fn fresh_deck() -> [u8; DECK_SIZE] {
+    std::array::from_fn(|card| card as u8)
+}
+
+/// Deterministic Fisher-Yates shuffle driven by `seed`; dealing replays it
+/// from the seed stored on the game.
+pub fn pseudo_shuffle(deck: &mut [u8], seed: u64) {
+    let mut state = seed;
+
+    for i in (1..deck.len()).rev() {
+        state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+        let j = (state % (i as u64 + 1)) as usize;
+        deck.swap(i, j);
+    }
+}
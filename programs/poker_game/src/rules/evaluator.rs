@@ -0,0 +1,117 @@
+use crate::rules::GameVariant;
+
+/// `evaluate` score of a royal flush, the best hand there is.
+pub const ROYAL_FLUSH: u32 = (8 << 20) | 12;
+
+/// Best five-card score available to a player. Omaha must use exactly two
+/// hole cards and three board cards; Hold'em and stud use any five of seven.
+pub fn best_hand(variant: GameVariant, hole: &[u8], board: &[u8; 5]) -> u32 {
+    let mut cards = [0u8; 7];
+    match variant {
+        GameVariant::Holdem => {
+            cards[..2].copy_from_slice(hole);
+            cards[2..].copy_from_slice(board);
+            best_of_seven(cards)
+        }
+        GameVariant::Stud => {
+            cards.copy_from_slice(hole);
+            best_of_seven(cards)
+        }
+        GameVariant::Omaha => {
+            let mut best = 0;
+            for h1 in 0..4 {
+                for h2 in h1 + 1..4 {
+                    for b1 in 0..5 {
+                        for b2 in b1 + 1..5 {
+                            for b3 in b2 + 1..5 {
+                                best = best.max(evaluate(&[
+                                    hole[h1], hole[h2], board[b1], board[b2], board[b3],
+                                ]));
+                            }
+                        }
+                    }
+                }
+            }
+            best
+        }
+    }
+}
+
+fn best_of_seven(cards: [u8; 7]) -> u32 {
+    let mut best = 0;
+    // Choose the two cards to leave out
+    for skip_a in 0..7 {
+        for skip_b in skip_a + 1..7 {
+            let mut five = [0u8; 5];
+            let mut n = 0;
+            for (i, &card) in cards.iter().enumerate() {
+                if i != skip_a && i != skip_b {
+                    five[n] = card;
+                    n += 1;
+                }
+            }
+            best = best.max(evaluate(&five));
+        }
+    }
+    best
+}
+
+/// Scores a hand of up to five cards; higher is better. Cards are 0..52 with
+/// rank `card % 13` (0 = deuce, 12 = ace) and suit `card / 13`. The hand
+/// category sits above 20 bits of tie-breaking ranks, most significant first.
+/// Straights and flushes only count with five cards, so partial hands (stud
+/// up cards) rank by pairs and high cards.
+pub fn evaluate(cards: &[u8]) -> u32 {
+    let mut counts = [0u8; 13];
+    let mut suits = [0u8; 4];
+    for &card in cards.iter() {
+        counts[(card % 13) as usize] += 1;
+        suits[(card / 13) as usize] += 1;
+    }
+
+    let flush = suits.contains(&5);
+
+    let mut straight_high = None;
+    if cards.len() == 5 && counts.iter().all(|&n| n <= 1) {
+        let high = (0..13).rev().find(|&r| counts[r] > 0).unwrap_or_default();
+        let low = (0..13).find(|&r| counts[r] > 0).unwrap_or_default();
+        if high - low == 4 {
+            straight_high = Some(high as u32);
+        } else if counts[12] == 1 && counts[..4].iter().all(|&n| n == 1) {
+            // Wheel: A-2-3-4-5 plays as five-high
+            straight_high = Some(3);
+        }
+    }
+
+    // Ranks ordered by group size, then rank, e.g. full house KKK22
+    let mut ordered = [0u32; 5];
+    let mut n = 0;
+    for size in (1..=4).rev() {
+        for rank in (0..13).rev() {
+            if counts[rank] == size {
+                for _ in 0..size {
+                    ordered[n] = rank as u32;
+                    n += 1;
+                }
+            }
+        }
+    }
+    let kickers = ordered.iter().fold(0, |acc, &rank| (acc << 4) | rank);
+
+    let largest = counts.iter().max().copied().unwrap_or_default();
+    let pairs = counts.iter().filter(|&&n| n == 2).count();
+
+    let (category, tiebreak) = match (straight_high, flush) {
+        (Some(high), true) => (8, high),
+        _ if largest == 4 => (7, kickers),
+        _ if largest == 3 && pairs == 1 => (6, kickers),
+        (None, true) => (5, kickers),
+        (Some(high), false) => (4, high),
+        _ if largest == 3 => (3, kickers),
+        _ if pairs == 2 => (2, kickers),
+        _ if pairs == 1 => (1, kickers),
+        _ => (0, kickers),
+    };
+
+    (category << 20) | tiebreak
+}
@@ -0,0 +1,16 @@
+//! The rules the program enforces, as plain Rust: cards and shuffling, hand
+//! evaluation, bet sizing and player ratings. Nothing here depends on
+//! Anchor, so building with `--no-default-features` gives wasm and native
+//! clients (frontends, bots) the exact code the program runs.
+
+mod betting;
+mod cards;
+mod evaluator;
+mod rating;
+mod variant;
+
+pub use betting::*;
+pub use cards::*;
+pub use evaluator::*;
+pub use rating::*;
+pub use variant::*;
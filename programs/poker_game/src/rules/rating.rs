@@ -0,0 +1,38 @@
+/// Rating every profile starts from.
+pub const INITIAL_RATING: u16 = 1200;
+/// Most a single pot can move a pair of ratings.
+pub const RATING_K: u32 = 32;
+/// Pot size, in big blinds, that moves ratings by the full `RATING_K`.
+pub const FULL_WEIGHT_POT_BB: u64 = 50;
+
+// 1000 / (1 + 10^(d / 400)) for d = 0, 25, .., 800: the expected score of
+// the lower-rated side of a `d`-point gap, in thousandths
+const EXPECTED: [u32; 33] = [
+    500, 464, 429, 394, 360, 327, 297, 267, 240, 215, 192, 170, 151, 133, 118, 104, 91, 80, 70, 61, 53, 46, 40,
+    35, 31, 27, 23, 20, 17, 15, 13, 11, 10,
+];
+
+/// Elo expected score of `rating` against `opponent`, in thousandths. Gaps
+/// are taken in 25-point steps and capped at 800, all in integers so every
+/// platform gets the same answer.
+pub fn expected_score(rating: u16, opponent: u16) -> u32 {
+    let gap = opponent as i32 - rating as i32;
+    let step = (gap.unsigned_abs().min(800) / 25) as usize;
+    if gap >= 0 {
+        EXPECTED[step]
+    } else {
+        1000 - EXPECTED[step]
+    }
+}
+
+/// K for a pot: the full `RATING_K` from `FULL_WEIGHT_POT_BB` big blinds up,
+/// less for smaller pots, so blinds stolen preflop barely count.
+pub fn stake_k(pot: u64, big_blind: u64) -> u32 {
+    let pot_bb = (pot / big_blind.max(1)).clamp(1, FULL_WEIGHT_POT_BB);
+    (RATING_K as u64 * pot_bb / FULL_WEIGHT_POT_BB).max(1) as u32
+}
+
+/// Points `winner` takes from `loser` for one pot.
+pub fn rating_change(winner: u16, loser: u16, k: u32) -> u16 {
+    (k * (1000 - expected_score(winner, loser)) / 1000) as u16
+}
@@ -0,0 +1,54 @@
+#[cfg(feature = "solana")]
+use anchor_lang::prelude::*;
+
+#[cfg_attr(feature = "solana", derive(AnchorSerialize, AnchorDeserialize, InitSpace))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum GameVariant {
+    /// No-limit Texas Hold'em.
+    Holdem,
+    /// Pot-limit Omaha.
+    Omaha,
+    /// Fixed-limit seven-card stud. Uses the small blind as the bring-in and
+    /// the big blind as the small bet.
+    Stud,
+}
+
+impl GameVariant {
+    /// Reads the code `Game` stores; unknown codes read as Hold'em.
+    pub fn from_code(code: u8) -> Self {
+        match code {
+            1 => GameVariant::Omaha,
+            2 => GameVariant::Stud,
+            _ => GameVariant::Holdem,
+        }
+    }
+
+    /// Cards a player holds at showdown.
+    pub fn hole_cards(&self) -> usize {
+        match self {
+            GameVariant::Holdem => 2,
+            GameVariant::Omaha => 4,
+            GameVariant::Stud => 7,
+        }
+    }
+
+    /// Cards dealt to each player when the hand starts.
+    pub fn starting_cards(&self) -> usize {
+        match self {
+            GameVariant::Stud => 3,
+            _ => self.hole_cards(),
+        }
+    }
+
+    pub fn has_board(&self) -> bool {
+        !matches!(self, GameVariant::Stud)
+    }
+
+    pub fn is_pot_limit(&self) -> bool {
+        matches!(self, GameVariant::Omaha)
+    }
+
+    pub fn is_fixed_limit(&self) -> bool {
+        matches!(self, GameVariant::Stud)
+    }
+}
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+/// Milestones the program can detect on its own.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum Achievement {
+    /// Won a pot at showdown holding a royal flush.
+    RoyalFlush,
+    /// Finished first in a tournament.
+    TournamentWin,
+}
+
+impl Achievement {
+    pub fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+/// Soul-bound award at `["achievement", owner, kind]`. The program owns it
+/// and never moves it, and the address allows one per player and kind.
+#[account]
+pub struct AchievementBadge {
+    pub owner: Pubkey,
+    pub kind: Achievement,
+    pub awarded_at: i64,
+    pub bump: u8,
+}
+
+impl AchievementBadge {
+    pub const LEN: usize = 32 + 1 + 8 + 1;
+}
@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+
+/// Who rules on a table's disputed hands.
+#[account]
+pub struct TableArbiter {
+    pub game: Pubkey,
+    pub arbiter: Pubkey,
+    pub bump: u8,
+}
+
+impl TableArbiter {
+    pub const LEN: usize = 32 + 32 + 1;
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{MAX_CLUB_MEMBERS, MAX_CLUB_NAME_LEN};
+use crate::state::ProtocolCounters;
+
+/// A home-game club at `["club", owner, name]`. Tables that reference it
+/// only seat its members and add their pots to its stats.
+#[account]
+pub struct Club {
+    pub owner: Pubkey,
+    pub name: String,
+    /// Wallets the club seats, besides the owner.
+    pub members: Vec<Pubkey>,
+    /// `games` counts the tables brought into the club.
+    pub stats: ProtocolCounters,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl Club {
+    pub const LEN: usize =
+        32 +                          // owner
+        4 + MAX_CLUB_NAME_LEN +       // name
+        4 + 32 * MAX_CLUB_MEMBERS +   // members
+        ProtocolCounters::LEN +       // stats
+        8 +                           // created_at
+        1;                            // bump
+
+    pub fn admits(&self, wallet: &Pubkey) -> bool {
+        *wallet == self.owner || self.members.contains(wallet)
+    }
+}
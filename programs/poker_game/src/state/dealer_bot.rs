@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+/// A crank key the host vouches for, at `["dealer_bot", game, bot]`. Once a
+/// table registers any, outside keys crank it only through these, each for
+/// the steps its scopes allow; players list them with a `game` filter.
+#[account]
+pub struct DealerBot {
+    pub game: Pubkey,
+    pub bot: Pubkey,
+    pub scopes: BotScopes,
+    pub registered_at: i64,
+    pub bump: u8,
+}
+
+impl DealerBot {
+    pub const LEN: usize =
+        32 +    // game
+        32 +    // bot
+        3 +     // scopes
+        8 +     // registered_at
+        1;      // bump
+}
+
+/// The crank steps a registered bot may run.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BotScopes {
+    /// Deal hands and streets.
+    pub deal: bool,
+    /// Run out a stalled seat's clock.
+    pub time_out: bool,
+    /// Pay out or refund a finished hand.
+    pub settle: bool,
+}
+
+impl BotScopes {
+    pub fn allows(&self, step: CrankStep) -> bool {
+        match step {
+            CrankStep::Deal => self.deal,
+            CrankStep::TimeOut => self.time_out,
+            CrankStep::Settle => self.settle,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum CrankStep {
+    Deal,
+    TimeOut,
+    Settle,
+}
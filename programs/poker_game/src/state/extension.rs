@@ -0,0 +1,67 @@
+use std::cell::RefMut;
+use std::ops::Range;
+
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+use crate::constants::DECK_SIZE;
+use crate::state::Game;
+
+/// Optional regions `enable_extension` appends after the `Game` struct, so a
+/// table only pays rent for the features it turns on. Each region sits at a
+/// fixed offset; enabling one grows the account to cover it and every
+/// region before it.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TableExtension {
+    /// The shuffled deck, so later streets deal from it instead of
+    /// reshuffling from the seed.
+    StoredDeck,
+}
+
+impl TableExtension {
+    const ALL: [TableExtension; 1] = [TableExtension::StoredDeck];
+
+    pub fn bit(self) -> u8 {
+        1 << self as u8
+    }
+
+    /// Byte range of the region, counted from the end of `Game`.
+    pub fn range(self) -> Range<usize> {
+        match self {
+            TableExtension::StoredDeck => 0..DECK_SIZE,
+        }
+    }
+
+    /// Account size covering every extension in `mask`.
+    pub fn account_len(mask: u8) -> usize {
+        let end = Self::ALL
+            .iter()
+            .filter(|e| mask & e.bit() != 0)
+            .map(|e| e.range().end)
+            .max()
+            .unwrap_or(0);
+        8 + Game::LEN + end
+    }
+
+    /// The extension's region in `tail`, if the table has it enabled.
+    pub fn region<'a>(self, game: &Game, tail: &'a mut [u8]) -> Option<&'a mut [u8]> {
+        if game.extensions & self.bit() == 0 {
+            return None;
+        }
+        tail.get_mut(self.range())
+    }
+}
+
+/// Loads the game mutably together with the extension regions after it.
+pub fn load_extended<'a>(loader: &'a AccountLoader<Game>) -> Result<(RefMut<'a, Game>, RefMut<'a, [u8]>)> {
+    let info: &AccountInfo = loader.as_ref();
+    let data = info.try_borrow_mut_data()?;
+    require!(
+        data.len() >= 8 + Game::LEN && data[..8] == *Game::DISCRIMINATOR,
+        anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch
+    );
+
+    let (head, tail) = RefMut::map_split(data, |d| d.split_at_mut(8 + Game::LEN));
+    let game = RefMut::map(head, |h| bytemuck::from_bytes_mut(&mut h[8..]));
+    Ok((game, tail))
+}
@@ -0,0 +1,931 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{LAST_STUD_STREET, MAX_HOLE_CARDS, MAX_PLAYERS};
+use crate::engine::{next_active_player, showdown_scores};
+use crate::errors::PokerError;
+use crate::rules::{fixed_limit_raise_to, fixed_limit_unit, legal_actions, pot_limit_max};
+use crate::state::TableConfig;
+
+pub use crate::rules::{GameVariant, LegalActions};
+
+/// Hot per-hand state, written by every action. Settings the host changes
+/// between hands live in the table's `TableConfig`, which hand instructions
+/// only read.
+///
+/// Loaded zero-copy: fields are laid out by hand, eight-byte fields first,
+/// with no implicit padding. Enums are stored as their `u8` codes behind
+/// accessors and flags as `0`/`1` bytes.
+#[account(zero_copy)]
+pub struct Game {
+    pub pot: u64,
+    pub small_blind: u64,
+    pub big_blind: u64,
+    pub current_bet: u64,
+    pub player_bets: [u64; MAX_PLAYERS],
+    /// Chips behind per seat: tournament chips, or the lamports a cash
+    /// player deposited and has not yet put in the pot.
+    pub stacks: [u64; MAX_PLAYERS],
+    pub ante: u64,
+    /// Shuffle seed of the current hand, kept so later streets deal from the same deck.
+    pub deck_seed: u64,
+    /// Unix time after which the player to act can be timed out.
+    pub turn_deadline: i64,
+    /// Remaining time bank per seat.
+    pub time_banks: [i64; MAX_PLAYERS],
+    /// Unix time the last hand finished.
+    pub hand_ended_at: i64,
+    /// Chips each seat has put into the current hand, refunded if it is voided.
+    pub contributions: [u64; MAX_PLAYERS],
+    /// Slot of each seat's last betting action.
+    pub last_action_slot: [u64; MAX_PLAYERS],
+    /// Client nonce of each seat's last betting action, or 0.
+    pub last_client_nonce: [u64; MAX_PLAYERS],
+    /// Slot of the last timeout or crank call.
+    pub last_crank_slot: u64,
+    /// Hands dealt at this table.
+    pub hand_number: u64,
+    /// Hand each seat last used disconnect protection in, or 0 if never.
+    pub last_protected_hand: [u64; MAX_PLAYERS],
+    /// Epoch of the last join or deal, used to find abandoned tables.
+    pub last_active_epoch: u64,
+    /// Crank rewards paid out of the current hand's pot.
+    pub crank_rewards_paid: u64,
+    /// Last hand whose pot was paid out or refunded.
+    pub settled_hand: u64,
+    /// Betting actions taken at this table; each signed action must quote it.
+    pub action_nonce: u64,
+    /// Bumped by every instruction that changes the table and carried on
+    /// every event, so clients can line events up with account fetches.
+    pub state_seq: u64,
+    /// When the current or last hand was dealt.
+    pub hand_started_at: i64,
+    /// Running totals over every hand whose pot was awarded.
+    pub stats: TableStats,
+    /// When the current hand reached showdown.
+    pub showdown_at: i64,
+
+    pub players: [Pubkey; MAX_PLAYERS],
+    /// Tournament this table belongs to, or the default key for cash games.
+    pub tournament: Pubkey,
+    /// Winner of the most recent pot, credited with knockouts.
+    pub last_winner: Pubkey,
+
+    pub player_hands: [[u8; MAX_HOLE_CARDS]; MAX_PLAYERS],
+    pub community_cards: [u8; 5],
+    pub current_turn: u8,
+    /// `GamePhase` code; read it through `phase()`.
+    pub phase: u8,
+    /// Street being bet on while `phase` is betting.
+    pub betting_street: u8,
+    pub folded: [u8; MAX_PLAYERS],
+    pub players_in_round: u8,
+    /// `GameVariant` code of the current hand; read it through `variant()`.
+    pub variant: u8,
+    /// Next undealt position in the deck.
+    pub deck_index: u8,
+    /// Whether each seat has acted since the last raise on this street.
+    pub acted: [u8; MAX_PLAYERS],
+    /// Position in the config's variant rotation, and hands dealt at it.
+    pub rotation_index: u8,
+    pub hands_in_variant: u8,
+    pub hands_since_refill: u8,
+    /// Seats timed out this hand and kept in as all-in under disconnect protection.
+    pub all_in_protected: [u8; MAX_PLAYERS],
+    /// Forced folds in a row per seat, cleared by any action the player takes.
+    pub consecutive_timeouts: [u8; MAX_PLAYERS],
+    /// Seats left out of new hands until the player calls `sit_in`.
+    pub sitting_out: [u8; MAX_PLAYERS],
+    /// Set when `assert_invariants` finds corrupted bookkeeping; no hand can
+    /// be dealt or played until the owner resumes the table.
+    pub halted: u8,
+    /// Set by `dispute_hand`; the pot waits for the table's arbiter.
+    pub disputed: u8,
+    /// Bitmask of the `TableExtension`s stored after this struct.
+    pub extensions: u8,
+    /// Bump of a PDA table (shootout rounds); 0 for keypair tables.
+    pub bump: u8,
+    /// Bitmask of the seats dealt into the current or last hand.
+    pub dealt_in: u8,
+    /// Seats that put chips in voluntarily on the first street this hand.
+    pub vpip: u8,
+    /// Seats that raised on the first street this hand.
+    pub pfr: u8,
+    /// Seats whose cash winnings go to their `PayoutRoute` instead of
+    /// their wallet.
+    pub escrow_seats: u8,
+    /// Set while the table's config takes only own funds or holds buy-ins
+    /// to a USD range. Those checks need accounts only `add_chips_v2`
+    /// takes, so `add_chips` is refused.
+    pub gated_top_ups: u8,
+    /// Zeroed room for new fields; also pads the account to eight bytes.
+    pub reserved: [u8; 57],
+}
+
+// Accounts are created through a CPI, which caps them at 10 KiB
+const _: () = assert!(8 + Game::LEN <= 10_240);
+
+impl Game {
+    pub const LEN: usize = std::mem::size_of::<Game>();
+
+    pub fn next_seq(&mut self) {
+        self.state_seq += 1;
+    }
+
+    pub fn phase(&self) -> GamePhase {
+        GamePhase::from_code(self.phase, self.betting_street)
+    }
+
+    pub fn variant(&self) -> GameVariant {
+        GameVariant::from_code(self.variant)
+    }
+
+    pub fn is_tournament_table(&self) -> bool {
+        self.tournament != Pubkey::default()
+    }
+
+    pub fn seated_count(&self) -> usize {
+        self.players.iter().filter(|&&p| p != Pubkey::default()).count()
+    }
+
+    /// Whether a seat can be dealt in: occupied, not sitting out and
+    /// holding chips.
+    pub fn is_ready(&self, seat: usize) -> bool {
+        self.players[seat] != Pubkey::default() && self.sitting_out[seat] == 0 && self.stacks[seat] > 0
+    }
+
+    pub fn ready_count(&self) -> usize {
+        (0..MAX_PLAYERS).filter(|&i| self.is_ready(i)).count()
+    }
+
+    /// True when auto-deal is on and the next hand is due.
+    pub fn auto_deal_ready(&self, config: &TableConfig, now: i64) -> bool {
+        config.auto_deal != 0
+            && self.phase() == GamePhase::WaitingForPlayers
+            && self.ready_count() >= config.min_players as usize
+            && now >= self.hand_ended_at + config.hand_delay
+    }
+
+    /// True while a hand is being played, from the deal until the pot is paid.
+    pub fn in_hand(&self) -> bool {
+        !matches!(self.phase(), GamePhase::WaitingForPlayers | GamePhase::Finished)
+    }
+
+    pub fn is_betting(&self) -> bool {
+        matches!(self.phase(), GamePhase::Betting { .. })
+    }
+
+    /// Current betting street; 0 outside the betting phase.
+    pub fn street(&self) -> u8 {
+        match self.phase() {
+            GamePhase::Betting { street } => street,
+            _ => 0,
+        }
+    }
+
+    /// Moves the hand to `next`, rejecting transitions the lifecycle doesn't allow.
+    pub fn set_phase(&mut self, next: GamePhase) -> Result<()> {
+        require!(self.phase().can_become(next), PokerError::InvalidPhaseTransition);
+        if next == GamePhase::Showdown {
+            self.showdown_at = Clock::get()?.unix_timestamp;
+        }
+        (self.phase, self.betting_street) = next.code();
+        Ok(())
+    }
+
+    /// A showdown pot waits out the dispute window, and a disputed one
+    /// waits for the arbiter.
+    pub fn check_settlement(&self, config: &TableConfig, now: i64) -> Result<()> {
+        require!(self.disputed == 0, PokerError::HandDisputed);
+        require!(
+            now >= self.showdown_at + config.dispute_window as i64,
+            PokerError::DisputeWindowOpen
+        );
+        Ok(())
+    }
+
+    /// Moves to showdown once a single player is left, or once betting on the
+    /// final street has closed. Called after every betting action.
+    pub fn end_of_action(&mut self) -> Result<()> {
+        debug_assert!(self.ledger_balanced());
+
+        // Nobody left to win the pot: everyone gets their chips back
+        if self.live_count() == 0 {
+            return self.refund_hand();
+        }
+
+        let last_street = match self.variant() {
+            GameVariant::Stud => LAST_STUD_STREET,
+            _ => 0,
+        };
+        if self.players_in_round <= 1 || (self.betting_complete() && self.street() >= last_street) {
+            self.set_phase(GamePhase::Showdown)?;
+        }
+        Ok(())
+    }
+
+    /// Seat holding the best live hand, the lowest seat on a tie.
+    pub fn showdown_winner(&self) -> Option<usize> {
+        let scores = showdown_scores(self);
+        (0..MAX_PLAYERS)
+            .filter(|&i| scores[i].is_some())
+            .max_by_key(|&i| (scores[i], MAX_PLAYERS - i))
+    }
+
+    /// Wallet of the showdown winner's seat.
+    pub fn showdown_winner_wallet(&self) -> Option<Pubkey> {
+        self.showdown_winner().map(|seat| self.players[seat])
+    }
+
+    /// Whether the pot holds everything put into the current hand, less the
+    /// crank rewards already paid out of it.
+    pub fn ledger_balanced(&self) -> bool {
+        !self.in_hand()
+            || self.pot + self.crank_rewards_paid == self.contributions.iter().sum::<u64>()
+    }
+
+    /// Whether the live-seat count is right and, while betting, the turn
+    /// is with a live seat.
+    pub fn seats_consistent(&self) -> bool {
+        if !self.in_hand() {
+            return true;
+        }
+        let seat = self.current_turn as usize;
+        self.players_in_round as usize == self.live_count()
+            && (!self.is_betting()
+                || (seat < MAX_PLAYERS && self.players[seat] != Pubkey::default() && self.folded[seat] == 0))
+    }
+
+    /// Seats still contesting the current hand.
+    pub fn live_count(&self) -> usize {
+        (0..MAX_PLAYERS)
+            .filter(|&i| self.folded[i] == 0 && self.players[i] != Pubkey::default())
+            .count()
+    }
+
+    /// Settles the current hand without a winner by putting each seat's
+    /// contribution back on its stack.
+    pub fn refund_hand(&mut self) -> Result<()> {
+        self.mark_settled()?;
+        for seat in 0..MAX_PLAYERS {
+            let refund = self.contributions[seat].min(self.pot);
+            self.stacks[seat] += refund;
+            self.pot -= refund;
+        }
+        self.contributions = [0; MAX_PLAYERS];
+        self.player_bets = [0; MAX_PLAYERS];
+        self.current_bet = 0;
+        self.players_in_round = 0;
+        self.set_phase(GamePhase::WaitingForPlayers)
+    }
+
+    /// Empties `seat` between hands, returning the stack its owner takes
+    /// with them.
+    pub fn vacate_seat(&mut self, seat: usize) -> u64 {
+        let stack = self.stacks[seat];
+        self.players[seat] = Pubkey::default();
+        self.stacks[seat] = 0;
+        self.time_banks[seat] = 0;
+        self.last_action_slot[seat] = 0;
+        self.last_client_nonce[seat] = 0;
+        self.last_protected_hand[seat] = 0;
+        self.all_in_protected[seat] = 0;
+        self.consecutive_timeouts[seat] = 0;
+        self.sitting_out[seat] = 0;
+        self.escrow_seats &= !(1 << seat);
+        self.players_in_round = self.players_in_round.saturating_sub(1);
+        stack
+    }
+
+    /// Records that the current hand's pot has been paid out or refunded, so
+    /// no payout path can run twice for the same hand.
+    pub fn mark_settled(&mut self) -> Result<()> {
+        require!(self.settled_hand != self.hand_number, PokerError::AlreadySettled);
+        self.settled_hand = self.hand_number;
+        Ok(())
+    }
+
+    /// Accepts a betting action only if it was signed for this exact point
+    /// in the game, so a relayed action can't be replayed later.
+    pub fn consume_action(&mut self, seat: usize, ticket: &ActionTicket) -> Result<()> {
+        require!(
+            ticket.hand_number == self.hand_number
+                && ticket.street == self.street()
+                && ticket.nonce == self.action_nonce,
+            PokerError::StaleAction
+        );
+        self.action_nonce += 1;
+        self.last_client_nonce[seat] = ticket.client_nonce;
+        Ok(())
+    }
+
+    /// Notes a voluntary bet or call on the first street for the seat's
+    /// VPIP and PFR counts.
+    pub fn note_first_street(&mut self, seat: usize, added: u64, raised: bool) {
+        if self.street() == 0 {
+            self.vpip |= u8::from(added > 0) << seat;
+            self.pfr |= u8::from(raised) << seat;
+        }
+    }
+
+    /// Puts `seat` in for a street total of `amount` under the variant's
+    /// betting limits, returning the chips added. The state change behind
+    /// `bet`, shared with `dry_run_action`.
+    pub fn apply_bet(&mut self, seat: usize, amount: u64) -> Result<u64> {
+        // Nobody can bet more than they have, but anyone can go all-in,
+        // even for less than the current bet
+        let all_in = self.player_bets[seat] + self.stacks[seat];
+        require!(amount <= all_in, PokerError::InsufficientStack);
+        require!(amount >= self.current_bet || amount == all_in, PokerError::BetTooLow);
+
+        // Pot limit: call, then raise by at most the pot after the call
+        let to_call = self.current_bet.saturating_sub(self.player_bets[seat]);
+        if self.variant().is_pot_limit() {
+            require!(
+                amount <= pot_limit_max(self.current_bet, self.pot, to_call),
+                PokerError::BetExceedsPotLimit
+            );
+        }
+
+        // Fixed limit: raise to the next multiple of the street's bet size,
+        // which also completes a stud bring-in to a full small bet, or go
+        // all-in short of it
+        if self.variant().is_fixed_limit() {
+            let raise_to = fixed_limit_raise_to(self.current_bet, self.fixed_limit_bet());
+            require!(
+                amount == raise_to || (amount == all_in && all_in < raise_to),
+                PokerError::InvalidFixedLimitBet
+            );
+        }
+
+        // `amount` is the player's total for the round; only the difference goes in
+        let added = amount - self.player_bets[seat];
+        let raised = amount > self.current_bet;
+        self.stacks[seat] -= added;
+
+        self.player_bets[seat] = amount;
+        self.contributions[seat] += added;
+        self.pot += added;
+        self.current_bet = self.current_bet.max(amount);
+        self.note_first_street(seat, added, raised);
+
+        // A raise reopens the action for everyone else
+        if raised {
+            self.acted = [0; MAX_PLAYERS];
+        }
+        self.acted[seat] = 1;
+        Ok(added)
+    }
+
+    /// Matches the current bet for `seat`, or puts the seat all-in when its
+    /// stack is short, returning the chips added; the state change behind
+    /// `call`.
+    pub fn apply_call(&mut self, seat: usize) -> Result<u64> {
+        let to_call = self.current_bet.saturating_sub(self.player_bets[seat]).min(self.stacks[seat]);
+        self.stacks[seat] -= to_call;
+
+        self.player_bets[seat] += to_call;
+        self.contributions[seat] += to_call;
+        self.pot += to_call;
+        self.acted[seat] = 1;
+        self.note_first_street(seat, to_call, false);
+        Ok(to_call)
+    }
+
+    /// Folds `seat`; the state change behind `fold`.
+    pub fn apply_fold(&mut self, seat: usize) {
+        self.folded[seat] = 1;
+        self.players_in_round = self.players_in_round.saturating_sub(1);
+    }
+
+    /// Hands the turn on after an action and closes the betting when it is
+    /// over. The last player left goes straight to showdown.
+    pub fn pass_action(&mut self, now: i64, config: &TableConfig) -> Result<()> {
+        if self.players_in_round > 1 {
+            let next = self.next_to_act(self.current_turn)?;
+            self.start_turn(next, now, config);
+        }
+        self.end_of_action()
+    }
+
+    /// True when `ticket` carries the same client nonce as the seat's last
+    /// action, i.e. it is an RPC retry of an action that already landed.
+    pub fn is_retry(&self, seat: usize, ticket: &ActionTicket) -> bool {
+        ticket.client_nonce != 0 && self.last_client_nonce[seat] == ticket.client_nonce
+    }
+
+    /// Passes the action to `seat` and restarts the turn clock.
+    pub fn start_turn(&mut self, seat: u8, now: i64, config: &TableConfig) {
+        self.current_turn = seat;
+        self.turn_deadline = now + config.turn_timeout;
+    }
+
+    /// Whether a live seat still has a decision to make: it has chips
+    /// behind and is not held all-in under disconnect protection.
+    pub fn can_act(&self, seat: usize) -> bool {
+        self.stacks[seat] > 0 && self.all_in_protected[seat] == 0
+    }
+
+    /// True once every live player who can still act has acted and matched
+    /// the current bet.
+    pub fn betting_complete(&self) -> bool {
+        (0..MAX_PLAYERS)
+            .filter(|&i| self.folded[i] == 0 && self.players[i] != Pubkey::default())
+            .all(|i| !self.can_act(i) || (self.acted[i] != 0 && self.player_bets[i] == self.current_bet))
+    }
+
+    /// Next live seat after `seat` that can still act, skipping players who
+    /// are all-in unless nobody else is left.
+    pub fn next_to_act(&self, seat: u8) -> Result<u8> {
+        let mut next = seat;
+        for _ in 0..MAX_PLAYERS {
+            next = next_active_player(&self.players, &self.folded, next)?;
+            if self.can_act(next as usize) {
+                return Ok(next);
+            }
+        }
+        next_active_player(&self.players, &self.folded, seat)
+    }
+
+    /// Whether a timed-out seat facing a bet may be treated as all-in
+    /// instead of folded under the table's disconnect protection rule.
+    pub fn all_in_protection_available(&self, config: &TableConfig, seat: usize) -> bool {
+        let every = config.rules.all_in_protection_hands as u64;
+        every > 0
+            && (self.last_protected_hand[seat] == 0
+                || self.hand_number >= self.last_protected_hand[seat] + every)
+    }
+
+    /// True when no more betting can happen: someone live is all-in and at
+    /// most one live player still has chips, having matched the bet.
+    pub fn all_in_runout(&self) -> bool {
+        let live = (0..MAX_PLAYERS).filter(|&i| self.players[i] != Pubkey::default() && self.folded[i] == 0);
+        let (mut live_count, mut with_chips) = (0, 0);
+        for i in live {
+            live_count += 1;
+            if self.can_act(i) {
+                if self.player_bets[i] < self.current_bet {
+                    return false;
+                }
+                with_chips += 1;
+            }
+        }
+        live_count > 1 && with_chips < live_count && with_chips <= 1
+    }
+
+    /// Mirrors the checks in `bet`, `call` and `fold`. Bet amounts are the
+    /// seat's total for the street, as `bet` takes them.
+    pub fn legal_actions(&self, seat: usize) -> LegalActions {
+        let on_turn = self.is_betting()
+            && self.halted == 0
+            && self.players[seat] != Pubkey::default()
+            && self.folded[seat] == 0
+            && self.current_turn as usize == seat;
+        legal_actions(
+            self.variant(),
+            on_turn,
+            self.current_bet,
+            self.pot,
+            self.player_bets[seat],
+            self.stacks[seat],
+            self.fixed_limit_bet(),
+        )
+    }
+
+    /// Fixed-limit bet size: the small bet on third and fourth street, the big bet after.
+    pub fn fixed_limit_bet(&self) -> u64 {
+        fixed_limit_unit(self.big_blind, self.street())
+    }
+}
+
+/// Where a table is in the hand lifecycle.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, PartialEq, Eq)]
+pub enum GamePhase {
+    /// Between hands.
+    WaitingForPlayers,
+    /// Cards are being dealt; only seen mid-instruction.
+    Dealing,
+    /// Players are acting on `street` (0 is the first betting round).
+    Betting { street: u8 },
+    /// Action is closed and the pot is waiting to be awarded.
+    Showdown,
+    /// The pot is being paid out; only seen mid-instruction.
+    Settling,
+    /// The table has been retired and will not deal again.
+    Finished,
+}
+
+impl GamePhase {
+    /// Reads the phase and betting street codes `Game` stores.
+    pub fn from_code(code: u8, street: u8) -> Self {
+        match code {
+            1 => GamePhase::Dealing,
+            2 => GamePhase::Betting { street },
+            3 => GamePhase::Showdown,
+            4 => GamePhase::Settling,
+            5 => GamePhase::Finished,
+            _ => GamePhase::WaitingForPlayers,
+        }
+    }
+
+    /// The phase code and betting street `Game` stores.
+    pub fn code(self) -> (u8, u8) {
+        match self {
+            GamePhase::WaitingForPlayers => (0, 0),
+            GamePhase::Dealing => (1, 0),
+            GamePhase::Betting { street } => (2, street),
+            GamePhase::Showdown => (3, 0),
+            GamePhase::Settling => (4, 0),
+            GamePhase::Finished => (5, 0),
+        }
+    }
+
+    pub fn can_become(self, next: GamePhase) -> bool {
+        use GamePhase::*;
+        matches!(
+            (self, next),
+            (WaitingForPlayers, Dealing)
+                | (WaitingForPlayers, Finished)
+                | (Dealing, Betting { .. })
+                | (Betting { .. }, Dealing)
+                | (Betting { .. }, Showdown)
+                | (Betting { .. }, WaitingForPlayers) // voided or reset
+                | (Showdown, Settling)
+                | (Showdown, WaitingForPlayers) // voided or reset
+                | (Settling, WaitingForPlayers)
+        )
+    }
+}
+
+/// Table-level options set by the host.
+#[zero_copy]
+#[derive(PartialEq, Eq, Default)]
+pub struct TableRules {
+    /// Minimum slots between two betting actions by the same seat; 0 disables.
+    pub action_slot_window: u64,
+    /// Minimum slots between timeout or crank calls on the table; 0 disables.
+    pub crank_slot_window: u64,
+    /// Cash tables only: seconds between blind doublings; 0 keeps the blinds fixed.
+    pub blind_double_interval: i64,
+    /// Lamports paid from a cash pot to whoever runs a timeout or crank step.
+    pub crank_reward: u64,
+    /// Most crank rewards a single hand can pay out.
+    pub crank_reward_cap: u64,
+    /// Disconnect protection: a timed-out player facing a bet stays in as
+    /// all-in for what they have committed, at most once per this many
+    /// hands; 0 disables.
+    pub all_in_protection_hands: u8,
+    pub padding: [u8; 7],
+}
+
+/// Table liveliness figures for lobby UIs, updated as each pot is awarded.
+#[zero_copy]
+#[derive(PartialEq, Eq, Default)]
+pub struct TableStats {
+    pub hands_played: u64,
+    /// Sum of every awarded pot, before anything was taken from it.
+    pub total_pot: u64,
+    /// Taken out of pots before payout; today that is crank rewards.
+    pub total_rake: u64,
+    /// Seconds from deal to payout, summed over hands.
+    pub total_duration: i64,
+}
+
+impl TableStats {
+    pub fn record(&mut self, pot: u64, rake: u64, duration: i64) {
+        self.hands_played += 1;
+        self.total_pot += pot;
+        self.total_rake += rake;
+        self.total_duration += duration.max(0);
+    }
+
+    pub fn average_pot(&self) -> u64 {
+        self.total_pot.checked_div(self.hands_played).unwrap_or_default()
+    }
+
+    pub fn average_duration(&self) -> i64 {
+        match self.hands_played {
+            0 => 0,
+            n => self.total_duration / n as i64,
+        }
+    }
+}
+
+/// Returned by `get_pot_breakdown`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct PotBreakdown {
+    pub pot: u64,
+    pub current_bet: u64,
+    /// Already taken out of the pot this hand.
+    pub crank_rewards_paid: u64,
+    pub street_bets: [u64; MAX_PLAYERS],
+    pub contributions: [u64; MAX_PLAYERS],
+}
+
+/// A betting action to try with `dry_run_action`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ProposedAction {
+    /// A street total, as `bet` takes it.
+    Bet { amount: u64 },
+    Call,
+    Fold,
+}
+
+/// Returned by `dry_run_action`: the table as it would stand after the
+/// action.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct ActionPreview {
+    pub phase: GamePhase,
+    pub pot: u64,
+    pub current_bet: u64,
+    /// The acting seat's stack and street total afterwards.
+    pub stack: u64,
+    pub street_bet: u64,
+    /// Seat to act next, or `None` once the street's betting has closed.
+    pub next_turn: Option<u8>,
+    /// What `next_turn` has to put in to call.
+    pub to_call: u64,
+}
+
+/// One seat as `get_table_snapshot` reports it.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SeatSnapshot {
+    /// Default when the seat is empty.
+    pub player: Pubkey,
+    pub stack: u64,
+    pub street_bet: u64,
+    pub contribution: u64,
+    pub time_bank: i64,
+    pub folded: bool,
+    pub sitting_out: bool,
+    pub all_in_protected: bool,
+}
+
+/// Returned by `get_table_snapshot`: everything a client needs to draw the
+/// table, in one call. Cards are left out.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct TableSnapshot {
+    pub state_seq: u64,
+    pub hand_number: u64,
+    pub phase: GamePhase,
+    pub variant: GameVariant,
+    pub halted: bool,
+    pub small_blind: u64,
+    pub big_blind: u64,
+    pub ante: u64,
+    pub pot: u64,
+    pub current_bet: u64,
+    pub current_turn: u8,
+    pub turn_deadline: i64,
+    pub seats: [SeatSnapshot; MAX_PLAYERS],
+    /// What the seat on turn may do; the flags are all false between hands.
+    pub legal_actions: LegalActions,
+
+    pub owner: Pubkey,
+    pub operator: Pubkey,
+    pub gatekeeper: Pubkey,
+    pub turn_timeout: i64,
+    pub time_bank: i64,
+    pub hand_delay: i64,
+    pub dispute_window: u32,
+    pub auto_deal: bool,
+    pub min_players: u8,
+    pub action_slot_window: u64,
+    pub crank_slot_window: u64,
+    pub blind_double_interval: i64,
+    pub crank_reward: u64,
+    pub crank_reward_cap: u64,
+    pub all_in_protection_hands: u8,
+}
+
+/// What a crank call did, or why it had nothing to do.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum AdvanceStatus {
+    /// Called again within the table's crank slot window.
+    RateLimited,
+    /// Between hands, and auto-deal is off or the next hand isn't due.
+    NotReadyToDeal,
+    /// Waiting on a player to act.
+    AwaitingAction,
+    HandDealt,
+    StreetDealt,
+    PotAwarded,
+    /// Nobody was left to win, so contributions went back on the stacks.
+    HandRefunded,
+    /// At showdown, but the dispute window is open or the hand is disputed.
+    SettlementHeld,
+    /// The seat to act ran out of time and was timed out.
+    TurnTimedOut,
+}
+
+/// Where in the game a betting action was signed. The game key is bound by
+/// the accounts; the nonce moves on with every action taken at the table.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct ActionTicket {
+    pub hand_number: u64,
+    pub street: u8,
+    pub nonce: u64,
+    /// Idempotency key picked by the client, or 0 for none. Resending an
+    /// action with the seat's last client nonce succeeds without acting again.
+    pub client_nonce: u64,
+}
+
+/// `ActionTicket` as clients sent it before client nonces, taken by the
+/// `_v1` betting entrypoints.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct ActionTicketV1 {
+    pub hand_number: u64,
+    pub street: u8,
+    pub nonce: u64,
+}
+
+impl From<ActionTicketV1> for ActionTicket {
+    fn from(ticket: ActionTicketV1) -> Self {
+        Self {
+            hand_number: ticket.hand_number,
+            street: ticket.street,
+            nonce: ticket.nonce,
+            client_nonce: 0,
+        }
+    }
+}
+
+/// Lockstep check of the betting engine against `legal_actions`: seeded
+/// random hands where every action, legal or not, is run through the same
+/// `apply_*` calls the handlers make, and the result has to agree with what
+/// the legal-actions view promised.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::program_stubs::{set_syscall_stubs, SyscallStubs};
+    use bytemuck::Zeroable;
+    use std::sync::Once;
+
+    const NOW: i64 = 1_700_000_000;
+    const HANDS: u32 = 400;
+    const MAX_STEPS: usize = 500;
+
+    /// `set_phase(Showdown)` reads the clock, which native builds don't have.
+    struct ClockStub;
+
+    impl SyscallStubs for ClockStub {
+        fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+            let clock = Clock { unix_timestamp: NOW, ..Clock::default() };
+            // SAFETY: `Clock::get` passes a pointer to its own `Clock`
+            unsafe { var_addr.cast::<Clock>().write(clock) };
+            0
+        }
+    }
+
+    fn stub_clock() {
+        static STUBS: Once = Once::new();
+        STUBS.call_once(|| {
+            set_syscall_stubs(Box::new(ClockStub));
+        });
+    }
+
+    /// mulberry32, so a failing seed replays exactly.
+    struct Rng(u32);
+
+    impl Rng {
+        fn next(&mut self) -> u32 {
+            self.0 = self.0.wrapping_add(0x6d2b_79f5);
+            let mut t = self.0;
+            t = (t ^ (t >> 15)).wrapping_mul(t | 1);
+            t ^= t.wrapping_add((t ^ (t >> 7)).wrapping_mul(t | 61));
+            t ^ (t >> 14)
+        }
+
+        fn below(&mut self, n: u64) -> u64 {
+            (self.next() as u64) % n.max(1)
+        }
+    }
+
+    /// Chips at the table: every stack plus the pot.
+    fn chips(game: &Game) -> u64 {
+        game.stacks.iter().sum::<u64>() + game.pot
+    }
+
+    /// A hand on its first street with random seats and stacks and the
+    /// blinds posted by the first two seats.
+    fn deal(rng: &mut Rng, variant: GameVariant) -> Game {
+        let mut game = Game::zeroed();
+        game.small_blind = 5;
+        game.big_blind = 10;
+        game.variant = variant as u8;
+        (game.phase, game.betting_street) = GamePhase::Betting { street: 0 }.code();
+
+        let seated = 2 + rng.below(MAX_PLAYERS as u64 - 1) as usize;
+        for seat in 0..seated {
+            game.players[seat] = Pubkey::new_from_array([seat as u8 + 1; 32]);
+            game.stacks[seat] = 1 + rng.below(400);
+        }
+        game.players_in_round = seated as u8;
+
+        for (seat, blind) in [(0, game.small_blind), (1, game.big_blind)] {
+            let posted = blind.min(game.stacks[seat]);
+            game.stacks[seat] -= posted;
+            game.player_bets[seat] = posted;
+            game.contributions[seat] = posted;
+            game.pot += posted;
+            game.current_bet = game.current_bet.max(posted);
+        }
+        game.current_turn = game.next_to_act(1).unwrap();
+        game
+    }
+
+    /// A bet total to try: mostly the edges of the legal range, sometimes
+    /// anything up to past the seat's all-in.
+    fn pick_amount(rng: &mut Rng, game: &Game, seat: usize, legal: &LegalActions) -> u64 {
+        let all_in = game.player_bets[seat] + game.stacks[seat];
+        match rng.below(5) {
+            0 => legal.min_bet,
+            1 => legal.max_bet,
+            2 => legal.min_bet + rng.below(legal.max_bet.saturating_sub(legal.min_bet) + 1),
+            3 => all_in + 1 + rng.below(20),
+            _ => rng.below(all_in + 20),
+        }
+    }
+
+    fn play_hand(seed: u32, variant: GameVariant) {
+        let mut rng = Rng(seed);
+        let config = TableConfig::zeroed();
+        let mut game = deal(&mut rng, variant);
+        let total = chips(&game);
+
+        let mut steps = 0;
+        while game.is_betting() && !game.betting_complete() {
+            steps += 1;
+            assert!(steps <= MAX_STEPS, "seed {seed}: hand never closed");
+            assert!(game.seats_consistent(), "seed {seed}: turn on a dead seat");
+
+            let seat = game.current_turn as usize;
+            let legal = game.legal_actions(seat);
+            assert!(legal.on_turn && legal.can_fold, "seed {seed}: seat {seat} not on turn");
+            for other in (0..MAX_PLAYERS).filter(|&i| i != seat) {
+                assert!(!game.legal_actions(other).on_turn, "seed {seed}: seat {other} also on turn");
+            }
+
+            match rng.below(4) {
+                0 => game.apply_fold(seat),
+                1 => {
+                    let added = game.apply_call(seat).unwrap();
+                    assert_eq!(added, legal.call_amount, "seed {seed}: call");
+                    assert_eq!(legal.can_check, added == 0 && legal.call_amount == 0, "seed {seed}: check");
+                }
+                _ => {
+                    let amount = pick_amount(&mut rng, &game, seat, &legal);
+                    let before = game;
+                    let allowed = legal.can_bet && (legal.min_bet..=legal.max_bet).contains(&amount);
+                    match game.apply_bet(seat, amount) {
+                        Ok(added) => {
+                            assert!(allowed, "seed {seed}: bet {amount} taken outside {legal:?}");
+                            assert_eq!(game.player_bets[seat], amount);
+                            assert_eq!(added, amount - before.player_bets[seat]);
+                        }
+                        Err(_) => {
+                            assert!(!allowed, "seed {seed}: bet {amount} refused inside {legal:?}");
+                            assert!(bytemuck::bytes_of(&before) == bytemuck::bytes_of(&game));
+                            continue;
+                        }
+                    }
+                }
+            }
+
+            game.pass_action(NOW, &config).unwrap();
+            assert!(game.ledger_balanced(), "seed {seed}: pot out of step with contributions");
+            assert!(game.seats_consistent(), "seed {seed}: live-seat count drifted");
+            assert_eq!(chips(&game), total, "seed {seed}: chips created or lost");
+        }
+
+        // The street closes only when the action is really over
+        match game.phase() {
+            GamePhase::Showdown => {
+                assert!(game.players_in_round <= 1 || game.betting_complete(), "seed {seed}: early showdown");
+                assert_eq!(game.showdown_at, NOW);
+            }
+            GamePhase::Betting { street } => {
+                assert!(game.betting_complete(), "seed {seed}: betting left open");
+                assert!(variant == GameVariant::Stud, "seed {seed}: street {street} left open");
+            }
+            phase => panic!("seed {seed}: hand ended in phase {:?}", phase.code()),
+        }
+    }
+
+    #[test]
+    fn no_limit_matches_legal_actions() {
+        stub_clock();
+        (0..HANDS).for_each(|seed| play_hand(seed, GameVariant::Holdem));
+    }
+
+    #[test]
+    fn pot_limit_matches_legal_actions() {
+        stub_clock();
+        (0..HANDS).for_each(|seed| play_hand(seed, GameVariant::Omaha));
+    }
+
+    #[test]
+    fn fixed_limit_matches_legal_actions() {
+        stub_clock();
+        (0..HANDS).for_each(|seed| play_hand(seed, GameVariant::Stud));
+    }
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_STAKING_PROGRAMS;
+
+/// Protocol-wide settings at `["global_config"]`, governed by the program's
+/// upgrade authority.
+#[account]
+pub struct GlobalConfig {
+    pub authority: Pubkey,
+    /// Programs players may route their winnings into.
+    pub staking_programs: Vec<StakingProgram>,
+    pub bump: u8,
+}
+
+impl GlobalConfig {
+    pub const LEN: usize =
+        32 +                                            // authority
+        4 + StakingProgram::LEN * MAX_STAKING_PROGRAMS + // staking_programs
+        1;                                              // bump
+
+    pub fn staking_program(&self, program: &Pubkey) -> Option<&StakingProgram> {
+        self.staking_programs.iter().find(|staking| staking.program == *program)
+    }
+}
+
+/// An allowlisted staking or yield program. Winnings are credited to the
+/// player's deposit account, owned by `program`, which is then called with
+/// `deposit_ix` followed by the amount as a little-endian u64 and the
+/// accounts `[table, deposit, wallet]`, the first two writable, to book
+/// them as a deposit.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct StakingProgram {
+    pub program: Pubkey,
+    /// Instruction discriminator of the program's deposit hook.
+    pub deposit_ix: [u8; 8],
+}
+
+impl StakingProgram {
+    pub const LEN: usize = 32 + 8;
+}
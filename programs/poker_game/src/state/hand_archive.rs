@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+use crate::constants::{HAND_ARCHIVE_DEPTH, MAX_HAND_ACTIONS};
+use crate::errors::PokerError;
+use crate::state::{GameVariant, HandAction, HandHistory};
+
+/// Append-only merkle tree of a table's archived hands, at
+/// `["hand_archive", game]`. Only the root and the rightmost path are kept;
+/// the leaves themselves are in the `HandArchived` events, and a proof built
+/// from them can be checked against `root` with `verify_archived_hand`.
+#[account]
+pub struct HandArchive {
+    pub game: Pubkey,
+    pub root: [u8; 32],
+    /// Leaves appended so far; the next hand archived gets this index.
+    pub next_index: u64,
+    /// Left-hand node at each level still waiting for its right sibling.
+    pub frontier: [[u8; 32]; HAND_ARCHIVE_DEPTH],
+    pub bump: u8,
+}
+
+impl HandArchive {
+    pub const LEN: usize =
+        32 +                       // game
+        32 +                       // root
+        8 +                        // next_index
+        32 * HAND_ARCHIVE_DEPTH +  // frontier
+        1;                         // bump
+
+    /// Root of a tree with no leaves: every leaf is zero.
+    pub fn empty_root() -> [u8; 32] {
+        let mut zero = [0u8; 32];
+        for _ in 0..HAND_ARCHIVE_DEPTH {
+            zero = hash_pair(&zero, &zero);
+        }
+        zero
+    }
+
+    /// Adds `leaf` as the next entry and returns its index.
+    pub fn append(&mut self, leaf: [u8; 32]) -> Result<u64> {
+        let index = self.next_index;
+        require!(index < 1u64 << HAND_ARCHIVE_DEPTH, PokerError::ArchiveFull);
+
+        // Empty subtrees to the right hash up from zero leaves
+        let mut node = leaf;
+        let mut zero = [0u8; 32];
+        for level in 0..HAND_ARCHIVE_DEPTH {
+            if (index >> level) & 1 == 0 {
+                self.frontier[level] = node;
+                node = hash_pair(&node, &zero);
+            } else {
+                node = hash_pair(&self.frontier[level], &node);
+            }
+            zero = hash_pair(&zero, &zero);
+        }
+
+        self.root = node;
+        self.next_index += 1;
+        Ok(index)
+    }
+
+    /// Whether `leaf` sits at `index` under the current root; `proof` runs
+    /// from the leaf's sibling up.
+    pub fn verify(&self, leaf: [u8; 32], index: u64, proof: &[[u8; 32]]) -> bool {
+        if index >= self.next_index || proof.len() != HAND_ARCHIVE_DEPTH {
+            return false;
+        }
+        let mut node = leaf;
+        for (level, sibling) in proof.iter().enumerate() {
+            node = if (index >> level) & 1 == 0 {
+                hash_pair(&node, sibling)
+            } else {
+                hash_pair(sibling, &node)
+            };
+        }
+        node == self.root
+    }
+}
+
+fn hash_pair(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    hashv(&[left, right]).to_bytes()
+}
+
+/// What an archived hand keeps of its `HandHistory`. The leaf is the
+/// sha256 of this struct's Borsh encoding.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct ArchivedHand {
+    pub hand_id: [u8; 32],
+    pub hand_number: u64,
+    pub variant: GameVariant,
+    pub actions: [HandAction; MAX_HAND_ACTIONS],
+    pub action_count: u8,
+    pub truncated: bool,
+    pub board: [u8; 5],
+    pub winner: Pubkey,
+    pub pot: u64,
+}
+
+impl ArchivedHand {
+    pub fn from_history(history: &HandHistory) -> Self {
+        Self {
+            hand_id: history.hand_id,
+            hand_number: history.hand_number,
+            variant: history.variant,
+            actions: history.actions,
+            action_count: history.action_count,
+            truncated: history.truncated,
+            board: history.board,
+            winner: history.winner,
+            pot: history.pot,
+        }
+    }
+
+    pub fn leaf(&self) -> Result<[u8; 32]> {
+        let mut bytes = Vec::new();
+        self.serialize(&mut bytes)?;
+        Ok(hashv(&[&bytes]).to_bytes())
+    }
+}
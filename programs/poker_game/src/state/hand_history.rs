@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_HAND_ACTIONS;
+use crate::state::{Game, GameVariant};
+
+/// Action-by-action record of one hand, kept for export and audit.
+#[account]
+pub struct HandHistory {
+    pub game: Pubkey,
+    pub hand_number: u64,
+    /// Same id as the hand's events carry.
+    pub hand_id: [u8; 32],
+    pub variant: GameVariant,
+    pub actions: [HandAction; MAX_HAND_ACTIONS],
+    pub action_count: u8,
+    /// Set once more actions were taken than fit.
+    pub truncated: bool,
+    pub board: [u8; 5],
+    pub winner: Pubkey,
+    pub pot: u64,
+    pub settled: bool,
+    /// Paid the rent; gets it back on close.
+    pub payer: Pubkey,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl HandHistory {
+    pub const LEN: usize =
+        32 +                  // game
+        8 +                   // hand_number
+        32 +                  // hand_id
+        1 +                   // variant
+        HandAction::LEN * MAX_HAND_ACTIONS + // actions
+        1 +                   // action_count
+        1 +                   // truncated
+        5 +                   // board
+        32 +                  // winner
+        8 +                   // pot
+        1 +                   // settled
+        32 +                  // payer
+        8 +                   // created_at
+        1;                    // bump
+
+    pub fn record(&mut self, seat: usize, kind: HandActionKind, amount: u64, street: u8) {
+        let n = self.action_count as usize;
+        if n == MAX_HAND_ACTIONS {
+            self.truncated = true;
+            return;
+        }
+        self.actions[n] = HandAction { seat: seat as u8, kind, amount, street };
+        self.action_count += 1;
+    }
+
+    pub fn record_result(&mut self, game: &Game, winner: usize, pot: u64) {
+        self.board = game.community_cards;
+        self.winner = game.players[winner];
+        self.pot = pot;
+        self.settled = true;
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub struct HandAction {
+    pub seat: u8,
+    pub kind: HandActionKind,
+    /// Total bet for a bet, chips added for a call.
+    pub amount: u64,
+    pub street: u8,
+}
+
+impl HandAction {
+    pub const LEN: usize = 1 + 1 + 8 + 1;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HandActionKind {
+    #[default]
+    Bet,
+    Call,
+    Fold,
+}
@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+/// A seat offered to one wallet at `["invitation", game, invitee]`. Once
+/// accepted it stands in for the table's gatekeeper attestation.
+#[account]
+pub struct Invitation {
+    pub game: Pubkey,
+    pub inviter: Pubkey,
+    pub invitee: Pubkey,
+    pub created_at: i64,
+    pub accepted: bool,
+    pub bump: u8,
+}
+
+impl Invitation {
+    pub const LEN: usize =
+        32 +    // game
+        32 +    // inviter
+        32 +    // invitee
+        8 +     // created_at
+        1 +     // accepted
+        1;      // bump
+}
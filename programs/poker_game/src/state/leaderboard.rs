@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::LEADERBOARD_SIZE;
+
+/// Top players of the season, at `["leaderboard"]` for the whole protocol or
+/// `["leaderboard", game]` for one table. Each ranking is kept sorted as
+/// pots are awarded, so UIs read it straight off the account. A player who
+/// drops off a ranking starts again from zero if they get back on.
+#[account]
+pub struct Leaderboard {
+    /// Table ranked, or the default key for the global board.
+    pub scope: Pubkey,
+    /// Starts new seasons on the global board; table boards defer to the
+    /// table's host.
+    pub authority: Pubkey,
+    pub season: u32,
+    pub season_started_at: i64,
+    pub by_winnings: [LeaderboardEntry; LEADERBOARD_SIZE],
+    pub by_hands_won: [LeaderboardEntry; LEADERBOARD_SIZE],
+    pub bump: u8,
+}
+
+impl Leaderboard {
+    pub const LEN: usize =
+        32 +                                          // scope
+        32 +                                          // authority
+        4 +                                           // season
+        8 +                                           // season_started_at
+        LeaderboardEntry::LEN * LEADERBOARD_SIZE * 2 + // by_winnings, by_hands_won
+        1;                                            // bump
+
+    /// Clears both rankings for a new season.
+    pub fn start_season(&mut self, now: i64) {
+        self.season += 1;
+        self.season_started_at = now;
+        self.by_winnings = [LeaderboardEntry::default(); LEADERBOARD_SIZE];
+        self.by_hands_won = [LeaderboardEntry::default(); LEADERBOARD_SIZE];
+    }
+
+    pub fn record_win(&mut self, player: Pubkey, won: u64) {
+        credit(&mut self.by_winnings, player, won);
+        credit(&mut self.by_hands_won, player, 1);
+    }
+}
+
+/// One ranked player; the default key marks an empty slot.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LeaderboardEntry {
+    pub player: Pubkey,
+    pub value: u64,
+}
+
+impl LeaderboardEntry {
+    pub const LEN: usize = 32 + 8;
+}
+
+// Adds `amount` to the player's value on the ranking, or enters them with
+// just `amount` when they are not on it
+fn credit(ranking: &mut [LeaderboardEntry; LEADERBOARD_SIZE], player: Pubkey, amount: u64) {
+    let held = ranking.iter().find(|entry| entry.player == player).map_or(0, |entry| entry.value);
+    place(ranking, player, held + amount);
+}
+
+// Sets the player's value on a ranking sorted best first, entering them in
+// the last slot if it is empty or they now beat it, then moves the entry up
+// into place
+pub(crate) fn place(ranking: &mut [LeaderboardEntry], player: Pubkey, value: u64) {
+    let last = ranking.len() - 1;
+    let mut i = match ranking.iter().position(|entry| entry.player == player) {
+        Some(i) => {
+            ranking[i].value = value;
+            i
+        }
+        None if ranking[last].player == Pubkey::default() || value > ranking[last].value => {
+            ranking[last] = LeaderboardEntry { player, value };
+            last
+        }
+        None => return,
+    };
+    while i > 0 && (ranking[i].value > ranking[i - 1].value || ranking[i - 1].player == Pubkey::default()) {
+        ranking.swap(i, i - 1);
+        i -= 1;
+    }
+}
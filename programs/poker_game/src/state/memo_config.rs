@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_MEMO_TAG_LEN;
+
+/// The tag a table's memos carry, at `["memo_config", game]`. Which fund
+/// movements get one is `TableConfig::memo_movements`.
+#[account]
+pub struct MemoConfig {
+    pub game: Pubkey,
+    /// Operator reference, such as a licence or account number.
+    pub tag: String,
+    pub bump: u8,
+}
+
+impl MemoConfig {
+    pub const LEN: usize =
+        32 +                        // game
+        4 + MAX_MEMO_TAG_LEN +      // tag
+        1;                          // bump
+}
+
+/// Lamport movements an operator can have tagged with a memo.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum FundMovement {
+    /// Buy-ins and top-ups.
+    Deposit,
+    /// Pots paid out to a wallet or payout route.
+    Payout,
+    /// Crank rewards taken from the pot.
+    Rake,
+    /// Spectator tips onto a player's stack.
+    Tip,
+    /// Stacks cashed out to the wallet when a seat is given up.
+    Withdrawal,
+}
+
+impl FundMovement {
+    pub fn bit(self) -> u8 {
+        1 << self as u8
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            FundMovement::Deposit => "deposit",
+            FundMovement::Payout => "payout",
+            FundMovement::Rake => "rake",
+            FundMovement::Tip => "tip",
+            FundMovement::Withdrawal => "withdrawal",
+        }
+    }
+}
@@ -0,0 +1,45 @@
+pub mod achievement;
+pub mod arbiter;
+pub mod club;
+pub mod dealer_bot;
+pub mod extension;
+pub mod game;
+pub mod global_config;
+pub mod hand_archive;
+pub mod hand_history;
+pub mod invitation;
+pub mod leaderboard;
+pub mod memo_config;
+pub mod note;
+pub mod payout_route;
+pub mod price_gate;
+pub mod profile;
+pub mod season;
+pub mod seat;
+pub mod spectator;
+pub mod stats;
+pub mod table_config;
+pub mod tournament;
+
+pub use achievement::*;
+pub use arbiter::*;
+pub use club::*;
+pub use dealer_bot::*;
+pub use extension::*;
+pub use game::*;
+pub use global_config::*;
+pub use hand_archive::*;
+pub use hand_history::*;
+pub use invitation::*;
+pub use leaderboard::*;
+pub use memo_config::*;
+pub use note::*;
+pub use payout_route::*;
+pub use price_gate::*;
+pub use profile::*;
+pub use season::*;
+pub use seat::*;
+pub use spectator::*;
+pub use stats::*;
+pub use table_config::*;
+pub use tournament::*;
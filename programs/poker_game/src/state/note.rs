@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+/// A player's private note on an opponent, at `["note", owner, opponent]`.
+/// The client encrypts before writing; the program only stores the bytes
+/// and sizes the account to fit them. Owners find all their notes by
+/// filtering on the leading `owner` field.
+#[account]
+pub struct PlayerNote {
+    pub owner: Pubkey,
+    pub opponent: Pubkey,
+    pub updated_at: i64,
+    pub bump: u8,
+    pub ciphertext: Vec<u8>,
+}
+
+impl PlayerNote {
+    /// Space for a note holding `len` bytes of ciphertext.
+    pub const fn space(len: usize) -> usize {
+        32 +        // owner
+        32 +        // opponent
+        8 +         // updated_at
+        1 +         // bump
+        4 + len     // ciphertext
+    }
+}
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+/// Where a seat's cash winnings go instead of the wallet, at
+/// `["payout_route", game, wallet]`. Only used while the seat's bit in
+/// `Game::escrow_seats` is set.
+#[account]
+pub struct PayoutRoute {
+    pub game: Pubkey,
+    pub wallet: Pubkey,
+    /// Allowlisted staking program, see `GlobalConfig`.
+    pub program: Pubkey,
+    /// The wallet's deposit account in `program`.
+    pub deposit: Pubkey,
+    pub bump: u8,
+}
+
+impl PayoutRoute {
+    pub const LEN: usize =
+        32 +    // game
+        32 +    // wallet
+        32 +    // program
+        32 +    // deposit
+        1;      // bump
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+/// A table's buy-in range in US dollars, at `["price_gate", game]`. Deposits
+/// are converted to lamports at the SOL/USD price in `feed` when they are
+/// made, so the stakes hold their value as SOL moves. Applies while the
+/// table config's `usd_buy_in` is set.
+#[account]
+pub struct PriceGate {
+    pub game: Pubkey,
+    /// Pyth `PriceUpdateV2` account for SOL/USD.
+    pub feed: Pubkey,
+    /// Buy-in range in cents; 0 leaves that end open.
+    pub min_buy_in_usd: u64,
+    pub max_buy_in_usd: u64,
+    /// Oldest price, in seconds, a deposit will be converted at.
+    pub max_age: i64,
+    pub bump: u8,
+}
+
+impl PriceGate {
+    pub const LEN: usize =
+        32 +    // game
+        32 +    // feed
+        8 +     // min_buy_in_usd
+        8 +     // max_buy_in_usd
+        8 +     // max_age
+        1;      // bump
+}
+
+/// Returned by `get_usd_stakes`: the table's stakes at the current price,
+/// blinds in cents and the buy-in range in lamports (0 where open).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct UsdStakes {
+    pub small_blind_usd: u64,
+    pub big_blind_usd: u64,
+    pub min_buy_in: u64,
+    pub max_buy_in: u64,
+    pub publish_time: i64,
+}
@@ -0,0 +1,157 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+use crate::constants::{LIMIT_COOLING_OFF, LIMIT_DAY, MAX_AVATAR_URI_LEN, MAX_DISPLAY_NAME_LEN, MAX_FRIENDS};
+use crate::errors::PokerError;
+
+/// A wallet's public identity at `["profile", wallet]`, so tables can show
+/// names instead of keys.
+#[account]
+pub struct PlayerProfile {
+    pub wallet: Pubkey,
+    pub display_name: String,
+    /// Where the avatar image lives; empty for none.
+    pub avatar_uri: String,
+    pub created_at: i64,
+    pub stats: PlayerStats,
+    /// Elo rating, moved pot by pot against the profiles of the other
+    /// players dealt in.
+    pub rating: u16,
+    /// `Achievement` bits earned at settlement, claimable as badges.
+    pub achievements: u8,
+    /// Wallets this player may invite to tables they sit at.
+    pub friends: Vec<Pubkey>,
+    pub limits: PlayerLimits,
+    pub bump: u8,
+}
+
+impl PlayerProfile {
+    pub const LEN: usize =
+        32 +                          // wallet
+        4 + MAX_DISPLAY_NAME_LEN +    // display_name
+        4 + MAX_AVATAR_URI_LEN +      // avatar_uri
+        8 +                           // created_at
+        PlayerStats::LEN +            // stats
+        2 +                           // rating
+        1 +                           // achievements
+        4 + 32 * MAX_FRIENDS +        // friends
+        PlayerLimits::LEN +           // limits
+        1;                            // bump
+}
+
+/// Claims a display name for one wallet at `["name", name_hash(name)]`, so
+/// no two profiles share a name however it is capitalised or padded.
+#[account]
+pub struct NameRecord {
+    pub owner: Pubkey,
+    pub bump: u8,
+}
+
+impl NameRecord {
+    pub const LEN: usize = 32 + 1;
+}
+
+/// Registry key for a display name: the hash of its trimmed, lowercased form.
+pub fn name_hash(name: &str) -> [u8; 32] {
+    hashv(&[name.trim().to_lowercase().as_bytes()]).to_bytes()
+}
+
+/// Responsible-gaming limits the player sets on themselves, checked
+/// whenever they bring chips to a table.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PlayerLimits {
+    /// Most the player may buy in for per day; 0 for no limit.
+    pub daily_buy_in: u64,
+    /// No buy-ins before this time.
+    pub excluded_until: i64,
+    /// A looser daily limit waiting out its cooling-off period.
+    pub pending_daily_buy_in: u64,
+    /// When the pending limit takes over; 0 when none is pending.
+    pub pending_from: i64,
+    pub day_started_at: i64,
+    pub bought_in_today: u64,
+}
+
+impl PlayerLimits {
+    pub const LEN: usize = 8 * 6;
+
+    /// Tighter limits and longer exclusions apply at once. Looser limits
+    /// wait `LIMIT_COOLING_OFF`, and an exclusion can never be shortened.
+    pub fn update(&mut self, daily_buy_in: u64, excluded_until: i64, now: i64) {
+        self.apply_pending(now);
+        let tighter = daily_buy_in != 0 && (self.daily_buy_in == 0 || daily_buy_in <= self.daily_buy_in);
+        if tighter {
+            self.daily_buy_in = daily_buy_in;
+            self.pending_from = 0;
+        } else if daily_buy_in != self.daily_buy_in {
+            self.pending_daily_buy_in = daily_buy_in;
+            self.pending_from = now + LIMIT_COOLING_OFF;
+        }
+        self.excluded_until = self.excluded_until.max(excluded_until);
+    }
+
+    /// Whether a daily limit still binds the player at `now`, or a change
+    /// to it is waiting out its cooling-off period. Closing the profile
+    /// would drop either at once.
+    pub fn in_force(&mut self, now: i64) -> bool {
+        self.apply_pending(now);
+        self.daily_buy_in != 0 || self.pending_from != 0
+    }
+
+    /// Counts a buy-in of `amount` against today's limit, refusing it
+    /// during an exclusion or past the limit.
+    pub fn charge(&mut self, amount: u64, now: i64) -> Result<()> {
+        require!(now >= self.excluded_until, PokerError::SelfExcluded);
+        self.apply_pending(now);
+        if now >= self.day_started_at + LIMIT_DAY {
+            self.day_started_at = now;
+            self.bought_in_today = 0;
+        }
+        let total = self.bought_in_today + amount;
+        require!(self.daily_buy_in == 0 || total <= self.daily_buy_in, PokerError::BuyInLimitReached);
+        self.bought_in_today = total;
+        Ok(())
+    }
+
+    fn apply_pending(&mut self, now: i64) {
+        if self.pending_from != 0 && now >= self.pending_from {
+            self.daily_buy_in = self.pending_daily_buy_in;
+            self.pending_from = 0;
+        }
+    }
+}
+
+/// Lifetime results, added to at settlement whenever profiles are passed
+/// along, which takes every dealt-in player's.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PlayerStats {
+    pub hands_played: u64,
+    pub hands_won: u64,
+    /// Pots won with more than one hand still live.
+    pub showdowns_won: u64,
+    /// Everything collected from pots won, the player's own bets included.
+    pub total_winnings: u64,
+    pub biggest_pot: u64,
+    /// Hands the player put chips in voluntarily on the first street; over
+    /// `hands_played` this is their VPIP.
+    pub vpip_hands: u64,
+    /// Hands the player raised on the first street; over `hands_played`
+    /// this is their PFR.
+    pub pfr_hands: u64,
+}
+
+impl PlayerStats {
+    pub const LEN: usize = 8 * 7;
+
+    pub fn record(&mut self, won: Option<u64>, showdown: bool, vpip: bool, pfr: bool) {
+        self.hands_played += 1;
+        self.vpip_hands += u64::from(vpip);
+        self.pfr_hands += u64::from(pfr);
+        if let Some(pot) = won {
+            self.hands_won += 1;
+            self.showdowns_won += u64::from(showdown);
+            self.total_winnings += pot;
+            self.biggest_pot = self.biggest_pot.max(pot);
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{MAX_SEASON_VENUES, SEASON_PAID_PLACES, SEASON_SCORED_FINISHES};
+use crate::state::{place, LeaderboardEntry};
+
+/// A league run by `authority` at `["season", authority, id]`. Registered
+/// players earn points at the tables and tournaments the season lists, and
+/// once it ends the top `SEASON_PAID_PLACES` split the sponsored prize pool
+/// held in this account.
+#[account]
+pub struct Season {
+    pub authority: Pubkey,
+    pub id: u32,
+    pub starts_at: i64,
+    pub ends_at: i64,
+    pub weights: SeasonWeights,
+    /// Basis points of the prize pool per final standing, best first.
+    pub prize_shares: [u16; SEASON_PAID_PLACES],
+    /// Lamports sponsors have paid in for prizes.
+    pub prize_pool: u64,
+    /// Tables and tournaments that earn points.
+    pub venues: [Pubkey; MAX_SEASON_VENUES],
+    pub venue_count: u8,
+    /// Best point totals so far, kept sorted as points accrue.
+    pub standings: [LeaderboardEntry; SEASON_PAID_PLACES],
+    pub bump: u8,
+}
+
+impl Season {
+    pub const LEN: usize =
+        32 +                                        // authority
+        4 +                                         // id
+        8 +                                         // starts_at
+        8 +                                         // ends_at
+        SeasonWeights::LEN +                        // weights
+        2 * SEASON_PAID_PLACES +                    // prize_shares
+        8 +                                         // prize_pool
+        32 * MAX_SEASON_VENUES +                    // venues
+        1 +                                         // venue_count
+        LeaderboardEntry::LEN * SEASON_PAID_PLACES + // standings
+        1;                                          // bump
+
+    pub fn is_active(&self, now: i64) -> bool {
+        now >= self.starts_at && now < self.ends_at
+    }
+
+    pub fn venue_index(&self, venue: &Pubkey) -> Option<usize> {
+        self.venues[..self.venue_count as usize].iter().position(|v| v == venue)
+    }
+
+    /// Frees a closed table's slot. The slot stays in place, since entries
+    /// track scored tournaments by venue index, and is reused by the next
+    /// venue added.
+    pub fn remove_venue(&mut self, venue: &Pubkey) {
+        if let Some(index) = self.venue_index(venue) {
+            self.venues[index] = Pubkey::default();
+        }
+    }
+
+    /// Adds points to an entry and moves it through the standings.
+    pub fn award(&mut self, entry: &mut SeasonEntry, points: u64) {
+        if points == 0 {
+            return;
+        }
+        entry.points += points;
+        place(&mut self.standings, entry.player, entry.points);
+    }
+
+    /// What the player finishing `standing` (0 for first) collects.
+    pub fn prize_for(&self, standing: usize) -> u64 {
+        (self.prize_pool as u128 * self.prize_shares[standing] as u128 / 10_000) as u64
+    }
+}
+
+/// How a season scores play.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SeasonWeights {
+    /// Per cash hand dealt in.
+    pub hand_played: u32,
+    /// Per cash pot won, on top of `hand_played`.
+    pub hand_won: u32,
+    /// Per tournament finish, first place first.
+    pub finishes: [u32; SEASON_SCORED_FINISHES],
+}
+
+impl SeasonWeights {
+    pub const LEN: usize = 4 + 4 + 4 * SEASON_SCORED_FINISHES;
+}
+
+/// A player's registration and running total at `["season_entry", season, player]`.
+#[account]
+pub struct SeasonEntry {
+    pub season: Pubkey,
+    pub player: Pubkey,
+    pub points: u64,
+    /// Season venues whose tournament finish has been scored, by index.
+    pub tournaments_scored: u16,
+    pub prize_claimed: bool,
+    pub bump: u8,
+}
+
+impl SeasonEntry {
+    pub const LEN: usize =
+        32 +    // season
+        32 +    // player
+        8 +     // points
+        2 +     // tournaments_scored
+        1 +     // prize_claimed
+        1;      // bump
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_EMOTES_PER_STREET;
+
+/// A player's seat at a table, so actions find their seat without scanning.
+#[account]
+pub struct Seat {
+    pub game: Pubkey,
+    pub owner: Pubkey,
+    pub index: u8,
+    pub bump: u8,
+    /// Hand and street the emote count below belongs to.
+    pub emote_hand: u64,
+    pub emote_street: u8,
+    pub emotes_sent: u8,
+    /// Key allowed to bet, call and fold for the owner until
+    /// `session_expires_at`; default when none.
+    pub session_key: Pubkey,
+    pub session_expires_at: i64,
+}
+
+impl Seat {
+    pub const LEN: usize = 32 + 32 + 1 + 1 + 8 + 1 + 1 + 32 + 8;
+
+    /// Whether `signer` may act for this seat at `now`: the owner, or an
+    /// unexpired session key.
+    pub fn can_act(&self, signer: &Pubkey, now: i64) -> bool {
+        *signer == self.owner
+            || (self.session_key != Pubkey::default() && *signer == self.session_key && now < self.session_expires_at)
+    }
+
+    /// Counts an emote against this hand and street's allowance.
+    pub fn spend_emote(&mut self, hand: u64, street: u8) -> bool {
+        if (self.emote_hand, self.emote_street) != (hand, street) {
+            self.emote_hand = hand;
+            self.emote_street = street;
+            self.emotes_sent = 0;
+        }
+        if self.emotes_sent >= MAX_EMOTES_PER_STREET {
+            return false;
+        }
+        self.emotes_sent += 1;
+        true
+    }
+}
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+/// A wallet watching a table at `["spectator", game, wallet]`. Only
+/// registered spectators may tip the players.
+#[account]
+pub struct Spectator {
+    pub game: Pubkey,
+    pub wallet: Pubkey,
+    pub registered_at: i64,
+    /// Lamports tipped to the table's players so far.
+    pub tipped: u64,
+    pub bump: u8,
+}
+
+impl Spectator {
+    pub const LEN: usize =
+        32 +    // game
+        32 +    // wallet
+        8 +     // registered_at
+        8 +     // tipped
+        1;      // bump
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+/// Protocol-wide totals for dashboards, merged in from the stats shards.
+#[account]
+pub struct GlobalStats {
+    pub totals: ProtocolCounters,
+    /// Unix time of the last merge.
+    pub merged_at: i64,
+    pub bump: u8,
+}
+
+impl GlobalStats {
+    pub const LEN: usize = ProtocolCounters::LEN + 8 + 1;
+}
+
+/// Counts written at settlement and not yet merged into `GlobalStats`.
+#[account]
+pub struct StatsShard {
+    pub index: u8,
+    pub pending: ProtocolCounters,
+    pub bump: u8,
+}
+
+impl StatsShard {
+    pub const LEN: usize = 1 + ProtocolCounters::LEN + 1;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ProtocolCounters {
+    /// Cash tables created.
+    pub games: u64,
+    /// Pots awarded.
+    pub hands: u64,
+    /// Sum of awarded pots, before rake.
+    pub volume: u64,
+    /// Crank rewards taken out of those pots.
+    pub rake: u64,
+}
+
+impl ProtocolCounters {
+    pub const LEN: usize = 8 * 4;
+
+    /// Counts an awarded pot; `volume` includes the `rake` taken from it.
+    pub fn record_pot(&mut self, volume: u64, rake: u64) {
+        self.hands += 1;
+        self.volume += volume;
+        self.rake += rake;
+    }
+}
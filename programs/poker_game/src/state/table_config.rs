@@ -0,0 +1,141 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{DEFAULT_HAND_DELAY, DEFAULT_TURN_TIMEOUT, MAX_ROTATION};
+use crate::state::{GameVariant, TableRules};
+
+/// Cold settings for a table, at `["table_config", game]`. Only the owner or
+/// operator writes it; hand instructions take it read-only so they never
+/// contend for its write lock. Zero-copy like `Game`.
+#[account(zero_copy)]
+pub struct TableConfig {
+    pub rules: TableRules,
+    /// Seconds each player has to act.
+    pub turn_timeout: i64,
+    /// Time bank each seat starts with and is refilled to, in seconds.
+    pub time_bank: i64,
+    /// Seconds to wait after a hand before auto-dealing the next.
+    pub hand_delay: i64,
+    /// Blinds and start time the cash-table blind schedule escalates from.
+    pub escalation_started_at: i64,
+    pub base_small_blind: u64,
+    pub base_big_blind: u64,
+    /// Seconds after showdown during which a seated player may dispute the
+    /// hand, holding up the payout; 0 pays out straight away.
+    pub dispute_window: u32,
+    /// Rating range a profile needs to join; 0 leaves that end open.
+    pub min_rating: u16,
+    pub max_rating: u16,
+
+    pub game: Pubkey,
+    /// Key allowed to move funds and rotate keys: the creator, or the
+    /// tournament authority for tournament tables.
+    pub owner: Pubkey,
+    /// Optional hot key for routine operations; default when unset.
+    pub operator: Pubkey,
+    /// Program issuing the passes new seats must hold; default when open.
+    pub gatekeeper: Pubkey,
+    /// Club whose members this table seats, or the default key.
+    pub club: Pubkey,
+
+    /// Mixed-game variant rotation; the game's `variant` holds the current
+    /// hand's entry.
+    pub rotation: [u8; MAX_ROTATION],
+    pub rotation_len: u8,
+    /// Hands per rotation entry, or 0 to rotate every orbit.
+    pub hands_per_variant: u8,
+    /// Hands between time bank refills, or 0 to never refill.
+    pub time_bank_refill_hands: u8,
+    /// Whether anyone may deal the next hand once the table is ready.
+    pub auto_deal: u8,
+    /// Ready seats needed before an auto-deal.
+    pub min_players: u8,
+    pub bump: u8,
+    /// `LegacyEntrypoint` bits the host has switched off.
+    pub deprecated: u8,
+    /// Registered `DealerBot`s. While there are any, only the host, the
+    /// seated players and those bots crank the table.
+    pub dealer_bots: u8,
+    /// Set when deposits must come from the player's own funds: see
+    /// `require_own_funds`.
+    pub own_funds_only: u8,
+    /// Set when deposits must fall within the table's `PriceGate` range.
+    pub usd_buy_in: u8,
+    /// `FundMovement` bits the operator tags with a memo.
+    pub memo_movements: u8,
+    /// Zeroed room for new fields; also pads the account to eight bytes.
+    pub reserved: [u8; 21],
+}
+
+impl TableConfig {
+    pub const LEN: usize = std::mem::size_of::<TableConfig>();
+
+    /// Defaults for a new table owned by `owner`.
+    pub fn init(&mut self, game: Pubkey, owner: Pubkey, small_blind: u64, big_blind: u64, bump: u8) {
+        self.rules = TableRules::default();
+        self.turn_timeout = DEFAULT_TURN_TIMEOUT;
+        self.time_bank = 0;
+        self.hand_delay = DEFAULT_HAND_DELAY;
+        self.escalation_started_at = 0;
+        self.base_small_blind = small_blind;
+        self.base_big_blind = big_blind;
+        self.dispute_window = 0;
+        self.min_rating = 0;
+        self.max_rating = 0;
+        self.game = game;
+        self.owner = owner;
+        self.operator = Pubkey::default();
+        self.gatekeeper = Pubkey::default();
+        self.club = Pubkey::default();
+        self.rotation = [GameVariant::Holdem as u8; MAX_ROTATION];
+        self.rotation_len = 0;
+        self.hands_per_variant = 0;
+        self.time_bank_refill_hands = 0;
+        self.auto_deal = 0;
+        self.min_players = 2;
+        self.bump = bump;
+        self.deprecated = 0;
+        self.dealer_bots = 0;
+        self.own_funds_only = 0;
+        self.usd_buy_in = 0;
+        self.memo_movements = 0;
+        self.reserved = [0; 21];
+    }
+
+    /// The owner, or the operator key when one is set.
+    pub fn is_host(&self, key: &Pubkey) -> bool {
+        *key == self.owner || (self.operator != Pubkey::default() && *key == self.operator)
+    }
+
+    /// Whether top-ups need the checks only `add_chips_v2` can run; mirrored
+    /// into `Game::gated_top_ups` for `add_chips`, which has no config.
+    pub fn gates_top_ups(&self) -> bool {
+        self.own_funds_only != 0 || self.usd_buy_in != 0
+    }
+
+    /// Whether a profile rated `rating` may sit down here.
+    pub fn admits_rating(&self, rating: u16) -> bool {
+        rating >= self.min_rating && (self.max_rating == 0 || rating <= self.max_rating)
+    }
+
+    pub fn is_rating_gated(&self) -> bool {
+        self.min_rating > 0 || self.max_rating > 0
+    }
+
+    pub fn is_deprecated(&self, entrypoint: LegacyEntrypoint) -> bool {
+        self.deprecated & entrypoint.bit() != 0
+    }
+}
+
+/// Older instruction shapes kept working for clients that predate a change.
+/// Each stays on until the host deprecates it for their table.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum LegacyEntrypoint {
+    /// `bet_v1`, `call_v1` and `fold_v1`, taking `ActionTicketV1`.
+    ActionsV1,
+}
+
+impl LegacyEntrypoint {
+    pub fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
@@ -0,0 +1,362 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{MAX_BLIND_LEVELS, MAX_ENTRANTS, MAX_PAID_PLACES, MAX_PLAYERS, MAX_TABLES};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum DealKind {
+    /// Lowest remaining prize each, the rest split by chip count.
+    ChipChop,
+    /// Independent Chip Model equity.
+    Icm,
+    /// Amounts agreed off-chain, in the final table's seat order.
+    Custom { amounts: [u64; MAX_PLAYERS] },
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum EntryGate {
+    Open,
+    /// Requires a `FreerollPass` granted by the tournament authority.
+    Allowlist,
+    /// Requires a satellite `Ticket` for the tournament.
+    Ticket,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum TournamentFormat {
+    /// Tables are balanced and broken down to a final table.
+    Standard,
+    /// Each table plays down to one winner, who advances to the next round.
+    Shootout,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum TournamentStatus {
+    Registering,
+    Running,
+    Finished,
+    /// Called off before starting; entrants can claim refunds.
+    Cancelled,
+}
+
+#[account]
+pub struct Tournament {
+    pub authority: Pubkey,
+    pub buy_in: u64,
+    pub starting_stack: u64,
+    pub small_blind: u64,
+    pub big_blind: u64,
+    pub prize_pool: u64,
+    pub entrants: [Pubkey; MAX_ENTRANTS],
+    pub entrant_count: u8,
+    pub players_remaining: u8,
+    pub tables: [Pubkey; MAX_TABLES],
+    pub table_count: u8,
+    pub status: TournamentStatus,
+    /// Blind schedule, or the default key for flat blinds.
+    pub blind_structure: Pubkey,
+    /// Unix time of the seat draw; blind levels are measured from here.
+    pub started_at: i64,
+    pub eliminated: [bool; MAX_ENTRANTS],
+    /// Buy-ins paid per entrant, including re-entries.
+    pub entries: [u8; MAX_ENTRANTS],
+    /// Re-entry is open while the blind level index is below this.
+    pub reentry_levels: u8,
+    pub max_reentries: u8,
+    /// Late registration is open while the blind level index is below this.
+    pub late_registration_levels: u8,
+    /// Finishing place per entrant, 0 while still in the tournament.
+    pub finish_positions: [u8; MAX_ENTRANTS],
+    pub payout_structure: PayoutStructure,
+    /// Tournament whose tickets this satellite awards, or the default key.
+    pub satellite_target: Pubkey,
+    pub satellite_seats: u8,
+    pub ticket_value: u64,
+    pub tickets_claimed: [bool; MAX_ENTRANTS],
+    /// Set on the money bubble: tables deal in lockstep, one hand at a time.
+    pub hand_for_hand: bool,
+    /// Tables that have dealt in the current hand-for-hand round.
+    pub hfh_dealt: [bool; MAX_TABLES],
+    /// Tables that have finished their hand in the current round.
+    pub hfh_ready: [bool; MAX_TABLES],
+    /// Portion of each buy-in placed as a knockout bounty; 0 for regular tournaments.
+    pub bounty: u64,
+    /// Bounty currently on each entrant's head.
+    pub bounties: [u64; MAX_ENTRANTS],
+    /// Bounty cash each entrant has won, claimable once the tournament finishes.
+    pub bounty_winnings: [u64; MAX_ENTRANTS],
+    pub format: TournamentFormat,
+    /// Current shootout round, starting at 0.
+    pub shootout_round: u8,
+    /// Tables playing in the current shootout round.
+    pub round_tables: u8,
+    /// Table winners waiting for the next shootout round.
+    pub advancers: [Pubkey; MAX_TABLES],
+    pub advancer_count: u8,
+    /// Total sponsor money added to the prize pool.
+    pub sponsored: u64,
+    /// Who may register for a freeroll.
+    pub entry_gate: EntryGate,
+    /// Final-table deal under consideration; `deal_count` is 0 when none is pending.
+    pub deal_players: [Pubkey; MAX_PLAYERS],
+    pub deal_amounts: [u64; MAX_PLAYERS],
+    pub deal_accepted: [bool; MAX_PLAYERS],
+    pub deal_count: u8,
+    /// Scheduled start as a unix time, or 0 when the authority starts it.
+    pub start_time: i64,
+    /// Entrants needed for a scheduled start.
+    pub min_entrants: u8,
+    /// Seat-draw seed, mixed with every registration.
+    pub seat_seed: u64,
+    /// Entrant index that won each player's busting pot, or `NO_ELIMINATOR`.
+    pub eliminated_by: [u8; MAX_ENTRANTS],
+    /// Blind level index each player busted at.
+    pub elimination_levels: [u8; MAX_ENTRANTS],
+}
+
+impl Tournament {
+    pub const LEN: usize =
+        32 +                  // authority
+        8 +                   // buy_in
+        8 +                   // starting_stack
+        8 +                   // small_blind
+        8 +                   // big_blind
+        8 +                   // prize_pool
+        32 * MAX_ENTRANTS +   // entrants
+        1 +                   // entrant_count
+        1 +                   // players_remaining
+        32 * MAX_TABLES +     // tables
+        1 +                   // table_count
+        1 +                   // status
+        32 +                  // blind_structure
+        8 +                   // started_at
+        MAX_ENTRANTS +        // eliminated
+        MAX_ENTRANTS +        // entries
+        1 +                   // reentry_levels
+        1 +                   // max_reentries
+        1 +                   // late_registration_levels
+        MAX_ENTRANTS +        // finish_positions
+        PayoutStructure::LEN + // payout_structure
+        32 +                  // satellite_target
+        1 +                   // satellite_seats
+        8 +                   // ticket_value
+        MAX_ENTRANTS +        // tickets_claimed
+        1 +                   // hand_for_hand
+        MAX_TABLES +          // hfh_dealt
+        MAX_TABLES +          // hfh_ready
+        8 +                   // bounty
+        8 * MAX_ENTRANTS +    // bounties
+        8 * MAX_ENTRANTS +    // bounty_winnings
+        1 +                   // format
+        1 +                   // shootout_round
+        1 +                   // round_tables
+        32 * MAX_TABLES +     // advancers
+        1 +                   // advancer_count
+        8 +                   // sponsored
+        1 +                   // entry_gate
+        32 * MAX_PLAYERS +    // deal_players
+        8 * MAX_PLAYERS +     // deal_amounts
+        MAX_PLAYERS +         // deal_accepted
+        1 +                   // deal_count
+        8 +                   // start_time
+        1 +                   // min_entrants
+        8 +                   // seat_seed
+        MAX_ENTRANTS +        // eliminated_by
+        MAX_ENTRANTS;         // elimination_levels
+
+    /// Folds a registration into the seat-draw seed, so the draw depends on
+    /// every entrant and the slot each one registered in.
+    pub fn mix_seat_seed(&mut self, player: &Pubkey, slot: u64) {
+        let key = u64::from_le_bytes(player.to_bytes()[..8].try_into().unwrap());
+        self.seat_seed = (self.seat_seed ^ key ^ slot)
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1);
+    }
+
+    pub fn entrant_index(&self, player: &Pubkey) -> Option<usize> {
+        self.entrants[..self.entrant_count as usize]
+            .iter()
+            .position(|p| p == player)
+    }
+
+    /// Prize for each paid place, winner first, and the number of places paid.
+    /// Rounding dust goes to the winner.
+    pub fn place_prizes(&self) -> ([u64; MAX_PAID_PLACES], usize) {
+        let (shares, paid) = self.payout_structure.shares(self.entrant_count as usize);
+        let total_bps: u128 = shares[..paid].iter().map(|&bps| bps as u128).sum();
+
+        let mut amounts = [0u64; MAX_PAID_PLACES];
+        for place in 0..paid {
+            amounts[place] = (self.prize_pool as u128 * shares[place] as u128 / total_bps) as u64;
+        }
+        amounts[0] += self.prize_pool - amounts[..paid].iter().sum::<u64>();
+        (amounts, paid)
+    }
+
+    /// Splits an entry fee between the prize pool and the bounty on the entrant's head.
+    pub fn add_entry_fee(&mut self, amount: u64) {
+        self.prize_pool += amount - self.bounty;
+    }
+
+    pub fn table_index(&self, table: &Pubkey) -> Option<usize> {
+        self.tables[..self.table_count as usize]
+            .iter()
+            .position(|t| t == table)
+    }
+
+    /// Removes the table at `index` from the table list, keeping the
+    /// per-table flags aligned.
+    pub fn remove_table(&mut self, index: usize) {
+        let count = self.table_count as usize;
+        self.tables[index..count].rotate_left(1);
+        self.tables[count - 1] = Pubkey::default();
+        self.hfh_dealt[index..count].rotate_left(1);
+        self.hfh_dealt[count - 1] = false;
+        self.hfh_ready[index..count].rotate_left(1);
+        self.hfh_ready[count - 1] = false;
+        self.table_count -= 1;
+    }
+
+    /// Plays hand-for-hand while one elimination away from the money on
+    /// more than one table. Entering or leaving the mode starts a fresh round.
+    /// Shootout tables play independently and never go hand-for-hand.
+    pub fn refresh_hand_for_hand(&mut self) {
+        let paid = if self.satellite_target != Pubkey::default() {
+            self.satellite_seats as usize
+        } else {
+            self.payout_structure.shares(self.entrant_count as usize).1
+        };
+        let on_bubble = self.players_remaining as usize == paid + 1
+            && self.table_count > 1
+            && self.format == TournamentFormat::Standard;
+
+        if on_bubble != self.hand_for_hand {
+            self.hand_for_hand = on_bubble;
+            self.hfh_dealt = [false; MAX_TABLES];
+            self.hfh_ready = [false; MAX_TABLES];
+        }
+    }
+}
+
+/// Satellite prize redeemable as the buy-in of `tournament`. Holds the
+/// buy-in in lamports on top of its rent.
+#[account]
+pub struct Ticket {
+    pub owner: Pubkey,
+    pub tournament: Pubkey,
+    pub value: u64,
+    pub bump: u8,
+}
+
+impl Ticket {
+    pub const LEN: usize = 32 + 32 + 8 + 1;
+}
+
+/// Allowlist entry for a gated freeroll.
+#[account]
+pub struct FreerollPass {
+    pub tournament: Pubkey,
+    pub player: Pubkey,
+    pub bump: u8,
+}
+
+impl FreerollPass {
+    pub const LEN: usize = 32 + 32 + 1;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum PayoutStructure {
+    WinnerTakesAll,
+    /// 50% / 30% / 20%.
+    TopThree,
+    /// Pays the top `percent` of the field on a linear curve, capped at MAX_PAID_PLACES.
+    TopPercent { percent: u8 },
+    /// Explicit shares in basis points for the first `places` finishers.
+    Custom { bps: [u16; MAX_PAID_PLACES], places: u8 },
+}
+
+impl PayoutStructure {
+    pub const LEN: usize = 1 + 2 * MAX_PAID_PLACES + 1;
+
+    pub fn is_valid(&self) -> bool {
+        match self {
+            PayoutStructure::WinnerTakesAll | PayoutStructure::TopThree => true,
+            PayoutStructure::TopPercent { percent } => *percent > 0 && *percent <= 100,
+            PayoutStructure::Custom { bps, places } => {
+                let places = *places as usize;
+                places > 0
+                    && places <= MAX_PAID_PLACES
+                    && bps[..places].iter().map(|&b| b as u32).sum::<u32>() == 10_000
+                    && bps[..places].windows(2).all(|w| w[0] >= w[1])
+            }
+        }
+    }
+
+    /// Basis-point shares per place and the number of places paid for a field of `entrants`.
+    pub fn shares(&self, entrants: usize) -> ([u16; MAX_PAID_PLACES], usize) {
+        let mut shares = [0u16; MAX_PAID_PLACES];
+        let places = match self {
+            PayoutStructure::WinnerTakesAll => {
+                shares[0] = 10_000;
+                1
+            }
+            PayoutStructure::TopThree => {
+                shares[..3].copy_from_slice(&[5_000, 3_000, 2_000]);
+                3
+            }
+            PayoutStructure::TopPercent { percent } => {
+                let places = (entrants * *percent as usize).div_ceil(100).clamp(1, MAX_PAID_PLACES);
+                // Linear curve: place i gets weight (places - i)
+                let total = (places * (places + 1) / 2) as u32;
+                for (i, share) in shares[..places].iter_mut().enumerate() {
+                    *share = ((places - i) as u32 * 10_000 / total) as u16;
+                }
+                places
+            }
+            PayoutStructure::Custom { bps, places } => {
+                shares = *bps;
+                *places as usize
+            }
+        };
+        (shares, places.min(entrants).max(1))
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct BlindLevel {
+    pub small_blind: u64,
+    pub big_blind: u64,
+    pub ante: u64,
+    /// Length of the level in seconds. The last level never ends.
+    pub duration: i64,
+}
+
+impl BlindLevel {
+    pub const LEN: usize = 8 + 8 + 8 + 8;
+}
+
+#[account]
+pub struct BlindStructure {
+    pub authority: Pubkey,
+    pub levels: [BlindLevel; MAX_BLIND_LEVELS],
+    pub level_count: u8,
+}
+
+impl BlindStructure {
+    pub const LEN: usize =
+        32 +                                  // authority
+        BlindLevel::LEN * MAX_BLIND_LEVELS +  // levels
+        1;                                    // level_count
+
+    /// Returns the level index and level in effect `elapsed` seconds after the start.
+    pub fn level_at(&self, elapsed: i64) -> (usize, BlindLevel) {
+        let last = self.level_count as usize - 1;
+        let mut remaining = elapsed.max(0);
+        for i in 0..last {
+            if remaining < self.levels[i].duration {
+                return (i, self.levels[i]);
+            }
+            remaining -= self.levels[i].duration;
+        }
+        (last, self.levels[last])
+    }
+}
@@ -0,0 +1,111 @@
+//! JavaScript bindings for `rules`, so a browser client scores hands and
+//! sizes bets with the code the program runs. Build for
+//! `wasm32-unknown-unknown` with `--no-default-features --features wasm`,
+//! then run `wasm-bindgen` over the output. Amounts are `bigint` in JS.
+
+use wasm_bindgen::prelude::*;
+
+use crate::constants::DECK_SIZE;
+use crate::rules::{self, GameVariant};
+
+fn check_cards(cards: &[u8]) -> Result<(), JsError> {
+    match cards.iter().find(|&&card| card as usize >= DECK_SIZE) {
+        Some(card) => Err(JsError::new(&format!("{card} is not a card"))),
+        None => Ok(()),
+    }
+}
+
+/// Score of up to five cards; higher wins. Cards are `0..52`, rank
+/// `card % 13` and suit `card / 13`.
+#[wasm_bindgen]
+pub fn evaluate(cards: &[u8]) -> Result<u32, JsError> {
+    check_cards(cards)?;
+    if cards.len() > 5 {
+        return Err(JsError::new("a hand is at most five cards; use bestHand for more"));
+    }
+    Ok(rules::evaluate(cards))
+}
+
+/// Best score a player's hole cards make with the five-card board, or their
+/// seven cards in stud. `variant` is the code the program stores: 0 Hold'em,
+/// 1 Omaha, 2 stud.
+#[wasm_bindgen(js_name = bestHand)]
+pub fn best_hand(variant: u8, hole: &[u8], board: &[u8]) -> Result<u32, JsError> {
+    let variant = GameVariant::from_code(variant);
+    check_cards(hole)?;
+    check_cards(board)?;
+    if hole.len() != variant.hole_cards() {
+        return Err(JsError::new("wrong number of hole cards for the variant"));
+    }
+    let board: [u8; 5] = match (variant.has_board(), board.len()) {
+        (true, 5) => board.try_into().unwrap(),
+        (false, 0) => [0; 5],
+        _ => return Err(JsError::new("the board must have five cards, or none in stud")),
+    };
+    Ok(rules::best_hand(variant, hole, &board))
+}
+
+/// `LegalActions` for JS: what the seat may do and the bet range.
+#[wasm_bindgen]
+pub struct LegalActions {
+    pub on_turn: bool,
+    pub can_fold: bool,
+    pub can_check: bool,
+    pub can_call: bool,
+    pub call_amount: u64,
+    pub can_bet: bool,
+    pub min_bet: u64,
+    pub max_bet: u64,
+}
+
+/// What a seat may do, as `get_legal_actions` reports it. `streetBet` is
+/// what the seat has put in this street and `fixedUnit` the street's
+/// fixed-limit bet size (see `fixedLimitUnit`).
+#[wasm_bindgen(js_name = legalActions)]
+pub fn legal_actions(
+    variant: u8,
+    on_turn: bool,
+    current_bet: u64,
+    pot: u64,
+    street_bet: u64,
+    stack: u64,
+    fixed_unit: u64,
+) -> LegalActions {
+    let legal = rules::legal_actions(
+        GameVariant::from_code(variant),
+        on_turn,
+        current_bet,
+        pot,
+        street_bet,
+        stack,
+        fixed_unit,
+    );
+    LegalActions {
+        on_turn: legal.on_turn,
+        can_fold: legal.can_fold,
+        can_check: legal.can_check,
+        can_call: legal.can_call,
+        call_amount: legal.call_amount,
+        can_bet: legal.can_bet,
+        min_bet: legal.min_bet,
+        max_bet: legal.max_bet,
+    }
+}
+
+/// Largest total a pot-limit bet can make.
+#[wasm_bindgen(js_name = potLimitMax)]
+pub fn pot_limit_max(current_bet: u64, pot: u64, to_call: u64) -> u64 {
+    rules::pot_limit_max(current_bet, pot, to_call)
+}
+
+/// Fixed-limit bet size on `street` (0 is the first betting round).
+#[wasm_bindgen(js_name = fixedLimitUnit)]
+pub fn fixed_limit_unit(big_blind: u64, street: u8) -> u64 {
+    rules::fixed_limit_unit(big_blind, street)
+}
+
+/// The only total a fixed-limit bet can make.
+#[wasm_bindgen(js_name = fixedLimitRaiseTo)]
+pub fn fixed_limit_raise_to(current_bet: u64, unit: u64) -> u64 {
+    rules::fixed_limit_raise_to(current_bet, unit)
+}
@@ -0,0 +1,159 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN } from "@coral-xyz/anchor";
+import { PokerGame } from "../target/types/poker_game";
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import assert from "assert";
+
+/**
+ * Compute-unit budgets for the per-action hot path. A change that pushes an
+ * instruction over its budget fails the suite; raise a budget only with a
+ * reason in the commit.
+ */
+const CU_BUDGET = {
+  bet: 30_000,
+  call: 30_000,
+  fold: 30_000,
+  advance: 30_000,
+};
+
+describe("compute units", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const provider = anchor.AnchorProvider.env();
+  const program = anchor.workspace.pokerGame as Program<PokerGame>;
+
+  const game = Keypair.generate();
+  const players = [Keypair.generate(), Keypair.generate()];
+  const used: Record<string, number> = {};
+
+  /** Units consumed by a confirmed transaction. */
+  async function unitsFor(sig: string): Promise<number> {
+    const tx = await provider.connection.getTransaction(sig, {
+      commitment: "confirmed",
+      maxSupportedTransactionVersion: 0,
+    });
+    return tx.meta.computeUnitsConsumed;
+  }
+
+  function signerFor(pk: PublicKey): Keypair {
+    const player = players.find((p) => p.publicKey.equals(pk));
+    if (!player) throw new Error("Unknown seat");
+    return player;
+  }
+
+  /** Ticket for the game's current hand, street and nonce. */
+  function ticketFor(state: any) {
+    const street = state.phase === 2 ? state.bettingStreet : 0;
+    return { handNumber: state.handNumber, street, nonce: state.actionNonce, clientNonce: new BN(0) };
+  }
+
+  async function act(name: "bet" | "call" | "fold") {
+    const state = await program.account.game.fetch(game.publicKey);
+    const player = signerFor(state.players[state.currentTurn]);
+    const method =
+      name === "bet"
+        ? program.methods.bet(state.currentBet, ticketFor(state))
+        : program.methods[name](ticketFor(state));
+    const sig = await method
+      .accounts({
+        game: game.publicKey,
+        player: player.publicKey,
+        seat: PublicKey.findProgramAddressSync(
+          [Buffer.from("seat"), game.publicKey.toBuffer(), player.publicKey.toBuffer()],
+          program.programId
+        )[0],
+        history: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([player])
+      .rpc({ commitment: "confirmed" });
+    used[name] = await unitsFor(sig);
+  }
+
+  /** Cranks the showdown, trying each wallet until the program accepts the winner. */
+  async function settle() {
+    for (const candidate of players) {
+      try {
+        const sig = await program.methods
+          .advanceGame()
+          .accounts({
+            game: game.publicKey,
+            tournament: null,
+            blindStructure: null,
+            caller: provider.wallet.publicKey,
+            history: null,
+            statsShard: null,
+            leaderboard: null,
+            tableLeaderboard: null,
+            season: null,
+            club: null,
+          })
+          .remainingAccounts([{ pubkey: candidate.publicKey, isSigner: false, isWritable: true }])
+          .rpc({ commitment: "confirmed" });
+        used.advance = Math.max(used.advance ?? 0, await unitsFor(sig));
+        return;
+      } catch (err) {
+        if (!/PlayerNotInGame/.test(String(err))) throw err;
+      }
+    }
+    throw new Error("No wallet was accepted as the winner");
+  }
+
+  async function startRound() {
+    await program.methods
+      .startRound()
+      .accounts({ game: game.publicKey, tournament: null, blindStructure: null, signer: players[0].publicKey })
+      .signers([players[0]])
+      .rpc();
+  }
+
+  before(async () => {
+    await program.methods
+      .initializeGame(new BN(10), new BN(20), { holdem: {} })
+      .accounts({
+        game: game.publicKey,
+        statsShard: null,
+        user: provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([game])
+      .rpc();
+
+    for (const player of players) {
+      const sig = await provider.connection.requestAirdrop(player.publicKey, 1_000_000_000);
+      await provider.connection.confirmTransaction(sig);
+      await program.methods
+        .joinGame(new BN(1000))
+        .accounts({
+          game: game.publicKey,
+          player: player.publicKey,
+          attestation: null,
+          invitation: null,
+          club: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([player])
+        .rpc();
+    }
+  });
+
+  it("Measures bet, call and the showdown crank", async () => {
+    await startRound();
+    await act("bet");
+    await act("call");
+    await settle();
+  });
+
+  it("Measures fold and the uncontested crank", async () => {
+    await startRound();
+    await act("fold");
+    await settle();
+  });
+
+  it("Keeps every action within its budget", () => {
+    console.log("Compute units:", used);
+    for (const [name, budget] of Object.entries(CU_BUDGET)) {
+      assert.ok(used[name] !== undefined, `${name} was not measured`);
+      assert.ok(used[name] <= budget, `${name} used ${used[name]} CU, over its ${budget} budget`);
+    }
+  });
+});
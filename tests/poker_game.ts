@@ -1,8 +1,9 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program, BN } from "@coral-xyz/anchor";
 import { PokerGame } from "../target/types/poker_game";
-import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import { Keypair, PublicKey, SystemProgram, SYSVAR_INSTRUCTIONS_PUBKEY } from "@solana/web3.js";
 import assert from "assert";
+import { createHash } from "crypto";
 
 /** Map on-chain pubkey to the Keypair we control. Extend for more players. */
 function getSignerForPubkey(pk: PublicKey, player1: Keypair, player2: Keypair): Keypair | null {
@@ -11,6 +12,15 @@ function getSignerForPubkey(pk: PublicKey, player1: Keypair, player2: Keypair):
   return null;
 }
 
+/** `GamePhase` codes as stored in the zero-copy game account. */
+const Phase = { waitingForPlayers: 0, dealing: 1, betting: 2, showdown: 3 };
+
+/** Binds a signed action to the game's current hand, street and nonce. */
+function ticketFor(game: any) {
+  const street = game.phase === Phase.betting ? game.bettingStreet : 0;
+  return { handNumber: game.handNumber, street, nonce: game.actionNonce, clientNonce: new BN(0) };
+}
+
 describe("poker_game", () => {
   anchor.setProvider(anchor.AnchorProvider.env());
   const provider = anchor.AnchorProvider.env();
@@ -19,15 +29,36 @@ describe("poker_game", () => {
   const game = Keypair.generate();
   const player1 = Keypair.generate();
   const player2 = Keypair.generate();
+  const shardIndex = game.publicKey.toBuffer()[0] % 8;
+  const [statsShard] = PublicKey.findProgramAddressSync(
+    [Buffer.from("stats_shard"), Buffer.from([shardIndex])],
+    program.programId
+  );
+  const [globalStats] = PublicKey.findProgramAddressSync([Buffer.from("global_stats")], program.programId);
+
+  /** A player's seat account; actions name it, since a session key can sign for it. */
+  const seatFor = (table: PublicKey, player: PublicKey) =>
+    PublicKey.findProgramAddressSync([Buffer.from("seat"), table.toBuffer(), player.toBuffer()], program.programId)[0];
+
+  /** Registry entry claiming a display name, keyed by its normalized hash. */
+  const nameRecordFor = (name: string) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("name"), createHash("sha256").update(name.trim().toLowerCase()).digest()],
+      program.programId
+    )[0];
 
   it("Initializes the game", async () => {
     const smallBlind = new BN(10);
     const bigBlind = new BN(20);
 
+    await program.methods.initStatsShard(shardIndex).accountsPartial({ shard: statsShard }).rpc();
+    await program.methods.initGlobalStats().accountsPartial({ globalStats }).rpc();
+
     const tx = await program.methods
-      .initializeGame(smallBlind, bigBlind)
+      .initializeGame(smallBlind, bigBlind, { holdem: {} })
       .accounts({
         game: game.publicKey,
+        statsShard,
         user: provider.wallet.publicKey,
         systemProgram: SystemProgram.programId,
       })
@@ -41,6 +72,32 @@ describe("poker_game", () => {
     assert.ok(gameAccount.pot.eq(new BN(0)));
     assert.equal(gameAccount.smallBlind.toNumber(), 10);
     assert.equal(gameAccount.bigBlind.toNumber(), 20);
+
+    // The allocated account must match the layout the IDL describes
+    const info = await provider.connection.getAccountInfo(game.publicKey);
+    assert.equal(info.data.length, program.account.game.size);
+  });
+
+  it("Rejects invalid blinds", async () => {
+    for (const [sb, bb, err] of [
+      [0, 0, /ZeroBlind/],
+      [20, 10, /BigBlindTooSmall/],
+    ] as const) {
+      const table = Keypair.generate();
+      await assert.rejects(
+        program.methods
+          .initializeGame(new BN(sb), new BN(bb), { holdem: {} })
+          .accounts({
+            game: table.publicKey,
+            statsShard: null,
+            user: provider.wallet.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([table])
+          .rpc(),
+        err
+      );
+    }
   });
 
   it("Players join the game", async () => {
@@ -50,6 +107,29 @@ describe("poker_game", () => {
       await provider.connection.confirmTransaction(sig);
     }
 
+    // Player 1 has a profile, so tables can show their name
+    const [profile] = PublicKey.findProgramAddressSync(
+      [Buffer.from("profile"), player1.publicKey.toBuffer()],
+      program.programId
+    );
+    await program.methods
+      .createProfile("river_rat", "https://example.com/avatar.png")
+      .accounts({ wallet: player1.publicKey, nameRecord: nameRecordFor("river_rat") })
+      .signers([player1])
+      .rpc();
+    const profileAccount = await program.account.playerProfile.fetch(profile);
+    assert.equal(profileAccount.displayName, "river_rat");
+    assert.ok(profileAccount.wallet.equals(player1.publicKey));
+
+    // Names are unique however they are capitalised or padded
+    await assert.rejects(
+      program.methods
+        .createProfile(" River_Rat", "")
+        .accounts({ wallet: player2.publicKey, nameRecord: nameRecordFor(" River_Rat") })
+        .signers([player2])
+        .rpc()
+    );
+
     // Players join with deposit
     for (const player of [player1, player2]) {
       await program.methods
@@ -57,6 +137,9 @@ describe("poker_game", () => {
         .accounts({
           game: game.publicKey,
           player: player.publicKey,
+          attestation: null,
+          invitation: null,
+          club: null,
           systemProgram: SystemProgram.programId,
         })
         .signers([player])
@@ -68,6 +151,1128 @@ describe("poker_game", () => {
     assert.ok(gameAccount.players[0].equals(player1.publicKey));
     assert.ok(gameAccount.players[1].equals(player2.publicKey));
     assert.ok(gameAccount.playersInRound === 2);
+    assert.ok(gameAccount.stateSeq.eq(new BN(2)), "each join moves the state sequence on");
+
+    // Each join records the seat in a PDA that betting actions check against
+    const [seat] = PublicKey.findProgramAddressSync(
+      [Buffer.from("seat"), game.publicKey.toBuffer(), player2.publicKey.toBuffer()],
+      program.programId
+    );
+    const seatAccount = await program.account.seat.fetch(seat);
+    assert.ok(seatAccount.owner.equals(player2.publicKey));
+    assert.equal(seatAccount.index, 1);
+  });
+
+  it("Rejects a second seat for the same wallet", async () => {
+    await assert.rejects(
+      program.methods
+        .joinGame(new BN(1000))
+        .accounts({
+          game: game.publicKey,
+          player: player1.publicKey,
+          attestation: null,
+          invitation: null,
+          club: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([player1])
+        .rpc(),
+      /AlreadySeated/
+    );
+  });
+
+  it("Gated tables turn away wallets without a pass", async () => {
+    const gated = Keypair.generate();
+    const gatekeeper = Keypair.generate().publicKey;
+
+    await program.methods
+      .initializeGame(new BN(10), new BN(20), { holdem: {} })
+      .accounts({
+        game: gated.publicKey,
+        statsShard: null,
+        user: provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([gated])
+      .rpc();
+    await program.methods
+      .joinGame(new BN(0))
+      .accounts({
+        game: gated.publicKey,
+        player: player1.publicKey,
+        attestation: null,
+        invitation: null,
+        club: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([player1])
+      .rpc();
+    // A seated player is no host until the owner registers them as operator
+    await assert.rejects(
+      program.methods
+        .configureGatekeeper(gatekeeper)
+        .accounts({ game: gated.publicKey, signer: player1.publicKey })
+        .signers([player1])
+        .rpc(),
+      /NotAuthorized/
+    );
+    await program.methods
+      .setOperator(player1.publicKey)
+      .accounts({ game: gated.publicKey, owner: provider.wallet.publicKey })
+      .rpc();
+    await program.methods
+      .configureGatekeeper(gatekeeper)
+      .accounts({ game: gated.publicKey, signer: player1.publicKey })
+      .signers([player1])
+      .rpc();
+
+    // Settings live in the table's config account, not the game
+    const [configKey] = PublicKey.findProgramAddressSync(
+      [Buffer.from("table_config"), gated.publicKey.toBuffer()],
+      program.programId
+    );
+    const config = await program.account.tableConfig.fetch(configKey);
+    assert.ok(config.gatekeeper.equals(gatekeeper));
+    assert.ok(config.operator.equals(player1.publicKey));
+
+    await assert.rejects(
+      program.methods
+        .joinGame(new BN(0))
+        .accounts({
+          game: gated.publicKey,
+          player: player2.publicKey,
+          attestation: null,
+          invitation: null,
+          club: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([player2])
+        .rpc(),
+      /InvalidAttestation/
+    );
+
+    // Player 1 befriends player 2, and as a seated friend can invite them past the gatekeeper
+    const [profile] = PublicKey.findProgramAddressSync(
+      [Buffer.from("profile"), player1.publicKey.toBuffer()],
+      program.programId
+    );
+    const [invitation] = PublicKey.findProgramAddressSync(
+      [Buffer.from("invitation"), gated.publicKey.toBuffer(), player2.publicKey.toBuffer()],
+      program.programId
+    );
+    await program.methods.addFriend(player2.publicKey).accounts({ wallet: player1.publicKey }).signers([player1]).rpc();
+    assert.ok((await program.account.playerProfile.fetch(profile)).friends[0].equals(player2.publicKey));
+    await program.methods
+      .invitePlayer(player2.publicKey)
+      .accountsPartial({ game: gated.publicKey, invitation, inviter: player1.publicKey, inviterProfile: profile })
+      .signers([player1])
+      .rpc();
+
+    // The invitation only opens the door once accepted
+    const joinInvited = () =>
+      program.methods
+        .joinGame(new BN(0))
+        .accounts({
+          game: gated.publicKey,
+          player: player2.publicKey,
+          attestation: null,
+          invitation,
+          club: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([player2])
+        .rpc();
+    await assert.rejects(joinInvited(), /InvalidAttestation/);
+    await program.methods.acceptInvitation().accountsPartial({ invitation, invitee: player2.publicKey }).signers([player2]).rpc();
+    await joinInvited();
+    const seated = await program.account.game.fetch(gated.publicKey);
+    assert.ok(seated.players.some((p: PublicKey) => p.equals(player2.publicKey)));
+
+    await program.methods.removeFriend(player2.publicKey).accounts({ wallet: player1.publicKey }).signers([player1]).rpc();
+    assert.equal((await program.account.playerProfile.fetch(profile)).friends.length, 0);
+  });
+
+  it("Rating-gated tables only seat profiles in range", async () => {
+    const gated = Keypair.generate();
+    await program.methods
+      .initializeGame(new BN(10), new BN(20), { holdem: {} })
+      .accounts({
+        game: gated.publicKey,
+        statsShard: null,
+        user: provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([gated])
+      .rpc();
+    const gate = (min: number, max: number) =>
+      program.methods
+        .configureRatingGate(min, max)
+        .accounts({ game: gated.publicKey, signer: provider.wallet.publicKey })
+        .rpc();
+    const [profile] = PublicKey.findProgramAddressSync(
+      [Buffer.from("profile"), player1.publicKey.toBuffer()],
+      program.programId
+    );
+    // The program finds each player's profile, if any, from their wallet
+    const join = (player: Keypair) =>
+      program.methods
+        .joinGame(new BN(0))
+        .accounts({
+          game: gated.publicKey,
+          player: player.publicKey,
+          attestation: null,
+          invitation: null,
+          club: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([player])
+        .rpc();
+
+    // Profiles start at 1200
+    assert.equal((await program.account.playerProfile.fetch(profile)).rating, 1200);
+    await gate(1300, 0);
+    await assert.rejects(join(player2), /RatingOutOfRange/);
+    await assert.rejects(join(player1), /RatingOutOfRange/);
+    await gate(1000, 1300);
+    await join(player1);
+  });
+
+  it("Club tables only seat club members", async () => {
+    const table = Keypair.generate();
+    await program.methods
+      .initializeGame(new BN(10), new BN(20), { holdem: {} })
+      .accounts({
+        game: table.publicKey,
+        statsShard: null,
+        user: provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([table])
+      .rpc();
+    const [club] = PublicKey.findProgramAddressSync(
+      [Buffer.from("club"), provider.wallet.publicKey.toBuffer(), Buffer.from("friday night")],
+      program.programId
+    );
+    await program.methods.createClub("friday night").accountsPartial({ club }).rpc();
+    await program.methods.setTableClub().accountsPartial({ game: table.publicKey, club }).rpc();
+    const clubAccount = await program.account.club.fetch(club);
+    assert.ok(clubAccount.stats.games.eq(new BN(1)));
+    const [tableConfig] = PublicKey.findProgramAddressSync(
+      [Buffer.from("table_config"), table.publicKey.toBuffer()],
+      program.programId
+    );
+    assert.ok((await program.account.tableConfig.fetch(tableConfig)).club.equals(club));
+
+    const join = () =>
+      program.methods
+        .joinGame(new BN(0))
+        .accounts({
+          game: table.publicKey,
+          player: player2.publicKey,
+          attestation: null,
+          invitation: null,
+          club,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([player2])
+        .rpc();
+    await assert.rejects(join(), /NotClubMember/);
+    await program.methods.addClubMember(player2.publicKey).accounts({ club }).rpc();
+    await join();
+  });
+
+  it("Holds players to the limits they set themselves", async () => {
+    const player = Keypair.generate();
+    const sig = await provider.connection.requestAirdrop(player.publicKey, 1_000_000_000);
+    await provider.connection.confirmTransaction(sig);
+    const [profile] = PublicKey.findProgramAddressSync(
+      [Buffer.from("profile"), player.publicKey.toBuffer()],
+      program.programId
+    );
+    await program.methods
+      .createProfile("careful", "")
+      .accounts({ wallet: player.publicKey, nameRecord: nameRecordFor("careful") })
+      .signers([player])
+      .rpc();
+    const setLimits = (daily: number, excludedUntil: number) =>
+      program.methods
+        .setPlayerLimits(new BN(daily), new BN(excludedUntil))
+        .accounts({ wallet: player.publicKey })
+        .signers([player])
+        .rpc();
+    await setLimits(500, 0);
+
+    const table = Keypair.generate();
+    await program.methods
+      .initializeGame(new BN(10), new BN(20), { holdem: {} })
+      .accounts({
+        game: table.publicKey,
+        statsShard: null,
+        user: provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([table])
+      .rpc();
+    await program.methods
+      .joinGame(new BN(300))
+      .accounts({
+        game: table.publicKey,
+        player: player.publicKey,
+        attestation: null,
+        invitation: null,
+        club: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([player])
+      .rpc();
+    const addChips = (amount: number) =>
+      program.methods
+        .addChips(new BN(amount))
+        .accounts({ game: table.publicKey, player: player.publicKey })
+        .signers([player])
+        .rpc();
+    await assert.rejects(addChips(300), /BuyInLimitReached/);
+    await addChips(200);
+    const seated = await program.account.game.fetch(table.publicKey);
+    assert.ok(seated.stacks[0].eq(new BN(500)));
+
+    // A looser limit waits out the cooling-off period
+    await setLimits(5_000, 0);
+    const { limits } = await program.account.playerProfile.fetch(profile);
+    assert.ok(limits.dailyBuyIn.eq(new BN(500)));
+    assert.ok(limits.pendingDailyBuyIn.eq(new BN(5_000)));
+    await assert.rejects(addChips(1), /BuyInLimitReached/);
+
+    // Closing and recreating the profile would start over without limits,
+    // so it waits until they have been lifted the slow way
+    const closeProfile = () =>
+      program.methods
+        .closeProfile()
+        .accountsPartial({ nameRecord: nameRecordFor("careful"), wallet: player.publicKey })
+        .signers([player])
+        .rpc();
+    await assert.rejects(closeProfile(), /LimitsInForce/);
+    await setLimits(0, 0);
+    await assert.rejects(closeProfile(), /LimitsInForce/);
+    assert.ok((await program.account.playerProfile.fetch(profile)).limits.dailyBuyIn.eq(new BN(500)));
+
+    // Self-exclusion shuts the player out, and cannot be lifted early
+    const now = Math.floor(Date.now() / 1000);
+    await setLimits(500, now + 3600);
+    await setLimits(500, 0);
+    assert.ok((await program.account.playerProfile.fetch(profile)).limits.excludedUntil.eq(new BN(now + 3600)));
+    await assert.rejects(addChips(0), /SelfExcluded/);
+  });
+
+  it("Lets registered spectators tip seated players", async () => {
+    const table = Keypair.generate();
+    await program.methods
+      .initializeGame(new BN(10), new BN(20), { holdem: {} })
+      .accounts({
+        game: table.publicKey,
+        statsShard: null,
+        user: provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([table])
+      .rpc();
+    await program.methods
+      .joinGame(new BN(1000))
+      .accounts({
+        game: table.publicKey,
+        player: player1.publicKey,
+        attestation: null,
+        invitation: null,
+        club: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([player1])
+      .rpc();
+
+    const tip = (amount: number) =>
+      program.methods
+        .tipPlayer(0, new BN(amount))
+        .accounts({ game: table.publicKey })
+        .rpc();
+    await assert.rejects(tip(50));
+    await program.methods.registerSpectator().accounts({ game: table.publicKey }).rpc();
+    await assert.rejects(tip(0), /InvalidTip/);
+    await tip(50);
+
+    const state = await program.account.game.fetch(table.publicKey);
+    assert.ok(state.stacks[0].eq(new BN(1050)));
+    const [spectator] = PublicKey.findProgramAddressSync(
+      [Buffer.from("spectator"), table.publicKey.toBuffer(), provider.wallet.publicKey.toBuffer()],
+      program.programId
+    );
+    assert.ok((await program.account.spectator.fetch(spectator)).tipped.eq(new BN(50)));
+
+    // The v2 entrypoint also takes the tipper's profile, to apply their limits
+    await program.methods
+      .tipPlayerV2(0, new BN(25))
+      .accounts({ tip: { game: table.publicKey }, memoConfig: null, memoProgram: null })
+      .rpc();
+    assert.ok((await program.account.game.fetch(table.publicKey)).stacks[0].eq(new BN(1075)));
+  });
+
+  it("Cashes out a player's stack when they leave the table", async () => {
+    const table = Keypair.generate();
+    await program.methods
+      .initializeGame(new BN(10), new BN(20), { holdem: {} })
+      .accounts({
+        game: table.publicKey,
+        statsShard: null,
+        user: provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([table])
+      .rpc();
+    const join = () =>
+      program.methods
+        .joinGame(new BN(1000))
+        .accounts({
+          game: table.publicKey,
+          player: player1.publicKey,
+          attestation: null,
+          invitation: null,
+          club: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([player1])
+        .rpc();
+    await join();
+
+    const seat = seatFor(table.publicKey, player1.publicKey);
+    const before = await provider.connection.getBalance(player1.publicKey);
+    await program.methods
+      .leaveTable()
+      .accounts({ game: table.publicKey, player: player1.publicKey })
+      .signers([player1])
+      .rpc();
+
+    // The stack and the seat's rent both come back
+    assert.ok((await provider.connection.getBalance(player1.publicKey)) > before + 1000);
+    assert.equal(await provider.connection.getAccountInfo(seat), null);
+    const state = await program.account.game.fetch(table.publicKey);
+    assert.ok(state.players[0].equals(PublicKey.default));
+    assert.ok(state.stacks[0].eq(new BN(0)));
+
+    // The seat is free to take again
+    await join();
+  });
+
+  it("Closes a stale table, cashing out whoever is still seated", async () => {
+    const table = Keypair.generate();
+    await program.methods
+      .initializeGame(new BN(10), new BN(20), { holdem: {} })
+      .accounts({
+        game: table.publicKey,
+        statsShard: null,
+        user: provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([table])
+      .rpc();
+    await program.methods
+      .joinGame(new BN(1000))
+      .accounts({
+        game: table.publicKey,
+        player: player1.publicKey,
+        attestation: null,
+        invitation: null,
+        club: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([player1])
+      .rpc();
+
+    const seasonId = 2;
+    const [season] = PublicKey.findProgramAddressSync(
+      [Buffer.from("season"), provider.wallet.publicKey.toBuffer(), new BN(seasonId).toArrayLike(Buffer, "le", 4)],
+      program.programId
+    );
+    const now = Math.floor(Date.now() / 1000);
+    await program.methods
+      .createSeason(
+        seasonId,
+        new BN(now - 60),
+        new BN(now + 3600),
+        { handPlayed: 1, handWon: 10, finishes: [100, 50, 25, 0, 0] },
+        [10_000, 0, 0, 0, 0]
+      )
+      .accountsPartial({ season })
+      .rpc();
+    await program.methods.addSeasonVenue(table.publicKey).accounts({ season }).rpc();
+
+    const seat = seatFor(table.publicKey, player1.publicKey);
+    const close = () =>
+      program.methods
+        .closeStaleTable()
+        .accounts({ game: table.publicKey, caller: provider.wallet.publicKey, season, memoConfig: null, memoProgram: null })
+        .remainingAccounts([
+          { pubkey: player1.publicKey, isSigner: false, isWritable: true },
+          { pubkey: seat, isSigner: false, isWritable: true },
+        ])
+        .rpc();
+    await assert.rejects(close(), /TableNotStale/);
+
+    // The validator runs short epochs, so STALE_TABLE_EPOCHS pass in a couple of minutes
+    const { lastActiveEpoch } = await program.account.game.fetch(table.publicKey);
+    while ((await provider.connection.getEpochInfo()).epoch < lastActiveEpoch.toNumber() + 10) {
+      await new Promise((resolve) => setTimeout(resolve, 2_000));
+    }
+
+    const before = await provider.connection.getBalance(player1.publicKey);
+    await close();
+    assert.equal(await provider.connection.getAccountInfo(table.publicKey), null);
+    assert.equal(await provider.connection.getAccountInfo(seat), null);
+    assert.ok((await provider.connection.getBalance(player1.publicKey)) > before + 1000);
+
+    // The table's venue slot is freed in place for the next one
+    const { venues, venueCount } = await program.account.season.fetch(season);
+    assert.equal(venueCount, 1);
+    assert.ok(venues[0].equals(PublicKey.default));
+  });
+
+  it("Own-funds tables want the instructions sysvar with each deposit", async () => {
+    const table = Keypair.generate();
+    await program.methods
+      .initializeGame(new BN(10), new BN(20), { holdem: {} })
+      .accounts({
+        game: table.publicKey,
+        statsShard: null,
+        user: provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([table])
+      .rpc();
+    await program.methods
+      .configureOwnFunds(true)
+      .accounts({ game: table.publicKey, signer: provider.wallet.publicKey })
+      .rpc();
+
+    const joinAccounts = {
+      game: table.publicKey,
+      player: player2.publicKey,
+      attestation: null,
+      invitation: null,
+      club: null,
+      systemProgram: SystemProgram.programId,
+    };
+    // The sysvar only fits the v2 entrypoint, so the frozen one is turned away
+    await assert.rejects(
+      program.methods.joinGame(new BN(1000)).accounts(joinAccounts).signers([player2]).rpc(),
+      /OwnFundsRequired/
+    );
+    const join = (proof: boolean) =>
+      program.methods
+        .joinGameV2(new BN(1000))
+        .accounts({
+          join: joinAccounts,
+          instructionsSysvar: proof ? SYSVAR_INSTRUCTIONS_PUBKEY : null,
+          priceGate: null,
+          priceFeed: null,
+          memoConfig: null,
+          memoProgram: null,
+        })
+        .signers([player2])
+        .rpc();
+    await assert.rejects(join(false), /OwnFundsRequired/);
+    await join(true);
+    assert.ok((await program.account.game.fetch(table.publicKey)).players[0].equals(player2.publicKey));
+
+    // Top-ups too: add_chips has no config to check, so the table's flag turns it away
+    await assert.rejects(
+      program.methods
+        .addChips(new BN(100))
+        .accounts({ game: table.publicKey, player: player2.publicKey })
+        .signers([player2])
+        .rpc(),
+      /AddChipsV2Required/
+    );
+    await program.methods
+      .addChipsV2(new BN(100))
+      .accounts({
+        chips: { game: table.publicKey, player: player2.publicKey },
+        instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+        memoConfig: null,
+        memoProgram: null,
+      })
+      .signers([player2])
+      .rpc();
+    assert.ok((await program.account.game.fetch(table.publicKey)).stacks[0].eq(new BN(1100)));
+  });
+
+  it("USD-priced tables only take a SOL/USD Pyth feed", async () => {
+    const table = Keypair.generate();
+    await program.methods
+      .initializeGame(new BN(10), new BN(20), { holdem: {} })
+      .accounts({
+        game: table.publicKey,
+        statsShard: null,
+        user: provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([table])
+      .rpc();
+    const [priceGate] = PublicKey.findProgramAddressSync(
+      [Buffer.from("price_gate"), table.publicKey.toBuffer()],
+      program.programId
+    );
+    const configure = (feed: PublicKey, priceFeed: PublicKey | null) =>
+      program.methods
+        .configureUsdBuyIn(feed, new BN(2_000), new BN(10_000), new BN(60))
+        .accounts({ game: table.publicKey, priceFeed, signer: provider.wallet.publicKey })
+        .rpc();
+    const join = () =>
+      program.methods
+        .joinGame(new BN(1000))
+        .accounts({
+          game: table.publicKey,
+          player: player2.publicKey,
+          attestation: null,
+          invitation: null,
+          club: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([player2])
+        .rpc();
+
+    // Any account but a Pyth SOL/USD update is refused as the feed, and
+    // the feed being set must come with it
+    const feed = Keypair.generate().publicKey;
+    await assert.rejects(configure(feed, feed), /InvalidPriceFeed/);
+    await assert.rejects(configure(provider.wallet.publicKey, provider.wallet.publicKey), /InvalidPriceFeed/);
+    await assert.rejects(configure(feed, null), /InvalidPriceFeed/);
+
+    // Without a feed the range is off and deposits go through as usual
+    await configure(PublicKey.default, null);
+    assert.equal((await program.account.priceGate.fetch(priceGate)).maxBuyInUsd.toNumber(), 10_000);
+    const [tableConfig] = PublicKey.findProgramAddressSync(
+      [Buffer.from("table_config"), table.publicKey.toBuffer()],
+      program.programId
+    );
+    assert.equal((await program.account.tableConfig.fetch(tableConfig)).usdBuyIn, 0);
+    await assert.rejects(
+      program.methods.getUsdStakes().accounts({ game: table.publicKey, feed: PublicKey.default }).view(),
+      /InvalidPriceFeed/
+    );
+    await join();
+  });
+
+  it("Session keys act for a seat until they expire or are revoked", async () => {
+    const table = Keypair.generate();
+    await program.methods
+      .initializeGame(new BN(10), new BN(20), { holdem: {} })
+      .accounts({
+        game: table.publicKey,
+        statsShard: null,
+        user: provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([table])
+      .rpc();
+    for (const player of [player1, player2]) {
+      await program.methods
+        .joinGame(new BN(1000))
+        .accounts({
+          game: table.publicKey,
+          player: player.publicKey,
+          attestation: null,
+          invitation: null,
+          club: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([player])
+        .rpc();
+    }
+    await program.methods
+      .startRound()
+      .accounts({ game: table.publicKey, tournament: null, blindStructure: null, signer: player1.publicKey })
+      .signers([player1])
+      .rpc();
+
+    const state = await program.account.game.fetch(table.publicKey);
+    const owner = getSignerForPubkey(state.players[state.currentTurn], player1, player2);
+    const session = Keypair.generate();
+    const authorize = (key: PublicKey, minutes: number) =>
+      program.methods
+        .authorizeSession(key, minutes)
+        .accounts({ game: table.publicKey, player: owner.publicKey })
+        .signers([owner])
+        .rpc();
+    const fold = () =>
+      program.methods
+        .fold(ticketFor(state))
+        .accounts({
+          game: table.publicKey,
+          player: session.publicKey,
+          seat: seatFor(table.publicKey, owner.publicKey),
+          history: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([session])
+        .rpc();
+
+    await assert.rejects(authorize(session.publicKey, 24 * 60), /InvalidSession/);
+    await authorize(session.publicKey, 30);
+    await authorize(PublicKey.default, 0);
+    await assert.rejects(fold(), /SeatMismatch/);
+    await authorize(session.publicKey, 30);
+    await fold();
+    const after = await program.account.game.fetch(table.publicKey);
+    assert.equal(after.folded[state.currentTurn], 1);
+  });
+
+  it("Runs a self-driving table from one automation instruction", async () => {
+    const table = Keypair.generate();
+    await program.methods
+      .initializeGame(new BN(10), new BN(20), { holdem: {} })
+      .accounts({
+        game: table.publicKey,
+        statsShard: null,
+        user: provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([table])
+      .rpc();
+    for (const player of [player1, player2]) {
+      await program.methods
+        .joinGame(new BN(1000))
+        .accounts({
+          game: table.publicKey,
+          player: player.publicKey,
+          attestation: null,
+          invitation: null,
+          club: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([player])
+        .rpc();
+    }
+    await program.methods
+      .configureAutoDeal(true, 2, new BN(0))
+      .accounts({ game: table.publicKey, signer: provider.wallet.publicKey })
+      .rpc();
+
+    // The same fixed account list every time, as a thread would send it
+    const tick = () =>
+      program.methods
+        .runAutomation()
+        .accounts({
+          advance: {
+            game: table.publicKey,
+            tournament: null,
+            blindStructure: null,
+            caller: provider.wallet.publicKey,
+            history: null,
+            statsShard: null,
+            leaderboard: null,
+            tableLeaderboard: null,
+            season: null,
+            club: null,
+          },
+          dealerBot: null,
+          payoutRoute: null,
+          globalConfig: null,
+          stakingDeposit: null,
+          stakingProgram: null,
+          memoConfig: null,
+          memoProgram: null,
+        })
+        .rpc();
+    await tick();
+    let state = await program.account.game.fetch(table.publicKey);
+    assert.equal(state.handNumber.toNumber(), 1);
+
+    const folder = getSignerForPubkey(state.players[state.currentTurn], player1, player2);
+    await program.methods
+      .fold(ticketFor(state))
+      .accounts({
+        game: table.publicKey,
+        player: folder.publicKey,
+        seat: seatFor(table.publicKey, folder.publicKey),
+        history: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([folder])
+      .rpc();
+
+    // Settles without the winner's wallet: the pot stays on the table
+    await tick();
+    state = await program.account.game.fetch(table.publicKey);
+    assert.equal(state.phase, Phase.waitingForPlayers);
+    assert.ok(state.stacks[0].add(state.stacks[1]).eq(new BN(2000)));
+    await tick();
+    assert.equal((await program.account.game.fetch(table.publicKey)).handNumber.toNumber(), 2);
+  });
+
+  it("Limits cranks to the host, players and scoped dealer bots", async () => {
+    const table = Keypair.generate();
+    const bot = Keypair.generate();
+    const outsider = Keypair.generate();
+    for (const wallet of [bot, outsider]) {
+      const sig = await provider.connection.requestAirdrop(wallet.publicKey, 1_000_000_000);
+      await provider.connection.confirmTransaction(sig);
+    }
+    await program.methods
+      .initializeGame(new BN(10), new BN(20), { holdem: {} })
+      .accounts({
+        game: table.publicKey,
+        statsShard: null,
+        user: provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([table])
+      .rpc();
+    for (const player of [player1, player2]) {
+      await program.methods
+        .joinGame(new BN(1000))
+        .accounts({
+          game: table.publicKey,
+          player: player.publicKey,
+          attestation: null,
+          invitation: null,
+          club: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([player])
+        .rpc();
+    }
+    await program.methods
+      .configureAutoDeal(true, 2, new BN(0))
+      .accounts({ game: table.publicKey, signer: provider.wallet.publicKey })
+      .rpc();
+
+    const [dealerBot] = PublicKey.findProgramAddressSync(
+      [Buffer.from("dealer_bot"), table.publicKey.toBuffer(), bot.publicKey.toBuffer()],
+      program.programId
+    );
+    const register = (settle: boolean) =>
+      program.methods
+        .registerDealerBot(bot.publicKey, { deal: true, timeOut: true, settle })
+        .accounts({ game: table.publicKey, signer: provider.wallet.publicKey })
+        .rpc();
+    const tick = (caller: Keypair, bot: PublicKey | null) =>
+      program.methods
+        .runAutomation()
+        .accounts({
+          advance: {
+            game: table.publicKey,
+            tournament: null,
+            blindStructure: null,
+            caller: caller.publicKey,
+            history: null,
+            statsShard: null,
+            leaderboard: null,
+            tableLeaderboard: null,
+            season: null,
+            club: null,
+          },
+          dealerBot: bot,
+          payoutRoute: null,
+          globalConfig: null,
+          stakingDeposit: null,
+          stakingProgram: null,
+          memoConfig: null,
+          memoProgram: null,
+        })
+        .signers([caller])
+        .rpc();
+
+    await register(false);
+    const record = await program.account.dealerBot.fetch(dealerBot);
+    assert.ok(record.bot.equals(bot.publicKey));
+    assert.equal(record.scopes.settle, false);
+
+    // Only the bot's own record counts, and only for its scopes
+    await assert.rejects(tick(outsider, null), /CrankNotAuthorized/);
+    await assert.rejects(tick(outsider, dealerBot), /ConstraintSeeds/);
+    // start_round has no place for the record; bots deal through start_round_v2
+    await assert.rejects(
+      program.methods
+        .startRound()
+        .accounts({ game: table.publicKey, tournament: null, blindStructure: null, signer: bot.publicKey })
+        .signers([bot])
+        .rpc(),
+      /NotAuthorized/
+    );
+    await tick(bot, dealerBot);
+    let state = await program.account.game.fetch(table.publicKey);
+    assert.equal(state.handNumber.toNumber(), 1);
+
+    const folder = getSignerForPubkey(state.players[state.currentTurn], player1, player2);
+    await program.methods
+      .fold(ticketFor(state))
+      .accounts({
+        game: table.publicKey,
+        player: folder.publicKey,
+        seat: seatFor(table.publicKey, folder.publicKey),
+        history: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([folder])
+      .rpc();
+    await assert.rejects(tick(bot, dealerBot), /CrankNotAuthorized/);
+    await register(true);
+    await tick(bot, dealerBot);
+    state = await program.account.game.fetch(table.publicKey);
+    assert.equal(state.phase, Phase.waitingForPlayers);
+
+    // With the last bot gone the table is open to anyone again
+    await program.methods
+      .revokeDealerBot()
+      .accounts({ game: table.publicKey, dealerBot, signer: provider.wallet.publicKey })
+      .rpc();
+    assert.equal((await program.account.tableConfig.fetch(
+      PublicKey.findProgramAddressSync([Buffer.from("table_config"), table.publicKey.toBuffer()], program.programId)[0]
+    )).dealerBots, 0);
+    await tick(outsider, null);
+    assert.equal((await program.account.game.fetch(table.publicKey)).handNumber.toNumber(), 2);
+  });
+
+  it("Routes winnings only into allowlisted staking programs", async () => {
+    const table = Keypair.generate();
+    const staking = Keypair.generate().publicKey;
+    const deposit = Keypair.generate().publicKey;
+    const [globalConfig] = PublicKey.findProgramAddressSync([Buffer.from("global_config")], program.programId);
+    const [programData] = PublicKey.findProgramAddressSync(
+      [program.programId.toBuffer()],
+      new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111")
+    );
+    await program.methods
+      .initGlobalConfig()
+      .accountsPartial({ globalConfig, program: program.programId, programData, authority: provider.wallet.publicKey })
+      .rpc();
+
+    await program.methods
+      .initializeGame(new BN(10), new BN(20), { holdem: {} })
+      .accounts({
+        game: table.publicKey,
+        statsShard: null,
+        user: provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([table])
+      .rpc();
+    for (const player of [player1, player2]) {
+      await program.methods
+        .joinGame(new BN(1000))
+        .accounts({
+          game: table.publicKey,
+          player: player.publicKey,
+          attestation: null,
+          invitation: null,
+          club: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([player])
+        .rpc();
+    }
+
+    const allow = (programs: PublicKey[]) =>
+      program.methods
+        .configureStakingAllowlist(programs.map((p) => ({ program: p, depositIx: Array(8).fill(1) })))
+        .accountsPartial({ globalConfig, authority: provider.wallet.publicKey })
+        .rpc();
+    const route = (player: Keypair) =>
+      program.methods
+        .setPayoutRoute(staking, deposit)
+        .accountsPartial({ game: table.publicKey, seat: seatFor(table.publicKey, player.publicKey), player: player.publicKey })
+        .signers([player])
+        .rpc();
+    await assert.rejects(route(player1), /StakingProgramNotAllowed/);
+    await allow([staking]);
+    for (const player of [player1, player2]) await route(player);
+    assert.equal((await program.account.game.fetch(table.publicKey)).escrowSeats, 0b11);
+
+    await program.methods
+      .startRound()
+      .accounts({ game: table.publicKey, tournament: null, blindStructure: null, signer: player1.publicKey })
+      .signers([player1])
+      .rpc();
+    const state = await program.account.game.fetch(table.publicKey);
+    const folder = getSignerForPubkey(state.players[state.currentTurn], player1, player2);
+    const winner = folder === player1 ? player2 : player1;
+    await program.methods
+      .fold(ticketFor(state))
+      .accounts({
+        game: table.publicKey,
+        player: folder.publicKey,
+        seat: seatFor(table.publicKey, folder.publicKey),
+        history: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([folder])
+      .rpc();
+
+    // Once the program is delisted the pot goes back to the wallet
+    await allow([]);
+    const [payoutRoute] = PublicKey.findProgramAddressSync(
+      [Buffer.from("payout_route"), table.publicKey.toBuffer(), winner.publicKey.toBuffer()],
+      program.programId
+    );
+    const before = await provider.connection.getBalance(winner.publicKey);
+    await program.methods
+      .advanceGameV2()
+      .accounts({
+        advance: {
+          game: table.publicKey,
+          tournament: null,
+          blindStructure: null,
+          caller: provider.wallet.publicKey,
+          history: null,
+          statsShard: null,
+          leaderboard: null,
+          tableLeaderboard: null,
+          season: null,
+          club: null,
+        },
+        dealerBot: null,
+        payoutRoute,
+        globalConfig,
+        stakingDeposit: deposit,
+        stakingProgram: staking,
+        memoConfig: null,
+        memoProgram: null,
+      })
+      .remainingAccounts([{ pubkey: winner.publicKey, isSigner: false, isWritable: true }])
+      .rpc();
+    assert.ok((await provider.connection.getBalance(winner.publicKey)) > before);
+  });
+
+  it("Tags deposits with the operator's memo", async () => {
+    const table = Keypair.generate();
+    const memoProgram = new PublicKey("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+    const [memoConfig] = PublicKey.findProgramAddressSync(
+      [Buffer.from("memo_config"), table.publicKey.toBuffer()],
+      program.programId
+    );
+    await program.methods
+      .initializeGame(new BN(10), new BN(20), { holdem: {} })
+      .accounts({
+        game: table.publicKey,
+        statsShard: null,
+        user: provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([table])
+      .rpc();
+    await assert.rejects(
+      program.methods
+        .configureMemos("x".repeat(65), [{ deposit: {} }])
+        .accounts({ game: table.publicKey, signer: provider.wallet.publicKey })
+        .rpc(),
+      /MemoTagTooLong/
+    );
+    await program.methods
+      .configureMemos("LIC-0042", [{ deposit: {} }, { payout: {} }])
+      .accounts({ game: table.publicKey, signer: provider.wallet.publicKey })
+      .rpc();
+    const [tableConfig] = PublicKey.findProgramAddressSync(
+      [Buffer.from("table_config"), table.publicKey.toBuffer()],
+      program.programId
+    );
+    assert.equal((await program.account.tableConfig.fetch(tableConfig)).memoMovements, 0b011);
+
+    const joinAccounts = (player: Keypair) => ({
+      game: table.publicKey,
+      player: player.publicKey,
+      attestation: null,
+      invitation: null,
+      club: null,
+      systemProgram: SystemProgram.programId,
+    });
+    const logs = async (sig: string) =>
+      (await provider.connection.getTransaction(sig, { commitment: "confirmed", maxSupportedTransactionVersion: 0 }))
+        .meta.logMessages;
+
+    // join_game predates memos, so it seats the player untagged
+    const untagged = await program.methods
+      .joinGame(new BN(1000))
+      .accounts(joinAccounts(player2))
+      .signers([player2])
+      .rpc({ commitment: "confirmed" });
+    assert.ok(!(await logs(untagged)).some((line) => line.includes("LIC-0042")));
+
+    const join = (player: Keypair, memo: boolean) =>
+      program.methods
+        .joinGameV2(new BN(1000))
+        .accounts({
+          join: joinAccounts(player),
+          instructionsSysvar: null,
+          priceGate: null,
+          priceFeed: null,
+          memoConfig: memo ? memoConfig : null,
+          memoProgram: memo ? memoProgram : null,
+        })
+        .signers([player])
+        .rpc({ commitment: "confirmed" });
+    await assert.rejects(join(player1, false), /MemoRequired/);
+    const sig = await join(player1, true);
+    const memo = `LIC-0042:deposit:${player1.publicKey.toBase58()}:1000`;
+    assert.ok((await logs(sig)).some((line) => line.includes(memo)));
+  });
+
+  it("Renames a profile and frees the old name", async () => {
+    const renamer = Keypair.generate();
+    const sig = await provider.connection.requestAirdrop(renamer.publicKey, 1_000_000_000);
+    await provider.connection.confirmTransaction(sig);
+    const create = (wallet: Keypair, name: string) =>
+      program.methods
+        .createProfile(name, "")
+        .accounts({ wallet: wallet.publicKey, nameRecord: nameRecordFor(name) })
+        .signers([wallet])
+        .rpc();
+    await create(renamer, "nit");
+    await program.methods
+      .renameProfile("maniac")
+      .accountsPartial({ oldName: nameRecordFor("nit"), newName: nameRecordFor("maniac"), wallet: renamer.publicKey })
+      .signers([renamer])
+      .rpc();
+    assert.ok((await program.account.nameRecord.fetch(nameRecordFor("maniac"))).owner.equals(renamer.publicKey));
+    assert.equal(await provider.connection.getAccountInfo(nameRecordFor("nit")), null);
+
+    // Closing the profile releases its name too
+    await program.methods
+      .closeProfile()
+      .accountsPartial({ nameRecord: nameRecordFor("maniac"), wallet: renamer.publicKey })
+      .signers([renamer])
+      .rpc();
+    assert.equal(await provider.connection.getAccountInfo(nameRecordFor("maniac")), null);
+    await create(player2, "maniac");
+  });
+
+  it("Keeps a player's notes on opponents", async () => {
+    // The program stores whatever bytes the client encrypted; these stand in for ciphertext
+    const [note] = PublicKey.findProgramAddressSync(
+      [Buffer.from("note"), player1.publicKey.toBuffer(), player2.publicKey.toBuffer()],
+      program.programId
+    );
+    const sizeOf = async () => (await provider.connection.getAccountInfo(note)).data.length;
+    await program.methods
+      .createNote(player2.publicKey, Buffer.from("calls too wide"))
+      .accountsPartial({ note, owner: player1.publicKey })
+      .signers([player1])
+      .rpc();
+    const small = await sizeOf();
+
+    const longer = Buffer.alloc(300, 7);
+    await program.methods.writeNote(longer).accountsPartial({ note, owner: player1.publicKey }).signers([player1]).rpc();
+    assert.ok(Buffer.from((await program.account.playerNote.fetch(note)).ciphertext).equals(longer));
+    assert.equal(await sizeOf(), small + 300 - "calls too wide".length);
+
+    // Only the owner can touch their notes
+    await assert.rejects(
+      program.methods.writeNote(Buffer.from("x")).accountsPartial({ note, owner: player2.publicKey }).signers([player2]).rpc()
+    );
+    await assert.rejects(
+      program.methods.writeNote(Buffer.alloc(1025)).accountsPartial({ note, owner: player1.publicKey }).signers([player1]).rpc(),
+      /NoteTooLong/
+    );
+
+    await program.methods.deleteNote().accountsPartial({ note, owner: player1.publicKey }).signers([player1]).rpc();
+    assert.equal(await provider.connection.getAccountInfo(note), null);
   });
 
   it("Starts the round", async () => {
@@ -75,17 +1280,48 @@ describe("poker_game", () => {
       .startRound()
       .accounts({
         game: game.publicKey,
+        tournament: null,
+        blindStructure: null,
+        signer: player1.publicKey,
       })
+      .signers([player1])
       .rpc();
 
     const gameAccount = await program.account.game.fetch(game.publicKey);
     console.log("StartRound - currentTurn:", gameAccount.currentTurn);
-    assert.ok(gameAccount.isActive);
-    assert.ok(gameAccount.bettingRound === 0);
+    assert.equal(gameAccount.phase, Phase.betting);
+    assert.equal(gameAccount.bettingStreet, 0);
     assert.ok(typeof gameAccount.currentTurn === "number");
     assert.ok(gameAccount.currentBet.eq(new BN(20)));
   });
 
+  it("Limits each seat's emotes per street", async () => {
+    const before = await program.account.game.fetch(game.publicKey);
+    const emote = () =>
+      program.methods
+        .sendEmote({ niceHand: {} })
+        .accountsPartial({ game: game.publicKey, player: player2.publicKey })
+        .signers([player2])
+        .rpc();
+    await emote();
+    await emote();
+    await assert.rejects(emote(), /EmoteLimit/);
+
+    // Emotes leave the table untouched
+    const after = await program.account.game.fetch(game.publicKey);
+    assert.ok(after.stateSeq.eq(before.stateSeq));
+  });
+
+  it("Rejects a timeout before the turn clock runs out", async () => {
+    await assert.rejects(
+      program.methods
+        .forceTimeout()
+        .accounts({ game: game.publicKey, caller: provider.wallet.publicKey, dealerBot: null, memoConfig: null, memoProgram: null })
+        .rpc(),
+      /TurnNotExpired/
+    );
+  });
+
   it("Player actions: bet -> call (no fold) -> reveal winner", async () => {
     // 1) Bet: fetch currentTurn and have that player bet
     let gameAccount = await program.account.game.fetch(game.publicKey);
@@ -96,21 +1332,71 @@ describe("poker_game", () => {
     const bettor = getSignerForPubkey(bettorPubkey, player1, player2);
     if (!bettor) throw new Error("Unknown bettor signer");
 
+    // Record this hand in an on-chain history
+    const [history] = PublicKey.findProgramAddressSync(
+      [Buffer.from("hand_history"), game.publicKey.toBuffer(), gameAccount.handNumber.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
     await program.methods
-      .bet(new BN(20))
-      .accounts({
-        game: game.publicKey,
-        player: bettor.publicKey,
-        systemProgram: SystemProgram.programId,
-      })
-      .signers([bettor])
+      .openHandHistory()
+      .accountsPartial({ game: game.publicKey, history, payer: provider.wallet.publicKey })
       .rpc();
 
+    // An action signed for another point in the game is refused
+    await assert.rejects(
+      program.methods
+        .bet(new BN(20), { ...ticketFor(gameAccount), nonce: gameAccount.actionNonce.addn(1) })
+        .accounts({
+          game: game.publicKey,
+          player: bettor.publicKey,
+          seat: seatFor(game.publicKey, bettor.publicKey),
+          history: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([bettor])
+        .rpc(),
+      /StaleAction/
+    );
+
+    // Nobody can put in more than the 1000 lamports they sat down with
+    await assert.rejects(
+      program.methods
+        .bet(new BN(5000), ticketFor(gameAccount))
+        .accounts({
+          game: game.publicKey,
+          player: bettor.publicKey,
+          seat: seatFor(game.publicKey, bettor.publicKey),
+          history: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([bettor])
+        .rpc(),
+      /InsufficientStack/
+    );
+
+    // A retry carrying the same client nonce lands once. The retry leaves
+    // out the history so it is a distinct transaction, as a resend would be.
+    const betTicket = { ...ticketFor(gameAccount), clientNonce: new BN(7) };
+    for (const attemptHistory of [history, null]) {
+      await program.methods
+        .bet(new BN(20), betTicket)
+        .accounts({
+          game: game.publicKey,
+          player: bettor.publicKey,
+          seat: seatFor(game.publicKey, bettor.publicKey),
+          history: attemptHistory,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([bettor])
+        .rpc();
+    }
+
     gameAccount = await program.account.game.fetch(game.publicKey);
     console.log("After bet - currentTurn:", gameAccount.currentTurn);
 
     const bettorRecordedIndex = gameAccount.players.findIndex((p: PublicKey) => p.equals(bettor.publicKey));
     assert.ok(gameAccount.playerBets[bettorRecordedIndex].eq(new BN(20)));
+    assert.ok(gameAccount.stacks[bettorRecordedIndex].eq(new BN(980)));
 
     // 2) Call: use the currentTurn signer (whoever the contract says)
     const callerIndex: number = gameAccount.currentTurn;
@@ -118,15 +1404,55 @@ describe("poker_game", () => {
     const caller = getSignerForPubkey(callerPubkey, player1, player2);
     if (!caller) throw new Error("Unknown caller signer");
 
-    await program.methods
-      .call()
-      .accounts({
-        game: game.publicKey,
-        player: caller.publicKey,
-        systemProgram: SystemProgram.programId,
-      })
-      .signers([caller])
-      .rpc();
+    // The view instructions agree with what the program will accept
+    const legal = await program.methods.getLegalActions(callerIndex).accounts({ game: game.publicKey }).view();
+    assert.ok(legal.onTurn && legal.canCall && !legal.canCheck);
+    assert.ok(legal.callAmount.eq(new BN(20)));
+    const breakdown = await program.methods.getPotBreakdown().accounts({ game: game.publicKey }).view();
+    assert.ok(breakdown.pot.eq(new BN(20)));
+    assert.ok(breakdown.streetBets[bettorRecordedIndex].eq(new BN(20)));
+    const snapshot = await program.methods.getTableSnapshot().accounts({ game: game.publicKey }).view();
+    assert.equal(snapshot.currentTurn, callerIndex);
+    assert.ok(snapshot.pot.eq(breakdown.pot));
+    assert.deepEqual(snapshot.legalActions, legal);
+    assert.ok(snapshot.seats[bettorRecordedIndex].streetBet.eq(new BN(20)));
+
+    // A dry run previews the call without touching the table
+    const preview = await program.methods
+      .dryRunAction(callerIndex, { call: {} }, ticketFor(gameAccount))
+      .accounts({ game: game.publicKey })
+      .view();
+    assert.ok(preview.pot.eq(new BN(40)));
+    assert.ok(preview.streetBet.eq(new BN(20)));
+    assert.ok(preview.stack.eq(gameAccount.stacks[callerIndex].sub(new BN(20))));
+    const untouched = await program.account.game.fetch(game.publicKey);
+    assert.ok(untouched.pot.eq(new BN(20)));
+    assert.ok(untouched.actionNonce.eq(gameAccount.actionNonce));
+
+    // A client built before client nonces calls through the v1 entrypoint,
+    // which stops working once the host deprecates it
+    const { clientNonce, ...legacyTicket } = ticketFor(gameAccount);
+    const callV1 = () =>
+      program.methods
+        .callV1(legacyTicket)
+        .accounts({
+          game: game.publicKey,
+          player: caller.publicKey,
+          seat: seatFor(game.publicKey, caller.publicKey),
+          history,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([caller])
+        .rpc();
+    const deprecate = (deprecated: boolean) =>
+      program.methods
+        .deprecateEntrypoint({ actionsV1: {} }, deprecated)
+        .accounts({ game: game.publicKey, signer: provider.wallet.publicKey })
+        .rpc();
+    await deprecate(true);
+    await assert.rejects(callV1(), /InstructionDeprecated/);
+    await deprecate(false);
+    await callV1();
 
     gameAccount = await program.account.game.fetch(game.publicKey);
     console.log("After call - currentTurn:", gameAccount.currentTurn);
@@ -134,20 +1460,300 @@ describe("poker_game", () => {
     const callerRecordedIndex = gameAccount.players.findIndex((p: PublicKey) => p.equals(caller.publicKey));
     assert.ok(gameAccount.playerBets[callerRecordedIndex].eq(new BN(20)));
 
-    // Now DON'T fold to keep the game active; instead reveal winner
-    // Reveal winner (we use player1 as winner in this test)
+    // Action is closed at showdown, so nobody can fold or bet into the pot
+    assert.equal(gameAccount.phase, Phase.showdown);
+    await assert.rejects(
+      program.methods
+        .fold(ticketFor(gameAccount))
+        .accounts({
+          game: game.publicKey,
+          player: player1.publicKey,
+          seat: seatFor(game.publicKey, player1.publicKey),
+          history: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([player1])
+        .rpc(),
+      /BettingClosed/
+    );
+
+    // Now DON'T fold to keep the game active; instead reveal winner.
+    // The program only pays the wallet of the player holding the best hand.
+    // Player 1's profile rides along so the hand counts toward their lifetime stats.
+    // Player 2 has none, but passing any profile takes every player's address.
+    const [profile, profile2] = [player1, player2].map(
+      (player) =>
+        PublicKey.findProgramAddressSync([Buffer.from("profile"), player.publicKey.toBuffer()], program.programId)[0]
+    );
+    const [tableLeaderboard] = PublicKey.findProgramAddressSync(
+      [Buffer.from("leaderboard"), game.publicKey.toBuffer()],
+      program.programId
+    );
+    await program.methods.initTableLeaderboard().accountsPartial({ game: game.publicKey, leaderboard: tableLeaderboard }).rpc();
+
+    // A season scoring this table: a point per hand played, ten more per pot won
+    const seasonId = 1;
+    const [season] = PublicKey.findProgramAddressSync(
+      [Buffer.from("season"), provider.wallet.publicKey.toBuffer(), new BN(seasonId).toArrayLike(Buffer, "le", 4)],
+      program.programId
+    );
+    const now = Math.floor(Date.now() / 1000);
+    await program.methods
+      .createSeason(
+        seasonId,
+        new BN(now - 60),
+        new BN(now + 3600),
+        { handPlayed: 1, handWon: 10, finishes: [100, 50, 25, 0, 0] },
+        [7_000, 3_000, 0, 0, 0]
+      )
+      .accountsPartial({ season })
+      .rpc();
+    await program.methods.addSeasonVenue(game.publicKey).accounts({ season }).rpc();
+    await program.methods.fundSeason(new BN(1_000_000)).accounts({ season }).rpc();
+    const entries = [player1, player2].map(
+      (player) =>
+        PublicKey.findProgramAddressSync(
+          [Buffer.from("season_entry"), season.toBuffer(), player.publicKey.toBuffer()],
+          program.programId
+        )[0]
+    );
+    for (const player of [player1, player2]) {
+      await program.methods.joinSeason().accounts({ season, player: player.publicKey }).signers([player]).rpc();
+    }
+
+    const reveal = (candidate: Keypair, accounts: PublicKey[]) =>
+      program.methods
+        .revealWinner()
+        .accounts({
+          game: game.publicKey,
+          winner: candidate.publicKey,
+          caller: player1.publicKey,
+          history,
+          statsShard,
+          leaderboard: null,
+          tableLeaderboard,
+          season,
+          club: null,
+          payoutRoute: null,
+          globalConfig: null,
+          stakingDeposit: null,
+          stakingProgram: null,
+          memoConfig: null,
+          memoProgram: null,
+        })
+        .remainingAccounts(accounts.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true })))
+        .signers([player1])
+        .rpc();
+
+    let revealed = false;
+    for (const candidate of [player1, player2]) {
+      try {
+        // Leaving a player's profile address out would spare them the rating loss
+        await assert.rejects(reveal(candidate, [profile, ...entries]), /ProfileMismatch/);
+        // Or withhold their season points
+        await assert.rejects(reveal(candidate, [profile, profile2, entries[0]]), /SeasonEntryMismatch/);
+        await reveal(candidate, [profile, profile2, ...entries]);
+        revealed = true;
+        break;
+      } catch (err) {
+        if (!/NotBestHand/.test(String(err))) throw err;
+      }
+    }
+    assert.ok(revealed, "one of the players should hold the best hand");
+
+    gameAccount = await program.account.game.fetch(game.publicKey);
+    assert.equal(gameAccount.phase, Phase.waitingForPlayers, "table should wait for the next hand after revealWinner");
+    assert.ok(gameAccount.pot.eq(new BN(0)), "pot expected to be zero after payout");
+    assert.ok(gameAccount.stats.handsPlayed.eq(new BN(1)));
+    assert.ok(gameAccount.stats.totalPot.eq(new BN(40)));
+
+    // The table's shard counted the table and the hand; merging moves them to the global totals
+    await program.methods.mergeStats().accountsPartial({ globalStats, shard: statsShard }).rpc();
+    const totals = (await program.account.globalStats.fetch(globalStats)).totals;
+    assert.ok(totals.games.eq(new BN(1)));
+    assert.ok(totals.hands.eq(new BN(1)));
+    assert.ok(totals.volume.eq(new BN(40)));
+
+    const { stats } = await program.account.playerProfile.fetch(profile);
+    const player1Won = gameAccount.lastWinner.equals(player1.publicKey);
+    assert.ok(stats.handsPlayed.eq(new BN(1)));
+    assert.ok(stats.handsWon.eq(new BN(player1Won ? 1 : 0)));
+    assert.ok(stats.biggestPot.eq(new BN(player1Won ? 40 : 0)));
+    // Both players put chips in preflop, but the bet only matched the big blind
+    assert.ok(stats.vpipHands.eq(new BN(1)));
+    assert.ok(stats.pfrHands.eq(new BN(0)));
+
+    // The winner tops the table's leaderboard until the host starts a new season
+    let board = await program.account.leaderboard.fetch(tableLeaderboard);
+    assert.ok(board.byWinnings[0].player.equals(gameAccount.lastWinner));
+    assert.ok(board.byWinnings[0].value.eq(new BN(40)));
+    assert.ok(board.byHandsWon[0].value.eq(new BN(1)));
+    assert.ok(board.byWinnings[1].player.equals(PublicKey.default));
+    await assert.rejects(
+      program.methods
+        .resetLeaderboard()
+        .accountsPartial({ leaderboard: tableLeaderboard, config: null, signer: provider.wallet.publicKey })
+        .rpc(),
+      /NotAuthorized/
+    );
+    const [config] = PublicKey.findProgramAddressSync(
+      [Buffer.from("table_config"), game.publicKey.toBuffer()],
+      program.programId
+    );
     await program.methods
-      .revealWinner(player1.publicKey)
+      .resetLeaderboard()
+      .accountsPartial({ leaderboard: tableLeaderboard, config, signer: provider.wallet.publicKey })
+      .rpc();
+    board = await program.account.leaderboard.fetch(tableLeaderboard);
+    assert.equal(board.season, 2);
+    assert.ok(board.byWinnings[0].player.equals(PublicKey.default));
+
+    // Both entries scored the hand; prizes wait for the season to end
+    const winnerEntry = entries[player1Won ? 0 : 1];
+    assert.ok((await program.account.seasonEntry.fetch(winnerEntry)).points.eq(new BN(11)));
+    assert.ok((await program.account.seasonEntry.fetch(entries[player1Won ? 1 : 0])).points.eq(new BN(1)));
+    const standings = (await program.account.season.fetch(season)).standings;
+    assert.ok(standings[0].player.equals(gameAccount.lastWinner));
+    await assert.rejects(
+      program.methods.claimSeasonPrize().accounts({ season, player: player1.publicKey }).signers([player1]).rpc(),
+      /SeasonNotOver/
+    );
+
+    // Nobody showed down a royal flush, so there is no badge to claim
+    const [badge] = PublicKey.findProgramAddressSync(
+      [Buffer.from("achievement"), player1.publicKey.toBuffer(), Buffer.from([0])],
+      program.programId
+    );
+    await assert.rejects(
+      program.methods
+        .claimAchievement({ royalFlush: {} })
+        .accountsPartial({ profile, badge, tournament: null, player: player1.publicKey })
+        .signers([player1])
+        .rpc(),
+      /AchievementNotEarned/
+    );
+
+    const record = await program.account.handHistory.fetch(history);
+    assert.equal(record.actionCount, 2);
+    assert.ok("bet" in record.actions[0].kind);
+    assert.ok("call" in record.actions[1].kind);
+    assert.ok(record.settled);
+    assert.ok(record.pot.eq(new BN(40)));
+    const handId = createHash("sha256")
+      .update(Buffer.concat([game.publicKey.toBuffer(), record.handNumber.toArrayLike(Buffer, "le", 8)]))
+      .digest();
+    assert.ok(Buffer.from(record.handId).equals(handId));
+
+    // Archiving folds the hand into the table's tree and returns the rent straight away
+    const [archive] = PublicKey.findProgramAddressSync(
+      [Buffer.from("hand_archive"), game.publicKey.toBuffer()],
+      program.programId
+    );
+    await program.methods.initHandArchive().accountsPartial({ game: game.publicKey, archive }).rpc();
+    await program.methods
+      .archiveHandHistory()
+      .accountsPartial({ game: game.publicKey, archive, history, payer: provider.wallet.publicKey })
+      .rpc();
+    assert.equal(await provider.connection.getAccountInfo(history), null);
+    assert.ok((await program.account.handArchive.fetch(archive)).nextIndex.eq(new BN(1)));
+
+    // The only leaf's siblings are the empty subtrees beside it
+    const sha = (data: Buffer) => createHash("sha256").update(data).digest();
+    const leaf = sha(program.coder.types.encode("ArchivedHand", record));
+    const proof: number[][] = [];
+    let zero = Buffer.alloc(32);
+    for (let level = 0; level < 20; level++) {
+      proof.push([...zero]);
+      zero = sha(Buffer.concat([zero, zero]));
+    }
+    const verify = (candidate: Buffer) =>
+      program.methods.verifyArchivedHand([...candidate], new BN(0), proof).accounts({ archive }).view();
+    assert.equal(await verify(leaf), true);
+    assert.equal(await verify(Buffer.alloc(32)), false);
+  });
+
+  it("Refuses to settle the same hand twice", async () => {
+    for (const candidate of [player1, player2]) {
+      await assert.rejects(
+        program.methods
+          .revealWinner()
+          .accounts({
+            game: game.publicKey,
+            winner: candidate.publicKey,
+            caller: player1.publicKey,
+            history: null,
+            statsShard: null,
+            leaderboard: null,
+            tableLeaderboard: null,
+            season: null,
+            club: null,
+            payoutRoute: null,
+            globalConfig: null,
+            stakingDeposit: null,
+            stakingProgram: null,
+            memoConfig: null,
+            memoProgram: null,
+          })
+          .signers([player1])
+          .rpc(),
+        /AlreadySettled/
+      );
+    }
+  });
+
+  it("Passes the invariant audit", async () => {
+    await program.methods.assertInvariants().accounts({ game: game.publicKey }).rpc();
+
+    const gameAccount = await program.account.game.fetch(game.publicKey);
+    assert.equal(gameAccount.halted, 0);
+  });
+
+  it("Grows the table account to store the deck", async () => {
+    const before = await provider.connection.getAccountInfo(game.publicKey);
+    await program.methods
+      .enableExtension({ storedDeck: {} })
+      .accounts({ game: game.publicKey, signer: provider.wallet.publicKey })
+      .rpc();
+
+    const after = await provider.connection.getAccountInfo(game.publicKey);
+    assert.equal(after.data.length, before.data.length + 52);
+    assert.equal((await program.account.game.fetch(game.publicKey)).extensions, 1);
+
+    await assert.rejects(
+      program.methods
+        .enableExtension({ storedDeck: {} })
+        .accounts({ game: game.publicKey, signer: provider.wallet.publicKey })
+        .rpc(),
+      /ExtensionEnabled/
+    );
+  });
+
+  it("Cannot end a hand that is still being played", async () => {
+    await program.methods
+      .startRound()
       .accounts({
         game: game.publicKey,
-        winner: player1.publicKey,
+        tournament: null,
+        blindStructure: null,
+        signer: player1.publicKey,
       })
+      .signers([player1])
       .rpc();
 
-    gameAccount = await program.account.game.fetch(game.publicKey);
-    assert.ok(!gameAccount.isActive, "game should be inactive after revealWinner");
-    assert.ok(gameAccount.pot.eq(new BN(0)), "pot expected to be zero after payout");
+    // Ending it would cash every seat out, wallet then seat in seat order
+    const seats = [player1, player2].flatMap((player) => [
+      { pubkey: player.publicKey, isSigner: false, isWritable: true },
+      { pubkey: seatFor(game.publicKey, player.publicKey), isSigner: false, isWritable: true },
+    ]);
+    await assert.rejects(
+      program.methods
+        .endGame()
+        .accounts({ game: game.publicKey, signer: provider.wallet.publicKey, memoConfig: null, memoProgram: null })
+        .remainingAccounts(seats)
+        .rpc(),
+      /HandNotAbandoned/
+    );
   });
 
-  // Note: we purposely do not call endGame here because revealWinner already sets is_active=false
+  // Note: we purposely do not call endGame here because revealWinner already ends the hand
 });
@@ -0,0 +1,197 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN } from "@coral-xyz/anchor";
+import { PokerGame } from "../target/types/poker_game";
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import assert from "assert";
+
+/**
+ * Drives a seeded random mix of legal and illegal betting actions at one
+ * table and checks every outcome against `get_legal_actions`. The view and
+ * the handlers validate separately, so any action one accepts and the other
+ * refuses is a divergence. Rerun with SIM_SEED to reproduce a failure.
+ */
+const SEED = Number(process.env.SIM_SEED ?? 0x5eed);
+const STEPS = 80;
+
+/** mulberry32: small, fast and the same on every machine. */
+function rng(seed: number) {
+  let a = seed >>> 0;
+  return () => {
+    a = (a + 0x6d2b79f5) >>> 0;
+    let t = a;
+    t = Math.imul(t ^ (t >>> 15), t | 1);
+    t ^= t + Math.imul(t ^ (t >>> 7), t | 61);
+    return ((t ^ (t >>> 14)) >>> 0) / 4294967296;
+  };
+}
+
+describe("simulator", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const provider = anchor.AnchorProvider.env();
+  const program = anchor.workspace.pokerGame as Program<PokerGame>;
+
+  const game = Keypair.generate();
+  const players = [Keypair.generate(), Keypair.generate(), Keypair.generate()];
+  const random = rng(SEED);
+  const pick = <T>(items: T[]): T => items[Math.floor(random() * items.length)];
+  const tally = { accepted: 0, rejected: 0, hands: 0 };
+
+  function ticketFor(state: any) {
+    const street = state.phase === 2 ? state.bettingStreet : 0;
+    return { handNumber: state.handNumber, street, nonce: state.actionNonce, clientNonce: new BN(0) };
+  }
+
+  /** Bet sizes at, inside and just outside the legal range. */
+  function betSizes(legal: any, state: any): BN[] {
+    const sizes = [legal.minBet, legal.maxBet, legal.minBet.addn(1), state.currentBet.subn(1), legal.maxBet.addn(1)];
+    if (legal.maxBet.gt(legal.minBet)) {
+      const span = legal.maxBet.sub(legal.minBet).toNumber();
+      sizes.push(legal.minBet.addn(Math.floor(random() * span)));
+    }
+    return sizes.filter((size) => !size.isNeg());
+  }
+
+  /** Whether the view says `action` at `amount` is allowed. */
+  function expectLegal(legal: any, action: string, amount: BN): boolean {
+    switch (action) {
+      case "bet":
+        return legal.canBet && amount.gte(legal.minBet) && amount.lte(legal.maxBet);
+      case "call":
+        return legal.canCall || legal.canCheck;
+      default:
+        return legal.canFold;
+    }
+  }
+
+  async function step() {
+    const state = await program.account.game.fetch(game.publicKey);
+    const seat = Math.floor(random() * players.length);
+    const player = players.find((p) => p.publicKey.equals(state.players[seat]));
+    if (!player) return;
+
+    const legal = await program.methods.getLegalActions(seat).accounts({ game: game.publicKey }).view();
+    const action = pick(["bet", "call", "fold"]);
+    const amount = action === "bet" ? pick(betSizes(legal, state)) : new BN(0);
+    const method =
+      action === "bet"
+        ? program.methods.bet(amount, ticketFor(state))
+        : program.methods[action as "call" | "fold"](ticketFor(state));
+
+    let accepted = true;
+    try {
+      await method
+        .accounts({
+          game: game.publicKey,
+          player: player.publicKey,
+          seat: PublicKey.findProgramAddressSync(
+            [Buffer.from("seat"), game.publicKey.toBuffer(), player.publicKey.toBuffer()],
+            program.programId
+          )[0],
+          history: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([player])
+        .rpc();
+    } catch (err) {
+      accepted = false;
+    }
+
+    const label = `seed ${SEED}: seat ${seat} ${action} ${amount.toString()} at seq ${state.stateSeq.toString()}`;
+    assert.equal(accepted, expectLegal(legal, action, amount), `${label}: view and handler disagree`);
+
+    // A refused action leaves the table exactly as it was
+    const after = await program.account.game.fetch(game.publicKey);
+    if (accepted) {
+      tally.accepted += 1;
+      assert.ok(after.stateSeq.gt(state.stateSeq), `${label}: accepted without moving the table`);
+    } else {
+      tally.rejected += 1;
+      assert.ok(after.stateSeq.eq(state.stateSeq), `${label}: refused but the table moved`);
+    }
+  }
+
+  /** Cranks the table on, trying each wallet as the showdown winner. */
+  async function crank() {
+    for (const candidate of players) {
+      try {
+        await program.methods
+          .advanceGame()
+          .accounts({
+            game: game.publicKey,
+            tournament: null,
+            blindStructure: null,
+            caller: provider.wallet.publicKey,
+            history: null,
+            statsShard: null,
+            leaderboard: null,
+            tableLeaderboard: null,
+            season: null,
+            club: null,
+          })
+          .remainingAccounts([{ pubkey: candidate.publicKey, isSigner: false, isWritable: true }])
+          .rpc();
+        return;
+      } catch (err) {
+        if (!/PlayerNotInGame/.test(String(err))) throw err;
+      }
+    }
+    throw new Error("No wallet was accepted as the winner");
+  }
+
+  before(async () => {
+    await program.methods
+      .initializeGame(new BN(10), new BN(20), { holdem: {} })
+      .accounts({
+        game: game.publicKey,
+        statsShard: null,
+        user: provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([game])
+      .rpc();
+
+    for (const player of players) {
+      const sig = await provider.connection.requestAirdrop(player.publicKey, 1_000_000_000);
+      await provider.connection.confirmTransaction(sig);
+      await program.methods
+        .joinGame(new BN(1000))
+        .accounts({
+          game: game.publicKey,
+          player: player.publicKey,
+          attestation: null,
+          invitation: null,
+          club: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([player])
+        .rpc();
+    }
+  });
+
+  it("Keeps the legal-actions view and the handlers in lockstep", async () => {
+    for (let i = 0; i < STEPS; i++) {
+      const state = await program.account.game.fetch(game.publicKey);
+      if (state.phase === 2) {
+        await step();
+      } else if (state.phase === 0) {
+        try {
+          await program.methods
+            .startRound()
+            .accounts({ game: game.publicKey, tournament: null, blindStructure: null, signer: players[0].publicKey })
+            .signers([players[0]])
+            .rpc();
+          tally.hands += 1;
+        } catch (err) {
+          // Stacks ran dry; the table cannot deal again
+          if (/NotEnoughPlayers/.test(String(err))) break;
+          throw err;
+        }
+      } else {
+        await crank();
+      }
+    }
+
+    console.log(`Simulator (seed ${SEED}):`, tally);
+    assert.ok(tally.accepted > 0 && tally.rejected > 0, "the run should exercise both outcomes");
+  });
+});
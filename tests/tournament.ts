@@ -0,0 +1,171 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN } from "@coral-xyz/anchor";
+import { PokerGame } from "../target/types/poker_game";
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import assert from "assert";
+
+describe("tournament", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const provider = anchor.AnchorProvider.env();
+  const program = anchor.workspace.pokerGame as Program<PokerGame>;
+
+  const tournament = Keypair.generate();
+  const tables = [Keypair.generate(), Keypair.generate()];
+  const entrants = [Keypair.generate(), Keypair.generate(), Keypair.generate()];
+  const buyIn = new BN(100_000_000);
+
+  it("Creates the tournament", async () => {
+    await program.methods
+      .createTournament(buyIn, new BN(1500), new BN(10), new BN(20))
+      .accounts({
+        tournament: tournament.publicKey,
+        blindStructure: null,
+        authority: provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([tournament])
+      .rpc();
+
+    const account = await program.account.tournament.fetch(tournament.publicKey);
+    assert.ok(account.buyIn.eq(buyIn));
+    assert.equal(account.entrantCount, 0);
+    assert.ok("registering" in account.status);
+  });
+
+  it("Registers entrants and rejects duplicates", async () => {
+    for (const player of entrants) {
+      const sig = await provider.connection.requestAirdrop(player.publicKey, 1_000_000_000);
+      await provider.connection.confirmTransaction(sig);
+
+      await program.methods
+        .registerTournament()
+        .accounts({
+          tournament: tournament.publicKey,
+          ticket: null,
+          player: player.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([player])
+        .rpc();
+    }
+
+    await assert.rejects(
+      program.methods
+        .registerTournament()
+        .accounts({
+          tournament: tournament.publicKey,
+          ticket: null,
+          player: entrants[0].publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([entrants[0]])
+        .rpc(),
+      /AlreadyRegistered/
+    );
+
+    const account = await program.account.tournament.fetch(tournament.publicKey);
+    assert.equal(account.entrantCount, 3);
+    assert.ok(account.prizePool.eq(buyIn.muln(3)));
+  });
+
+  it("Adds tables and draws seats", async () => {
+    for (const table of tables) {
+      await program.methods
+        .addTournamentTable()
+        .accounts({
+          tournament: tournament.publicKey,
+          game: table.publicKey,
+          authority: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([table])
+        .rpc();
+    }
+
+    await program.methods
+      .seatDraw()
+      .accounts({
+        tournament: tournament.publicKey,
+        authority: provider.wallet.publicKey,
+      })
+      .remainingAccounts(
+        tables.map((t) => ({ pubkey: t.publicKey, isSigner: false, isWritable: true }))
+      )
+      .rpc();
+
+    const account = await program.account.tournament.fetch(tournament.publicKey);
+    assert.ok("running" in account.status);
+    assert.equal(account.playersRemaining, 3);
+
+    const first = await program.account.game.fetch(tables[0].publicKey);
+    const second = await program.account.game.fetch(tables[1].publicKey);
+    // Seats come from the registration seed, not join order, but every
+    // entrant is seated exactly once and tables stay within one of each other
+    const seated = [...first.players, ...second.players].filter((p) => !p.equals(PublicKey.default));
+    assert.equal(seated.length, 3);
+    for (const entrant of entrants) {
+      assert.equal(seated.filter((p) => p.equals(entrant.publicKey)).length, 1);
+    }
+    assert.ok(!first.players[1].equals(PublicKey.default));
+    assert.ok(first.stacks[0].eq(new BN(1500)));
+  });
+
+  it("Refuses to balance tables that differ by one", async () => {
+    await assert.rejects(
+      program.methods
+        .balanceTables()
+        .accounts({
+          tournament: tournament.publicKey,
+          fromTable: tables[0].publicKey,
+          toTable: tables[1].publicKey,
+        })
+        .rpc(),
+      /TablesBalanced/
+    );
+  });
+});
+
+describe("scheduled tournament", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const provider = anchor.AnchorProvider.env();
+  const program = anchor.workspace.pokerGame as Program<PokerGame>;
+
+  const tournament = Keypair.generate();
+  const startTime = new BN(Math.floor(Date.now() / 1000) + 3600);
+
+  it("Schedules a start time", async () => {
+    await program.methods
+      .createTournament(new BN(100_000_000), new BN(1500), new BN(10), new BN(20))
+      .accounts({
+        tournament: tournament.publicKey,
+        blindStructure: null,
+        authority: provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([tournament])
+      .rpc();
+
+    await program.methods
+      .configureSchedule(startTime, 4)
+      .accounts({
+        tournament: tournament.publicKey,
+        authority: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    const account = await program.account.tournament.fetch(tournament.publicKey);
+    assert.ok(account.startTime.eq(startTime));
+    assert.equal(account.minEntrants, 4);
+  });
+
+  it("Cannot be started or cancelled before the start time", async () => {
+    await assert.rejects(
+      program.methods.startTournament().accounts({ tournament: tournament.publicKey }).rpc(),
+      /TournamentNotStarted/
+    );
+    await assert.rejects(
+      program.methods.cancelTournament().accounts({ tournament: tournament.publicKey }).rpc(),
+      /TournamentNotStarted/
+    );
+  });
+});